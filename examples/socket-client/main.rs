@@ -81,12 +81,12 @@ fn main() -> Result<()> {
     let mut client: Box<dyn Client> = match protocol {
         Protocol::Udp => {
             println!("Listening for UDP data (port={})", port);
-            Box::new(UDPClient::new(port))
+            Box::new(UDPClient::new(port, true))
         }
         Protocol::Tcp => {
             let hostname = args.next().unwrap_or_else(|| String::from("localhost"));
             println!("Connecting to TCP (hostname={}, port={})", hostname, port);
-            Box::new(TCPClient::new(&hostname, port))
+            Box::new(TCPClient::new(&hostname, port, true))
         }
     };
     client.connect()?;
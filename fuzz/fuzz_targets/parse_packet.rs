@@ -0,0 +1,7 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+// Parsing arbitrary bytes must never panic, only return an error.
+fuzz_target!(|data: &[u8]| {
+    let _ = sep_data::parser::parse_packet(data);
+});
@@ -11,17 +11,42 @@ ID_ENUM_ENTRY_TEMPLATE = "\
 ID_TRY_FROM_MATCH_ENTRY_TEMPLATE = "\
     x if x == SEOutputDataId::%(enum_id)s as u16 => Ok(SEOutputDataId::%(enum_id)s),\n"
 
+ID_NAME_MATCH_ENTRY_TEMPLATE = "\
+    SEOutputDataId::%(enum_id)s => \"%(enum_id)s\",\n"
+
+ID_FROM_NAME_MATCH_ENTRY_TEMPLATE = "\
+    \"%(enum_id)s\" => Some(SEOutputDataId::%(enum_id)s),\n"
+
+ID_ALL_ENTRY_TEMPLATE = "\
+    SEOutputDataId::%(enum_id)s,\n"
+
+ID_EYE_MATCH_ENTRY_TEMPLATE = "\
+    SEOutputDataId::%(enum_id)s => %(eye)s,\n"
+
+TYPE_ID_MATCH_ENTRY_TEMPLATE = "\
+    SEOutputData::%(enum_id)s(_) => SEOutputDataId::%(enum_id)s,\n"
+
+ID_TYPE_ID_MATCH_ENTRY_TEMPLATE = "\
+    SEOutputDataId::%(enum_id)s => SETypeId::%(variant)s,\n"
+
+TYPE_INTO_VARIANT_MATCH_ENTRY_TEMPLATE = "\
+    SEOutputData::%(enum_id)s(v) => SEVariant::%(variant)s(v),\n"
+
+TYPE_FROM_VARIANT_MATCH_ENTRY_TEMPLATE = "\
+    (SEOutputDataId::%(enum_id)s, SEVariant::%(variant)s(v)) => Some(SEOutputData::%(enum_id)s(v)),\n"
+
 OUTPUT_TEMPLATE = """
 // NOTE: this file is generated by generate_output_data.py
 
-use super::types::*;
+use super::{eye::Eye, types::*};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SEOutputData {
     %(type_enum_entries)s
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 #[repr(u16)]
 pub enum SEOutputDataId {
     %(id_enum_entries)s
@@ -33,7 +58,67 @@ impl std::convert::TryFrom<u16> for SEOutputDataId {
     fn try_from(value: u16) -> Result<Self, Self::Error> {
         match value {
             %(try_from_match_entries)s
-            _ => unimplemented!("{:#x}", value),
+            _ => Err(()),
+        }
+    }
+}
+
+impl SEOutputDataId {
+    // All output data ids, in the order of their definition.
+    pub const ALL: &'static [SEOutputDataId] = &[
+        %(id_all_entries)s
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            %(id_name_match_entries)s
+        }
+    }
+
+    // Inverse of name, None if there is no output data with the name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            %(id_from_name_match_entries)s
+            _ => None,
+        }
+    }
+
+    pub fn type_id(&self) -> SETypeId {
+        match self {
+            %(id_type_id_match_entries)s
+        }
+    }
+
+    // Eye the output data is measured from, or None if not eye related (e.g.
+    // head pose or time stamps).
+    pub fn eye(&self) -> Option<Eye> {
+        match self {
+            %(id_eye_match_entries)s
+        }
+    }
+}
+
+impl SEOutputData {
+    pub fn id(&self) -> SEOutputDataId {
+        match self {
+            %(id_match_entries)s
+        }
+    }
+
+    // Output data with the given id and value, None if the type of the value
+    // does not match the id.
+    pub(crate) fn from_variant(id: SEOutputDataId, value: SEVariant) -> Option<Self> {
+        match (id, value) {
+            %(from_variant_match_entries)s
+            _ => None,
+        }
+    }
+}
+
+impl From<SEOutputData> for SEVariant {
+    fn from(data: SEOutputData) -> Self {
+        match data {
+            %(into_variant_match_entries)s
         }
     }
 }
@@ -57,6 +142,47 @@ SEP_TO_RUST_DATA_TYPE = {
     "SEType_WorldIntersections": "SETypeWorldIntersections",
 }
 
+SEP_TO_RUST_VARIANT = {
+    "SEType_u8": "U8",
+    "SEType_u16": "U16",
+    "SEType_u32": "U32",
+    "SEType_u64": "U64",
+    "SEType_float": "F64",
+    "SEType_f64": "F64",
+    "SEType_Vector": "Vector",
+    "SEType_Point2D": "Point2D",
+    "SEType_Point3D": "Point3D",
+    "SEType_Vect3D": "Vect3D",
+    "SEType_String": "String",
+    "SEType_Quaternion": "Quaternion",
+    "SEType_UserMarker": "UserMarker",
+    "SEType_WorldIntersection": "WorldIntersection",
+    "SEType_WorldIntersections": "WorldIntersections",
+}
+
+# Eye of output data that can not be told from its name by eye_of.
+EYE_OVERRIDES = {
+    "SESaccade": "Combined",
+    "SEFixation": "Combined",
+    "SEBlink": "Combined",
+    "SEEyeglassesStatus": None,
+    "SEExpressionEyeWiden": None,
+}
+
+
+def eye_of(enum_id):
+    if enum_id in EYE_OVERRIDES:
+        return EYE_OVERRIDES[enum_id]
+    if enum_id.startswith("SEHead"):
+        return None
+    if "Left" in enum_id:
+        return "Left"
+    if "Right" in enum_id:
+        return "Right"
+    if any(s in enum_id for s in ["Gaze", "Eye", "Pupil", "WorldIntersection"]):
+        return "Combined"
+    return None
+
 
 def main():
     data_output_json = Path(sys.argv[1])
@@ -69,10 +195,19 @@ def main():
     type_enum_entries = ""
     id_enum_entries = ""
     id_try_from_match_entries = ""
+    id_name_match_entries = ""
+    id_from_name_match_entries = ""
+    id_all_entries = ""
+    id_type_id_match_entries = ""
+    id_eye_match_entries = ""
+    id_match_entries = ""
+    into_variant_match_entries = ""
+    from_variant_match_entries = ""
     for d in output_data_definitions:
         enum_id = d["EnumID"]
         enum_number = int(d["EnumNumber"], 16)
         data_type = SEP_TO_RUST_DATA_TYPE[d["DataType"]]
+        variant = SEP_TO_RUST_VARIANT[d["DataType"]]
         required_module = d["RequiredModule"]
 
         if required_module == "FacialFeatures":
@@ -89,11 +224,49 @@ def main():
         id_try_from_match_entries += ID_TRY_FROM_MATCH_ENTRY_TEMPLATE % {
             'enum_id': enum_id
         }
+        id_name_match_entries += ID_NAME_MATCH_ENTRY_TEMPLATE % {
+            'enum_id': enum_id
+        }
+        id_from_name_match_entries += ID_FROM_NAME_MATCH_ENTRY_TEMPLATE % {
+            'enum_id': enum_id
+        }
+        id_all_entries += ID_ALL_ENTRY_TEMPLATE % {
+            'enum_id': enum_id
+        }
+        id_type_id_match_entries += ID_TYPE_ID_MATCH_ENTRY_TEMPLATE % {
+            'enum_id': enum_id,
+            'variant': variant
+        }
+        eye = eye_of(enum_id)
+        id_eye_match_entries += ID_EYE_MATCH_ENTRY_TEMPLATE % {
+            'enum_id': enum_id,
+            'eye': "Some(Eye::%s)" % eye if eye else "None"
+        }
+        id_match_entries += TYPE_ID_MATCH_ENTRY_TEMPLATE % {
+            'enum_id': enum_id
+        }
+        into_variant_match_entries += TYPE_INTO_VARIANT_MATCH_ENTRY_TEMPLATE % {
+            'enum_id': enum_id,
+            'variant': variant
+        }
+
+        from_variant_match_entries += TYPE_FROM_VARIANT_MATCH_ENTRY_TEMPLATE % {
+            'enum_id': enum_id,
+            'variant': variant
+        }
 
     file_content = OUTPUT_TEMPLATE % {
         'type_enum_entries': type_enum_entries,
         'id_enum_entries': id_enum_entries,
-        'try_from_match_entries': id_try_from_match_entries
+        'try_from_match_entries': id_try_from_match_entries,
+        'id_name_match_entries': id_name_match_entries,
+        'id_from_name_match_entries': id_from_name_match_entries,
+        'id_all_entries': id_all_entries,
+        'id_type_id_match_entries': id_type_id_match_entries,
+        'id_eye_match_entries': id_eye_match_entries,
+        'id_match_entries': id_match_entries,
+        'into_variant_match_entries': into_variant_match_entries,
+        'from_variant_match_entries': from_variant_match_entries
     }
     out_file.write_text(file_content)
 
@@ -0,0 +1,530 @@
+use crate::{
+    client::Packet,
+    packet,
+    se_types::{Point3D, SEOutputData, SEOutputDataId, SEVariant, Vect3D},
+};
+use std::time::Duration;
+
+// Makes SETimeStamp values relative to the first timestamp observed, so that
+// the first packet has timestamp 0. Timestamps earlier than the first one
+// saturate at 0.
+#[derive(Debug, Clone, Default)]
+pub struct RelativeTimestamps {
+    origin: Option<u64>,
+}
+
+impl RelativeTimestamps {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn origin(&self) -> Option<u64> {
+        self.origin
+    }
+
+    // Timestamp of packet relative to the first observed timestamp, without
+    // modifying the packet.
+    pub fn relative_timestamp(&mut self, packet: &[SEOutputData]) -> Option<u64> {
+        let time_stamp = packet::timestamp(packet)?;
+        let origin = *self.origin.get_or_insert(time_stamp);
+        Some(time_stamp.saturating_sub(origin))
+    }
+
+    // Rewrites the SETimeStamp fields of packet to be relative. Packets
+    // without a timestamp are left unchanged.
+    pub fn apply(&mut self, packet: &mut [SEOutputData]) {
+        let time_stamp = match packet::timestamp(packet) {
+            Some(time_stamp) => time_stamp,
+            None => return,
+        };
+        let origin = *self.origin.get_or_insert(time_stamp);
+        for data in packet.iter_mut() {
+            if let SEOutputData::SETimeStamp(v) = data {
+                *v = v.saturating_sub(origin);
+            }
+        }
+    }
+}
+
+// Iterator adapter returned by coalesce_by_frame.
+pub struct CoalesceByFrame<I> {
+    packets: I,
+    pending: Option<Packet>,
+}
+
+// Adds the fields of src to dst. A field whose id is already present in dst
+// replaces the existing field.
+fn merge_packet(dst: &mut Packet, src: Packet) {
+    for data in src {
+        match dst.iter_mut().find(|d| d.id() == data.id()) {
+            Some(existing) => *existing = data,
+            None => dst.push(data),
+        }
+    }
+}
+
+impl<I: Iterator<Item = Packet>> Iterator for CoalesceByFrame<I> {
+    type Item = Packet;
+
+    fn next(&mut self) -> Option<Packet> {
+        let mut merged = self.pending.take().or_else(|| self.packets.next())?;
+        let frame_number = match packet::frame_number(&merged) {
+            Some(frame_number) => frame_number,
+            None => return Some(merged),
+        };
+        for packet in &mut self.packets {
+            if packet::frame_number(&packet) == Some(frame_number) {
+                merge_packet(&mut merged, packet);
+            } else {
+                self.pending = Some(packet);
+                break;
+            }
+        }
+        Some(merged)
+    }
+}
+
+// Merges consecutive packets having the same SEFrameNumber into a single
+// packet. If a field occurs in more than one of the merged packets, the value
+// from the last packet wins. Packets without a frame number are passed
+// through as is.
+pub fn coalesce_by_frame<I>(packets: I) -> CoalesceByFrame<I::IntoIter>
+where
+    I: IntoIterator<Item = Packet>,
+{
+    CoalesceByFrame {
+        packets: packets.into_iter(),
+        pending: None,
+    }
+}
+
+// Iterator adapter returned by window_by_time.
+pub struct WindowByTime<I> {
+    packets: I,
+    window_ticks: u64,
+    // First timestamp, which the windows are aligned to.
+    origin: Option<u64>,
+    // Window of the last timestamp, negative for windows before the origin.
+    current: Option<i128>,
+    batch: Vec<Packet>,
+}
+
+impl<I: Iterator<Item = Packet>> Iterator for WindowByTime<I> {
+    type Item = Vec<Packet>;
+
+    fn next(&mut self) -> Option<Vec<Packet>> {
+        let (origin, window_ticks) = (&mut self.origin, self.window_ticks);
+        for packet in &mut self.packets {
+            let window = packet::timestamp(&packet).map(|time_stamp| {
+                let origin = *origin.get_or_insert(time_stamp);
+                (i128::from(time_stamp) - i128::from(origin)).div_euclid(i128::from(window_ticks))
+            });
+            match (window, self.current) {
+                (Some(window), Some(current)) if window != current => {
+                    self.current = Some(window);
+                    let batch = std::mem::replace(&mut self.batch, vec![packet]);
+                    return Some(batch);
+                }
+                (Some(window), _) => self.current = Some(window),
+                (None, _) => (),
+            }
+            self.batch.push(packet);
+        }
+        if self.batch.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.batch))
+        }
+    }
+}
+
+// Groups packets into batches by fixed windows of SETimeStamp time, aligned
+// to the first timestamp. A batch is emitted when a packet's timestamp falls
+// in another window than the previous timestamp (including going back in
+// time). Packets without a timestamp go into the current batch.
+pub fn window_by_time<I>(packets: I, window: Duration) -> WindowByTime<I::IntoIter>
+where
+    I: IntoIterator<Item = Packet>,
+{
+    let window_ticks = (window.as_nanos() * u128::from(packet::TIME_STAMP_TICKS_PER_SECOND)
+        / 1_000_000_000) as u64;
+    assert!(window_ticks > 0, "window must be at least 100 ns");
+    WindowByTime {
+        packets: packets.into_iter(),
+        window_ticks,
+        origin: None,
+        current: None,
+        batch: Vec::new(),
+    }
+}
+
+// Id of the quality field accompanying fields with the given id, such as
+// SEGazeDirectionQ for SEGazeDirection. Both head rotation representations
+// share SEHeadRotationQ.
+pub fn quality_id(id: SEOutputDataId) -> Option<SEOutputDataId> {
+    use SEOutputDataId as Id;
+    Some(match id {
+        Id::SEGazeDirection => Id::SEGazeDirectionQ,
+        Id::SELeftGazeDirection => Id::SELeftGazeDirectionQ,
+        Id::SERightGazeDirection => Id::SERightGazeDirectionQ,
+        Id::SEFilteredGazeDirection => Id::SEFilteredGazeDirectionQ,
+        Id::SEFilteredLeftGazeDirection => Id::SEFilteredLeftGazeDirectionQ,
+        Id::SEFilteredRightGazeDirection => Id::SEFilteredRightGazeDirectionQ,
+        Id::SEEstimatedGazeDirection => Id::SEEstimatedGazeDirectionQ,
+        Id::SEEstimatedLeftGazeDirection => Id::SEEstimatedLeftGazeDirectionQ,
+        Id::SEEstimatedRightGazeDirection => Id::SEEstimatedRightGazeDirectionQ,
+        Id::SEFilteredEstimatedGazeDirection => Id::SEFilteredEstimatedGazeDirectionQ,
+        Id::SEFilteredEstimatedLeftGazeDirection => Id::SEFilteredEstimatedLeftGazeDirectionQ,
+        Id::SEFilteredEstimatedRightGazeDirection => Id::SEFilteredEstimatedRightGazeDirectionQ,
+        Id::SEHeadPosition => Id::SEHeadPositionQ,
+        Id::SEHeadRotationRodrigues => Id::SEHeadRotationQ,
+        Id::SEHeadRotationQuaternion => Id::SEHeadRotationQ,
+        Id::SEEyelidOpening => Id::SEEyelidOpeningQ,
+        Id::SELeftEyelidOpening => Id::SELeftEyelidOpeningQ,
+        Id::SERightEyelidOpening => Id::SERightEyelidOpeningQ,
+        Id::SEPupilDiameter => Id::SEPupilDiameterQ,
+        Id::SELeftPupilDiameter => Id::SELeftPupilDiameterQ,
+        Id::SERightPupilDiameter => Id::SERightPupilDiameterQ,
+        Id::SEFilteredPupilDiameter => Id::SEFilteredPupilDiameterQ,
+        Id::SEFilteredLeftPupilDiameter => Id::SEFilteredLeftPupilDiameterQ,
+        Id::SEFilteredRightPupilDiameter => Id::SEFilteredRightPupilDiameterQ,
+        _ => return None,
+    })
+}
+
+// Removes the fields whose quality, as given by the quality field of the
+// packet, is below threshold. The quality fields themselves are kept, as are
+// fields without a quality field in the packet.
+pub fn drop_low_quality(packet: &mut Packet, threshold: f64) {
+    drop_below(packet, threshold, false);
+}
+
+// Like drop_low_quality, but also removes the quality fields of the removed
+// fields.
+pub fn drop_low_quality_with_quality(packet: &mut Packet, threshold: f64) {
+    drop_below(packet, threshold, true);
+}
+
+fn drop_below(packet: &mut Packet, threshold: f64, drop_quality: bool) {
+    let mut low_quality: Vec<SEOutputDataId> = packet
+        .iter()
+        .map(SEOutputData::id)
+        .filter(|&id| {
+            quality_id(id)
+                .and_then(|quality_id| packet::field_f64(packet, quality_id))
+                .is_some_and(|quality| quality < threshold)
+        })
+        .collect();
+    if drop_quality {
+        let quality_ids: Vec<SEOutputDataId> =
+            low_quality.iter().copied().filter_map(quality_id).collect();
+        low_quality.extend(quality_ids);
+    }
+    packet.retain(|data| !low_quality.contains(&data.id()));
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct InterpolatedPacket {
+    pub packet: Packet,
+    // Whether the packet was synthesized by the Interpolator, rather than
+    // received.
+    pub synthetic: bool,
+}
+
+// Fills in dropped frames, detected as gaps in SEFrameNumber, by linearly
+// interpolating between the packets surrounding the gap. Only f64, Point3D
+// and Vect3D fields present in both surrounding packets are interpolated,
+// all other fields (except the frame number) are omitted from synthesized
+// packets. Gaps larger than max_gap frames are not filled.
+#[derive(Debug, Clone)]
+pub struct Interpolator {
+    prev: Option<Packet>,
+    max_gap: u32,
+}
+
+impl Default for Interpolator {
+    fn default() -> Self {
+        Interpolator {
+            prev: None,
+            max_gap: 10,
+        }
+    }
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+fn interpolate_variant(a: SEVariant, b: SEVariant, t: f64) -> Option<SEVariant> {
+    match (a, b) {
+        (SEVariant::F64(a), SEVariant::F64(b)) => Some(SEVariant::F64(lerp(a, b, t))),
+        (SEVariant::Point3D(a), SEVariant::Point3D(b)) => Some(SEVariant::Point3D(Point3D(
+            lerp(a.0, b.0, t),
+            lerp(a.1, b.1, t),
+            lerp(a.2, b.2, t),
+        ))),
+        (SEVariant::Vect3D(a), SEVariant::Vect3D(b)) => Some(SEVariant::Vect3D(Vect3D(
+            lerp(a.0, b.0, t),
+            lerp(a.1, b.1, t),
+            lerp(a.2, b.2, t),
+        ))),
+        _ => None,
+    }
+}
+
+fn interpolate_packet(prev: &Packet, next: &Packet, frame_number: u32, t: f64) -> Packet {
+    let mut packet = Packet::new();
+    packet.push(SEOutputData::SEFrameNumber(frame_number));
+    for data in next {
+        let prev_data = match packet::find_field(prev, data.id()) {
+            Some(prev_data) => prev_data,
+            None => continue,
+        };
+        let value = interpolate_variant(
+            SEVariant::from(prev_data.clone()),
+            SEVariant::from(data.clone()),
+            t,
+        );
+        if let Some(data) = value.and_then(|v| SEOutputData::from_variant(data.id(), v)) {
+            packet.push(data);
+        }
+    }
+    packet
+}
+
+impl Interpolator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_gap(mut self, max_gap: u32) -> Self {
+        self.max_gap = max_gap;
+        self
+    }
+
+    // Returns synthesized packets for any frames missing between the
+    // previous packet and packet, followed by packet itself.
+    pub fn push(&mut self, packet: Packet) -> Vec<InterpolatedPacket> {
+        let mut out = Vec::new();
+        let frames = self
+            .prev
+            .as_ref()
+            .and_then(|prev| packet::frame_number(prev))
+            .zip(packet::frame_number(&packet));
+        if let (Some(prev), Some((prev_frame, frame))) = (&self.prev, frames) {
+            let gap = frame.wrapping_sub(prev_frame);
+            if gap > 1 && gap - 1 <= self.max_gap {
+                for i in 1..gap {
+                    let t = f64::from(i) / f64::from(gap);
+                    out.push(InterpolatedPacket {
+                        packet: interpolate_packet(prev, &packet, prev_frame.wrapping_add(i), t),
+                        synthetic: true,
+                    });
+                }
+            }
+        }
+        self.prev = Some(packet.clone());
+        out.push(InterpolatedPacket {
+            packet,
+            synthetic: false,
+        });
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{se_types::Quaternion, test_support::packet_of};
+
+    #[test]
+    fn test_relative_timestamps() {
+        let mut relative = RelativeTimestamps::new();
+
+        let mut first = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SETimeStamp(5000),
+        ];
+        let mut no_time_stamp = vec![SEOutputData::SEFrameNumber(2)];
+        let mut second = vec![
+            SEOutputData::SEFrameNumber(3),
+            SEOutputData::SETimeStamp(5160),
+        ];
+        assert_eq!(relative.relative_timestamp(&first), Some(0));
+
+        relative.apply(&mut first);
+        relative.apply(&mut no_time_stamp);
+        relative.apply(&mut second);
+        assert_eq!(relative.origin(), Some(5000));
+        assert_eq!(first[1], SEOutputData::SETimeStamp(0));
+        assert_eq!(no_time_stamp, vec![SEOutputData::SEFrameNumber(2)]);
+        assert_eq!(second[1], SEOutputData::SETimeStamp(160));
+    }
+
+    #[test]
+    fn test_coalesce_by_frame() {
+        let packets = vec![
+            packet_of(vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SETimeStamp(100),
+                SEOutputData::SEHeadHeading(0.1),
+            ]),
+            packet_of(vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SEHeadHeading(0.2),
+                SEOutputData::SEGazeHeading(0.3),
+            ]),
+            packet_of(vec![SEOutputData::SEFrameNumber(2)]),
+        ];
+        let coalesced: Vec<Packet> = coalesce_by_frame(packets).collect();
+        assert_eq!(
+            coalesced,
+            vec![
+                packet_of(vec![
+                    SEOutputData::SEFrameNumber(1),
+                    SEOutputData::SETimeStamp(100),
+                    SEOutputData::SEHeadHeading(0.2),
+                    SEOutputData::SEGazeHeading(0.3),
+                ]),
+                packet_of(vec![SEOutputData::SEFrameNumber(2)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_window_by_time() {
+        let packet = |frame_number, time_stamp: Option<u64>| {
+            let mut packet = packet_of(vec![SEOutputData::SEFrameNumber(frame_number)]);
+            packet.extend(time_stamp.map(SEOutputData::SETimeStamp));
+            packet
+        };
+        // 10 ms windows, 100_000 ticks each.
+        let packets = vec![
+            packet(1, Some(1_000_000)),
+            packet(2, None),
+            packet(3, Some(1_090_000)),
+            packet(4, Some(1_100_000)),
+            packet(5, None),
+        ];
+        let batches: Vec<Vec<Packet>> =
+            window_by_time(packets.clone(), Duration::from_millis(10)).collect();
+        assert_eq!(batches, vec![packets[..3].to_vec(), packets[3..].to_vec()]);
+
+        // Timestamps before the origin fall into windows aligned to it.
+        let packets = vec![
+            packet(1, Some(1_000_000)),
+            packet(2, Some(950_000)),
+            packet(3, Some(920_000)),
+            packet(4, Some(1_000_000)),
+        ];
+        let batches: Vec<Vec<Packet>> =
+            window_by_time(packets.clone(), Duration::from_millis(10)).collect();
+        assert_eq!(
+            batches,
+            vec![
+                packets[..1].to_vec(),
+                packets[1..3].to_vec(),
+                packets[3..].to_vec()
+            ]
+        );
+
+        assert_eq!(
+            window_by_time(Vec::new(), Duration::from_millis(10)).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_interpolator() {
+        let mut interpolator = Interpolator::new();
+        let first = packet_of(vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEHeadPosition(Point3D(0.0, 0.0, 0.5)),
+            SEOutputData::SEHeadHeading(0.1),
+            SEOutputData::SEKeyboardState(String::from("a")),
+        ]);
+        let third = packet_of(vec![
+            SEOutputData::SEFrameNumber(3),
+            SEOutputData::SEHeadPosition(Point3D(0.2, 0.0, 0.7)),
+            SEOutputData::SEHeadHeading(0.3),
+            SEOutputData::SEKeyboardState(String::from("b")),
+        ]);
+        assert_eq!(
+            interpolator.push(first.clone()),
+            vec![InterpolatedPacket {
+                packet: first,
+                synthetic: false,
+            }]
+        );
+
+        let out = interpolator.push(third.clone());
+        assert_eq!(out.len(), 2);
+        assert!(out[0].synthetic);
+        let second = &out[0].packet;
+        assert_eq!(second.len(), 3);
+        assert_eq!(second[0], SEOutputData::SEFrameNumber(2));
+        match second[1] {
+            SEOutputData::SEHeadPosition(Point3D(x, y, z)) => {
+                assert!((x - 0.1).abs() < 1e-12);
+                assert_eq!(y, 0.0);
+                assert!((z - 0.6).abs() < 1e-12);
+            }
+            _ => panic!("expected SEHeadPosition"),
+        }
+        assert!(matches!(second[2], SEOutputData::SEHeadHeading(v) if (v - 0.2).abs() < 1e-12));
+        assert_eq!(
+            out[1],
+            InterpolatedPacket {
+                packet: third,
+                synthetic: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_drop_low_quality() {
+        let mut packet = packet_of(vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEGazeDirection(Vect3D(0.0, 0.0, -1.0)),
+            SEOutputData::SEGazeDirectionQ(0.2),
+            SEOutputData::SEHeadPosition(Point3D(0.0, 0.0, 0.6)),
+            SEOutputData::SEHeadPositionQ(0.9),
+        ]);
+        drop_low_quality(&mut packet, 0.5);
+        assert_eq!(
+            packet[..],
+            [
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SEGazeDirectionQ(0.2),
+                SEOutputData::SEHeadPosition(Point3D(0.0, 0.0, 0.6)),
+                SEOutputData::SEHeadPositionQ(0.9),
+            ]
+        );
+
+        let mut packet = packet_of(vec![
+            SEOutputData::SEGazeDirection(Vect3D(0.0, 0.0, -1.0)),
+            SEOutputData::SEGazeDirectionQ(0.2),
+            SEOutputData::SEHeadPosition(Point3D(0.0, 0.0, 0.6)),
+            SEOutputData::SEHeadPositionQ(0.9),
+        ]);
+        drop_low_quality_with_quality(&mut packet, 0.5);
+        assert_eq!(
+            packet[..],
+            [
+                SEOutputData::SEHeadPosition(Point3D(0.0, 0.0, 0.6)),
+                SEOutputData::SEHeadPositionQ(0.9),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drop_low_quality_head_rotation() {
+        let mut packet = packet_of(vec![
+            SEOutputData::SEHeadRotationQuaternion(Quaternion(1.0, 0.0, 0.0, 0.0)),
+            SEOutputData::SEHeadRotationRodrigues(Vect3D(0.0, 0.0, 0.0)),
+            SEOutputData::SEHeadRotationQ(0.1),
+        ]);
+        drop_low_quality(&mut packet, 0.5);
+        assert_eq!(packet[..], [SEOutputData::SEHeadRotationQ(0.1)]);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{client::Packet, se_types::SEOutputData};
+use ::bincode::Options;
+
+// Variable-length integer encoding keeps field tags and small integers to a
+// byte or two, making packets smaller than when encoded as SEPD.
+fn options() -> impl Options {
+    ::bincode::DefaultOptions::new()
+}
+
+// Encodes the packet in a compact binary form, for storage rather than
+// interchange: unlike SEPD, the encoding is not self-describing and is tied to
+// the version of sep-data.
+pub fn to_bincode(packet: &[SEOutputData]) -> Vec<u8> {
+    options()
+        .serialize(packet)
+        .expect("packets can always be encoded")
+}
+
+// Decodes a packet encoded by to_bincode.
+pub fn from_bincode(bytes: &[u8]) -> Result<Packet, ::bincode::Error> {
+    let fields: Vec<SEOutputData> = options().deserialize(bytes)?;
+    Ok(fields.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        se_types::{Point3D, Vect3D, WorldIntersection},
+        serializer::serialize_packet,
+    };
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let packet = vec![
+            SEOutputData::SEFrameNumber(17819),
+            SEOutputData::SETimeStamp(123_456_789),
+            SEOutputData::SEHeadPosition(Point3D(0.1, 0.2, 0.6)),
+            SEOutputData::SEGazeDirection(Vect3D(0.0, 0.0, -1.0)),
+            SEOutputData::SEGazeDirectionQ(0.8),
+            SEOutputData::SEClosestWorldIntersection(Some(WorldIntersection {
+                world_point: Point3D(0.0, 0.1, 0.0),
+                object_point: Point3D(0.2, 0.1, 0.0),
+                object_name: String::from("Screen"),
+            })),
+        ];
+        let encoded = to_bincode(&packet);
+        assert_eq!(from_bincode(&encoded).unwrap()[..], packet[..]);
+        assert!(encoded.len() < serialize_packet(&packet).len());
+
+        assert!(from_bincode(&encoded[..encoded.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use super::{Client, ClientError};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    thread,
+    time::Duration,
+};
+
+// Delays between retries of a failing operation, such as connecting, growing
+// by multiplier after each retry up to max. With jitter j, each delay is
+// randomly shortened by up to a fraction j, so that many clients retrying at
+// once spread out.
+#[derive(Debug, Clone)]
+pub struct BackoffPolicy {
+    initial: Duration,
+    max: Duration,
+    multiplier: f64,
+    jitter: f64,
+    current: Option<Duration>,
+    rng_state: u64,
+}
+
+impl BackoffPolicy {
+    // Policy doubling the delay after each retry, without jitter.
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        let seed = RandomState::new().build_hasher().finish();
+        BackoffPolicy {
+            initial,
+            max,
+            multiplier: 2.0,
+            jitter: 0.0,
+            current: None,
+            // The xorshift state must not be 0.
+            rng_state: seed | 1,
+        }
+    }
+
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        assert!(multiplier >= 1.0, "multiplier must be at least 1");
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        assert!((0.0..=1.0).contains(&jitter), "jitter must be in [0, 1]");
+        self.jitter = jitter;
+        self
+    }
+
+    // Random number in [0, 1), using xorshift64.
+    fn random(&mut self) -> f64 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.rng_state = x;
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Delay before the next retry.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = match self.current {
+            Some(current) => current.mul_f64(self.multiplier).min(self.max),
+            None => self.initial.min(self.max),
+        };
+        self.current = Some(delay);
+        if self.jitter > 0.0 {
+            let r = self.random();
+            delay.mul_f64(1.0 - self.jitter * r)
+        } else {
+            delay
+        }
+    }
+
+    // Starts over from the initial delay, e.g. after a successful retry.
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+}
+
+// Connects client, retrying up to attempts times in total with delays given
+// by policy. Returns the error of the last attempt if all attempts fail.
+pub fn connect_with_retry<C: Client + ?Sized>(
+    client: &mut C,
+    policy: &mut BackoffPolicy,
+    attempts: u32,
+) -> Result<(), ClientError> {
+    let mut attempt = 1;
+    loop {
+        match client.connect() {
+            Ok(()) => {
+                policy.reset();
+                return Ok(());
+            }
+            Err(err) if attempt >= attempts => return Err(err),
+            Err(_) => {
+                thread::sleep(policy.next_delay());
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::TCPClient;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_backoff_policy() {
+        let ms = Duration::from_millis;
+        let mut policy = BackoffPolicy::new(ms(100), ms(1000)).multiplier(3.0);
+        let delays: Vec<_> = (0..5).map(|_| policy.next_delay()).collect();
+        assert_eq!(delays, vec![ms(100), ms(300), ms(900), ms(1000), ms(1000)]);
+
+        policy.reset();
+        assert_eq!(policy.next_delay(), ms(100));
+
+        let mut policy = BackoffPolicy::new(ms(100), ms(1000)).jitter(0.5);
+        for _ in 0..100 {
+            let delay = policy.next_delay();
+            assert!(delay <= ms(1000));
+        }
+        policy.reset();
+        let delay = policy.next_delay();
+        assert!(delay >= ms(50) && delay <= ms(100));
+    }
+
+    #[test]
+    fn test_connect_with_retry() {
+        // Find a port that refuses connections.
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let mut client = TCPClient::new("127.0.0.1", port, true);
+        let mut policy = BackoffPolicy::new(Duration::from_millis(1), Duration::from_millis(2));
+        assert!(matches!(
+            connect_with_retry(&mut client, &mut policy, 3),
+            Err(ClientError::Connect(_))
+        ));
+        assert_eq!(policy.current, Some(Duration::from_millis(2)));
+    }
+}
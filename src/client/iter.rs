@@ -0,0 +1,113 @@
+use super::{Client, ClientError, Packet};
+use std::thread;
+
+// Iterator over the packets of a client, ending when reading would block.
+// Iterating again later continues with packets received since.
+pub struct PacketIter<'a> {
+    client: &'a mut dyn Client,
+}
+
+impl<'a> PacketIter<'a> {
+    pub fn new(client: &'a mut dyn Client) -> Self {
+        PacketIter { client }
+    }
+}
+
+impl<'a> Iterator for PacketIter<'a> {
+    type Item = Result<Packet, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.client.next() {
+            Err(ClientError::ReadWouldBlock) => None,
+            res => Some(res),
+        }
+    }
+}
+
+// Iterator over the packets of a client, retrying while reading would
+// block. Invalid packets and non-monotonic timestamps are yielded as errors
+// and iteration continues; any other error is yielded and ends iteration.
+pub struct BlockingPacketIter<'a> {
+    client: &'a mut dyn Client,
+    done: bool,
+}
+
+impl<'a> BlockingPacketIter<'a> {
+    pub fn new(client: &'a mut dyn Client) -> Self {
+        BlockingPacketIter {
+            client,
+            done: false,
+        }
+    }
+}
+
+impl<'a> Iterator for BlockingPacketIter<'a> {
+    type Item = Result<Packet, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            match self.client.next() {
+                Err(ClientError::ReadWouldBlock) => thread::yield_now(),
+                res @ Ok(_)
+                | res @ Err(ClientError::InvalidPacket(_))
+                | res @ Err(ClientError::NonMonotonicTimestamp { .. }) => return Some(res),
+                res => {
+                    self.done = true;
+                    return Some(res);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{test_client::VecClient, test_packets::PACKET_FRAME_NUMBER, TCPClient},
+        se_types::SEOutputData,
+        test_support::packet_of,
+    };
+    use std::{io::Write, net::TcpListener};
+
+    #[test]
+    fn test_packet_iter() {
+        let packets = vec![
+            packet_of(vec![SEOutputData::SEFrameNumber(1)]),
+            packet_of(vec![SEOutputData::SEFrameNumber(2)]),
+        ];
+        let mut client = VecClient(packets.clone());
+        let read: Vec<Packet> = client.iter().map(Result::unwrap).collect();
+        assert_eq!(read, packets);
+
+        client.0.push(packets[0].clone());
+        assert_eq!(PacketIter::new(&mut client).count(), 1);
+        assert_eq!(client.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_blocking_packet_iter() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let sender = thread::spawn(move || {
+            let (mut server, _) = listener.accept().unwrap();
+            server.write_all(PACKET_FRAME_NUMBER).unwrap();
+            thread::sleep(std::time::Duration::from_millis(20));
+            server.write_all(PACKET_FRAME_NUMBER).unwrap();
+        });
+        let mut client = TCPClient::new("127.0.0.1", port, true);
+        client.connect().unwrap();
+
+        let results: Vec<_> = client.blocking_iter().collect();
+        sender.join().unwrap();
+        let expected = packet_of(vec![SEOutputData::SEFrameNumber(17819)]);
+        assert_eq!(results.len(), 3);
+        assert!(results[..2]
+            .iter()
+            .all(|p| p.as_ref().ok() == Some(&expected)));
+        assert!(matches!(results[2], Err(ClientError::Disconnected)));
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Client, ClientError, Packet};
+
+// Client wrapper returning only the most recent packet: next reads all
+// packets immediately available from the (non-blocking) inner client and
+// returns the last of them, dropping the rest. Useful when stale packets are
+// of no interest, e.g. for rendering.
+pub struct LatestClient<C> {
+    client: C,
+    dropped: u64,
+    // Error of the inner client met while draining, returned by the next
+    // call to next.
+    pending_error: Option<ClientError>,
+}
+
+impl<C: Client> LatestClient<C> {
+    pub fn new(client: C) -> Self {
+        LatestClient {
+            client,
+            dropped: 0,
+            pending_error: None,
+        }
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+
+    // Number of packets dropped for being superseded by a newer packet.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+impl<C: Client> Client for LatestClient<C> {
+    fn connect(&mut self) -> Result<(), ClientError> {
+        self.pending_error = None;
+        self.client.connect()
+    }
+
+    fn disconnect(&mut self) -> Result<(), ClientError> {
+        self.client.disconnect()
+    }
+
+    fn next(&mut self) -> Result<Packet, ClientError> {
+        if let Some(err) = self.pending_error.take() {
+            return Err(err);
+        }
+        let mut latest = self.client.next()?;
+        loop {
+            match self.client.next() {
+                Ok(packet) => {
+                    latest = packet;
+                    self.dropped += 1;
+                }
+                Err(ClientError::ReadWouldBlock) => return Ok(latest),
+                Err(err) => {
+                    self.pending_error = Some(err);
+                    return Ok(latest);
+                }
+            }
+        }
+    }
+
+    fn pause(&mut self) {
+        self.client.pause()
+    }
+
+    fn resume(&mut self) {
+        self.client.resume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::test_client::VecClient, se_types::SEOutputData, test_support::packet_of};
+
+    #[test]
+    fn test_latest_client() {
+        let mut client = LatestClient::new(VecClient(vec![
+            packet_of(vec![SEOutputData::SEFrameNumber(1)]),
+            packet_of(vec![SEOutputData::SEFrameNumber(2)]),
+            packet_of(vec![SEOutputData::SEFrameNumber(3)]),
+        ]));
+        client.connect().unwrap();
+        assert_eq!(client.next().unwrap()[..], [SEOutputData::SEFrameNumber(3)]);
+        assert_eq!(client.dropped(), 2);
+        assert!(matches!(client.next(), Err(ClientError::ReadWouldBlock)));
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{parser, se_types::SEOutputData};
+use std::io;
+use thiserror::Error;
+
+mod backoff;
+mod iter;
+mod latest;
+mod monotonic;
+mod stream_reader;
+mod tcp;
+mod throttled;
+mod udp;
+#[cfg(unix)]
+mod unix;
+
+pub use backoff::{connect_with_retry, BackoffPolicy};
+pub use iter::{BlockingPacketIter, PacketIter};
+pub use latest::LatestClient;
+pub use monotonic::MonotonicClient;
+pub use tcp::TCPClient;
+pub use throttled::{ErrorSummary, ThrottledClient};
+pub use udp::UDPClient;
+#[cfg(unix)]
+pub use unix::UnixClient;
+
+#[cfg(not(feature = "smallvec"))]
+pub type Packet = Vec<SEOutputData>;
+// Typical packets have few enough fields to be stored inline.
+#[cfg(feature = "smallvec")]
+pub type Packet = smallvec_crate::SmallVec<[SEOutputData; 8]>;
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("could not connect")]
+    Connect(#[source] io::Error),
+
+    #[error("failed disconnecting")]
+    Disconnect(#[source] io::Error),
+
+    #[error("read failed")]
+    Read(#[source] io::Error),
+
+    #[error("read would block")]
+    ReadWouldBlock,
+
+    #[error("client is paused")]
+    Paused,
+
+    #[error("send failed")]
+    Send(#[source] io::Error),
+
+    #[error("server disconnected")]
+    Disconnected,
+
+    // The operation is not valid in the current state of the client, e.g.
+    // reading before connecting or connecting twice.
+    #[error("invalid client state")]
+    InvalidState,
+
+    // No data was received for longer than the read timeout.
+    #[error("read timed out")]
+    Timeout,
+
+    #[error("invalid packet")]
+    InvalidPacket(#[source] parser::ParseError),
+
+    #[error("timestamp {got} not greater than previous timestamp {prev}")]
+    NonMonotonicTimestamp { prev: u64, got: u64 },
+}
+
+pub trait Client {
+    fn connect(&mut self) -> Result<(), ClientError>;
+    fn disconnect(&mut self) -> Result<(), ClientError>;
+
+    fn next(&mut self) -> Result<Packet, ClientError>;
+
+    // Stops reading from the connection, without disconnecting, until
+    // resume is called. While paused, next returns ClientError::Paused.
+    // Data keeps being buffered by the OS; for TCP a full receive buffer
+    // makes the server block or drop data, for UDP datagrams that do not
+    // fit the receive buffer are dropped.
+    fn pause(&mut self);
+    fn resume(&mut self);
+
+    // Like next, but decodes the packet into packet (replacing its content),
+    // allowing its allocation to be reused between calls.
+    fn next_into(&mut self, packet: &mut Packet) -> Result<(), ClientError> {
+        *packet = self.next()?;
+        Ok(())
+    }
+
+    // Iterator over the packets read until reading would block, e.g.
+    // for packet in client.iter() { ... }. For a dyn Client, use
+    // PacketIter::new instead.
+    fn iter(&mut self) -> PacketIter<'_>
+    where
+        Self: Sized,
+    {
+        PacketIter::new(self)
+    }
+
+    // Like iter, but waits for more packets when reading would block.
+    fn blocking_iter(&mut self) -> BlockingPacketIter<'_>
+    where
+        Self: Sized,
+    {
+        BlockingPacketIter::new(self)
+    }
+}
+
+// Reads from client until n packets have been read, retrying while the read
+// would block. Invalid packets and non-monotonic timestamps are included in
+// the result, without counting towards n, and reading continues; any other
+// error is included as the last element and stops reading.
+pub fn take_packets(client: &mut dyn Client, n: usize) -> Vec<Result<Packet, ClientError>> {
+    let mut results = Vec::with_capacity(n);
+    let mut packets = 0;
+    while packets < n {
+        match client.next() {
+            Ok(packet) => {
+                packets += 1;
+                results.push(Ok(packet));
+            }
+            Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+            Err(err @ ClientError::InvalidPacket(_))
+            | Err(err @ ClientError::NonMonotonicTimestamp { .. }) => results.push(Err(err)),
+            Err(err) => {
+                results.push(Err(err));
+                break;
+            }
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod test_packets {
+    pub const PACKET_FRAME_NUMBER: &[u8] = &[
+        // Packet Header
+        0x53, 0x45, 0x50, 0x44, // Sync Id
+        0x00, 0x04, // Packet type
+        0x00, 0x08, // Packet length
+        // Subpacket header
+        0x00, 0x01, // Id (0x0001 = SEFrameNumber)
+        0x00, 0x04, // Length
+        // Subpacket data
+        0x00, 0x00, 0x45, 0x9B,
+    ];
+}
+
+#[cfg(test)]
+mod test_client {
+    use super::{Client, ClientError, Packet};
+
+    // Client returning the given packets, then failing with ReadWouldBlock.
+    pub struct VecClient(pub Vec<Packet>);
+
+    impl Client for VecClient {
+        fn connect(&mut self) -> Result<(), ClientError> {
+            Ok(())
+        }
+
+        fn disconnect(&mut self) -> Result<(), ClientError> {
+            Ok(())
+        }
+
+        fn next(&mut self) -> Result<Packet, ClientError> {
+            if self.0.is_empty() {
+                Err(ClientError::ReadWouldBlock)
+            } else {
+                Ok(self.0.remove(0))
+            }
+        }
+
+        fn pause(&mut self) {}
+
+        fn resume(&mut self) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::packet_of;
+    use std::{io::Write, net::TcpListener, thread};
+    use test_packets::PACKET_FRAME_NUMBER;
+
+    #[test]
+    fn test_take_packets() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let sender = thread::spawn(move || {
+            let (mut server, _) = listener.accept().unwrap();
+            for _ in 0..3 {
+                server.write_all(PACKET_FRAME_NUMBER).unwrap();
+            }
+            server
+        });
+        let mut client = TCPClient::new("127.0.0.1", port, true);
+        client.connect().unwrap();
+
+        let packets = take_packets(&mut client, 3);
+        let expected = packet_of(vec![SEOutputData::SEFrameNumber(17819)]);
+        assert_eq!(packets.len(), 3);
+        assert!(packets.iter().all(|p| p.as_ref().ok() == Some(&expected)));
+
+        // The sender disconnecting stops reading.
+        drop(sender.join().unwrap());
+        let packets = take_packets(&mut client, 1);
+        assert!(matches!(packets[..], [Err(ClientError::Disconnected)]));
+    }
+}
@@ -0,0 +1,89 @@
+use super::{Client, ClientError, Packet};
+use crate::packet;
+
+// Client wrapper checking that the SETimeStamp of each packet is strictly
+// greater than that of the previous packet, failing with
+// ClientError::NonMonotonicTimestamp otherwise. Packets without a timestamp
+// are passed through unchecked.
+pub struct MonotonicClient<C> {
+    client: C,
+    prev: Option<u64>,
+}
+
+impl<C: Client> MonotonicClient<C> {
+    pub fn new(client: C) -> Self {
+        MonotonicClient { client, prev: None }
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+
+    fn check(&mut self, packet: &Packet) -> Result<(), ClientError> {
+        let got = match packet::timestamp(packet) {
+            Some(got) => got,
+            None => return Ok(()),
+        };
+        match self.prev.replace(got) {
+            Some(prev) if got <= prev => Err(ClientError::NonMonotonicTimestamp { prev, got }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<C: Client> Client for MonotonicClient<C> {
+    fn connect(&mut self) -> Result<(), ClientError> {
+        self.prev = None;
+        self.client.connect()
+    }
+
+    fn disconnect(&mut self) -> Result<(), ClientError> {
+        self.client.disconnect()
+    }
+
+    fn next(&mut self) -> Result<Packet, ClientError> {
+        let packet = self.client.next()?;
+        self.check(&packet)?;
+        Ok(packet)
+    }
+
+    fn pause(&mut self) {
+        self.client.pause()
+    }
+
+    fn resume(&mut self) {
+        self.client.resume()
+    }
+
+    fn next_into(&mut self, packet: &mut Packet) -> Result<(), ClientError> {
+        self.client.next_into(packet)?;
+        self.check(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::test_client::VecClient, se_types::SEOutputData, test_support::packet_of};
+
+    #[test]
+    fn test_monotonic_client() {
+        let mut client = MonotonicClient::new(VecClient(vec![
+            packet_of(vec![SEOutputData::SETimeStamp(100)]),
+            packet_of(vec![SEOutputData::SEFrameNumber(1)]),
+            packet_of(vec![SEOutputData::SETimeStamp(200)]),
+            packet_of(vec![SEOutputData::SETimeStamp(150)]),
+        ]));
+        client.connect().unwrap();
+        assert!(client.next().is_ok());
+        assert!(client.next().is_ok());
+        assert!(client.next().is_ok());
+        assert!(matches!(
+            client.next(),
+            Err(ClientError::NonMonotonicTimestamp {
+                prev: 200,
+                got: 150
+            })
+        ));
+    }
+}
@@ -0,0 +1,304 @@
+use super::{ClientError, Packet};
+use crate::parser;
+use std::{
+    cmp,
+    io::{self, Read},
+    net::{Shutdown, TcpStream},
+    time::{Duration, Instant},
+};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+// A connected byte stream that SEPD packets can be read from.
+pub(super) trait Stream: Read {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()>;
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()>;
+    fn shutdown(&self) -> io::Result<()>;
+}
+
+impl Stream for TcpStream {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        TcpStream::set_nonblocking(self, nonblocking)
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        TcpStream::set_read_timeout(self, timeout)
+    }
+
+    fn shutdown(&self) -> io::Result<()> {
+        TcpStream::shutdown(self, Shutdown::Both)
+    }
+}
+
+#[cfg(unix)]
+impl Stream for UnixStream {
+    fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        UnixStream::set_nonblocking(self, nonblocking)
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        UnixStream::set_read_timeout(self, timeout)
+    }
+
+    fn shutdown(&self) -> io::Result<()> {
+        UnixStream::shutdown(self, Shutdown::Both)
+    }
+}
+
+// Buffered reader splitting a stream into packets.
+pub(super) struct StreamReader<S> {
+    pub stream: S,
+    buf: Vec<u8>,
+    pos: usize,
+    deadline: Option<Instant>,
+    // Mode of the stream outside of next_timeout.
+    nonblocking: bool,
+    // See set_read_timeout.
+    read_timeout: Option<Duration>,
+    last_read: Instant,
+    // Set while searching for the next packet header after having lost
+    // track of the packet boundaries.
+    resyncing: bool,
+}
+
+impl<S: Stream> StreamReader<S> {
+    pub fn new(stream: S, nonblocking: bool) -> Self {
+        let buf = Vec::new();
+        StreamReader {
+            stream,
+            buf,
+            pos: 0,
+            deadline: None,
+            nonblocking,
+            read_timeout: None,
+            last_read: Instant::now(),
+            resyncing: false,
+        }
+    }
+
+    // Fails reads with ClientError::Timeout once no bytes have been received
+    // for longer than timeout, in both blocking and non-blocking mode. Reads
+    // by next_timeout are not affected.
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) -> io::Result<()> {
+        if !self.nonblocking {
+            self.stream.set_read_timeout(timeout)?;
+        }
+        self.read_timeout = timeout;
+        self.last_read = Instant::now();
+        Ok(())
+    }
+
+    // Error for a read that would block or timed out.
+    fn would_block(&self) -> ClientError {
+        match self.read_timeout {
+            Some(timeout) if self.deadline.is_none() && self.last_read.elapsed() >= timeout => {
+                ClientError::Timeout
+            }
+            _ => ClientError::ReadWouldBlock,
+        }
+    }
+
+    pub fn buffer(&mut self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    fn grow(&mut self, wanted: usize) -> Result<(), ClientError> {
+        let available = self.buf.capacity() - self.pos;
+        if available < wanted {
+            // Not enough remaining capacity, have to re-allocate.
+            // Here we also reset pos, by moving the remaining last items from
+            // old Vec to the front of the new Vec.
+            let required = self.pos + wanted;
+            let new_cap = cmp::max(required, self.buf.capacity());
+            let mut new_buf: Vec<u8> = Vec::with_capacity(new_cap);
+            new_buf.extend(&self.buf[self.pos..]);
+            self.buf = new_buf;
+            self.pos = 0;
+        }
+        // Only bytes actually read are kept in buf, so that a read failing
+        // part way (e.g. would block) can be resumed by a later call.
+        let target_len = self.buf.len() + wanted;
+        while self.buf.len() < target_len {
+            self.apply_deadline()?;
+            let old_len = self.buf.len();
+            self.buf.resize(target_len, 0u8);
+            let res = self.stream.read(&mut self.buf[old_len..]);
+            self.buf.truncate(old_len + res.as_ref().map_or(0, |n| *n));
+            match res {
+                Ok(0) => return Err(ClientError::Disconnected),
+                Ok(_) => self.last_read = Instant::now(),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(ref e)
+                    if e.kind() == io::ErrorKind::WouldBlock
+                        || e.kind() == io::ErrorKind::TimedOut =>
+                {
+                    return Err(self.would_block())
+                }
+                Err(e) => return Err(ClientError::Read(e)),
+            }
+        }
+        Ok(())
+    }
+
+    // Limits the blocking time of the next read to the time remaining
+    // until the deadline, if one is set.
+    fn apply_deadline(&mut self) -> Result<(), ClientError> {
+        if let Some(deadline) = self.deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining == Duration::from_secs(0) {
+                return Err(ClientError::ReadWouldBlock);
+            }
+            self.stream
+                .set_read_timeout(Some(remaining))
+                .map_err(ClientError::Read)?;
+        }
+        Ok(())
+    }
+
+    fn reserve(&mut self, additional: usize) -> Result<(), ClientError> {
+        let current_length = self.buffer().len();
+        if current_length < additional {
+            let needed_length = additional - current_length;
+            self.grow(needed_length)?;
+        }
+        Ok(())
+    }
+
+    pub fn peek(&mut self, n: usize) -> Result<&[u8], ClientError> {
+        self.reserve(n)?;
+        Ok(&self.buffer()[..n])
+    }
+
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.pos = 0;
+        // The stream is no longer known to be at a packet boundary.
+        self.resyncing = true;
+    }
+
+    pub fn consume(&mut self, n: usize) {
+        if self.buffer().len() < n {
+            panic!("consume out of range")
+        }
+        self.pos += n;
+    }
+
+    // Seeks the stream until a valid packet header is found, leaving it
+    // unconsumed.
+    fn seek_packet_header(&mut self) -> Result<parser::PacketHeader, ClientError> {
+        loop {
+            let header_buf = self.peek(parser::PACKET_HEADER_SIZE)?;
+            if let Ok(packet_header) = parser::parse_packet_header(header_buf) {
+                // When resyncing, the header might just as well be "SEPD"
+                // occurring within the data of a packet.
+                if !self.resyncing || self.is_plausible_packet(packet_header)? {
+                    return Ok(packet_header);
+                }
+            }
+            // Invalid header, skip forward 1 byte.
+            self.consume(1);
+            self.resyncing = true;
+        }
+    }
+
+    fn is_plausible_packet(&mut self, header: parser::PacketHeader) -> Result<bool, ClientError> {
+        let data_len = cmp::min(header.length as usize, parser::SUB_PACKET_HEADER_SIZE);
+        let buf = self.peek(parser::PACKET_HEADER_SIZE + data_len)?;
+        Ok(parser::is_plausible_packet_data(
+            header,
+            &buf[parser::PACKET_HEADER_SIZE..],
+        ))
+    }
+
+    pub fn next_into(&mut self, packet: &mut Packet) -> Result<(), ClientError> {
+        loop {
+            let packet_header = self.seek_packet_header()?;
+            // Parse packet data. The header is only consumed together with
+            // the data, so that a partially received packet is kept buffered
+            // until the next call.
+            let packet_size = parser::PACKET_HEADER_SIZE + packet_header.length as usize;
+            let buf = self.peek(packet_size)?;
+            let res = parser::parse_packet_data_into(
+                packet_header,
+                &buf[parser::PACKET_HEADER_SIZE..],
+                packet,
+            );
+            if res.is_err() && self.resyncing {
+                // Not a packet after all, keep searching.
+                self.consume(1);
+                continue;
+            }
+            self.consume(packet_size);
+            self.resyncing = false;
+            return res.map_err(ClientError::InvalidPacket);
+        }
+    }
+
+    // Blocks for at most timeout waiting for the next packet, returning
+    // Ok(None) if no complete packet arrived in time. Bytes of a partially
+    // received packet are kept and used by the next call.
+    pub fn next_timeout(&mut self, timeout: Duration) -> Result<Option<Packet>, ClientError> {
+        self.stream
+            .set_nonblocking(false)
+            .map_err(ClientError::Read)?;
+        self.deadline = Some(Instant::now() + timeout);
+
+        let mut packet = Packet::new();
+        let res = self.next_into(&mut packet);
+
+        self.deadline = None;
+        self.stream
+            .set_read_timeout(self.read_timeout.filter(|_| !self.nonblocking))
+            .and_then(|_| self.stream.set_nonblocking(self.nonblocking))
+            .map_err(ClientError::Read)?;
+        match res {
+            Ok(()) => Ok(Some(packet)),
+            Err(ClientError::ReadWouldBlock) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{se_types::SEOutputData, serializer::serialize_packet};
+
+    impl Stream for io::Cursor<Vec<u8>> {
+        fn set_nonblocking(&self, _nonblocking: bool) -> io::Result<()> {
+            Ok(())
+        }
+
+        fn set_read_timeout(&self, _timeout: Option<Duration>) -> io::Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_stream_reader_resync_ignores_sync_id_in_data() {
+        // The time stamp is "SEPD", packet type and a length of 16, i.e. a
+        // valid packet header.
+        let first = serialize_packet(&[
+            SEOutputData::SETimeStamp(0x5345_5044_0004_0010),
+            SEOutputData::SEFrameNumber(1),
+        ]);
+        let second = serialize_packet(&[SEOutputData::SEFrameNumber(2)]);
+        // Start reading in the middle of the first packet, just before the
+        // time stamp.
+        let data = [&first[parser::PACKET_HEADER_SIZE + 2..], &second].concat();
+        let mut reader = StreamReader::new(io::Cursor::new(data), true);
+
+        let mut packet = Packet::new();
+        reader.next_into(&mut packet).unwrap();
+        assert_eq!(packet[..], [SEOutputData::SEFrameNumber(2)]);
+        assert!(matches!(
+            reader.next_into(&mut packet),
+            Err(ClientError::Disconnected)
+        ));
+    }
+}
@@ -0,0 +1,359 @@
+use super::{
+    stream_reader::{Stream, StreamReader},
+    Client, ClientError, Packet,
+};
+use std::{
+    net::{SocketAddr, TcpStream},
+    time::Duration,
+};
+
+enum TCPClientState {
+    Pending {
+        addr: String,
+        nonblocking: bool,
+    },
+    Connected {
+        stream_reader: StreamReader<TcpStream>,
+    },
+    Disconnected,
+}
+
+pub struct TCPClient {
+    state: TCPClientState,
+    paused: bool,
+    read_timeout: Option<Duration>,
+}
+
+impl TCPClient {
+    // With nonblocking false, next blocks until a packet arrives rather than
+    // failing with ClientError::ReadWouldBlock.
+    pub fn new(hostname: &str, port: u16, nonblocking: bool) -> Self {
+        let addr = format!("{}:{}", hostname, port);
+        let state = TCPClientState::Pending { addr, nonblocking };
+        TCPClient {
+            state,
+            paused: false,
+            read_timeout: None,
+        }
+    }
+
+    // Client reading from an already connected stream, such as one from a
+    // connection pool. The client starts out connected, with the stream in
+    // the given blocking mode (see new).
+    pub fn from_stream(stream: TcpStream, nonblocking: bool) -> Result<Self, ClientError> {
+        stream
+            .set_nonblocking(nonblocking)
+            .map_err(ClientError::Connect)?;
+        let stream_reader = StreamReader::new(stream, nonblocking);
+        Ok(TCPClient {
+            state: TCPClientState::Connected { stream_reader },
+            paused: false,
+            read_timeout: None,
+        })
+    }
+
+    // Makes reads fail with ClientError::Timeout once no data has been
+    // received from the server for longer than timeout, e.g. for reconnecting
+    // to a stalled server. In non-blocking mode, reads keep failing with
+    // ClientError::ReadWouldBlock until then. Applied on connect, or
+    // immediately if already connected.
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) -> Result<(), ClientError> {
+        self.read_timeout = timeout;
+        match &mut self.state {
+            TCPClientState::Connected { stream_reader } => stream_reader
+                .set_read_timeout(timeout)
+                .map_err(ClientError::Connect),
+            _ => Ok(()),
+        }
+    }
+
+    // Address of the server, if connected.
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        match &self.state {
+            TCPClientState::Connected { stream_reader } => stream_reader.stream.peer_addr().ok(),
+            _ => None,
+        }
+    }
+
+    // Discards all buffered but not yet consumed bytes, so that reading
+    // resumes with fresh data from the stream. Any partially received packet
+    // in the buffer is lost.
+    pub fn reset_buffer(&mut self) {
+        if let TCPClientState::Connected { stream_reader } = &mut self.state {
+            stream_reader.clear();
+        }
+    }
+
+    // Blocks for at most timeout waiting for the next packet, returning
+    // Ok(None) if no complete packet arrived in time. Bytes of a partially
+    // received packet are kept and used by the next call.
+    pub fn next_timeout(&mut self, timeout: Duration) -> Result<Option<Packet>, ClientError> {
+        if self.paused {
+            return Err(ClientError::Paused);
+        }
+        match &mut self.state {
+            TCPClientState::Connected { stream_reader } => stream_reader.next_timeout(timeout),
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+}
+
+impl Client for TCPClient {
+    fn connect(&mut self) -> Result<(), ClientError> {
+        match &self.state {
+            TCPClientState::Pending { addr, nonblocking } => {
+                let stream = TcpStream::connect(addr.as_str()).map_err(ClientError::Connect)?;
+                stream
+                    .set_nonblocking(*nonblocking)
+                    .map_err(ClientError::Connect)?;
+                let mut stream_reader = StreamReader::new(stream, *nonblocking);
+                stream_reader
+                    .set_read_timeout(self.read_timeout)
+                    .map_err(ClientError::Connect)?;
+                self.state = TCPClientState::Connected { stream_reader };
+                Ok(())
+            }
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+
+    fn disconnect(&mut self) -> Result<(), ClientError> {
+        match &self.state {
+            TCPClientState::Connected { stream_reader } => {
+                let shutdown_res =
+                    Stream::shutdown(&stream_reader.stream).map_err(ClientError::Disconnect);
+                self.state = TCPClientState::Disconnected;
+                shutdown_res
+            }
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+
+    fn next(&mut self) -> Result<Packet, ClientError> {
+        let mut packet = Packet::new();
+        self.next_into(&mut packet)?;
+        Ok(packet)
+    }
+
+    fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    fn next_into(&mut self, packet: &mut Packet) -> Result<(), ClientError> {
+        if self.paused {
+            return Err(ClientError::Paused);
+        }
+        if let TCPClientState::Connected { stream_reader } = &mut self.state {
+            stream_reader.next_into(packet)
+        } else {
+            Err(ClientError::InvalidState)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::test_packets::PACKET_FRAME_NUMBER, se_types::SEOutputData, test_support::packet_of,
+    };
+    use std::{io::Write, net::TcpListener};
+
+    fn connected_tcp_client() -> (TCPClient, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mut client = TCPClient::new("127.0.0.1", port, true);
+        client.connect().unwrap();
+        let (server, _) = listener.accept().unwrap();
+        (client, server)
+    }
+
+    #[test]
+    fn test_tcp_client_invalid_state() {
+        let mut client = TCPClient::new("127.0.0.1", 1, true);
+        assert!(matches!(client.next(), Err(ClientError::InvalidState)));
+        assert!(matches!(
+            client.disconnect(),
+            Err(ClientError::InvalidState)
+        ));
+
+        let (mut client, _server) = connected_tcp_client();
+        assert!(matches!(client.connect(), Err(ClientError::InvalidState)));
+        client.disconnect().unwrap();
+        assert!(matches!(client.next(), Err(ClientError::InvalidState)));
+    }
+
+    #[test]
+    fn test_tcp_client_server_disconnect() {
+        let (mut client, server) = connected_tcp_client();
+        drop(server);
+        loop {
+            match client.next() {
+                Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                res => break assert!(matches!(res, Err(ClientError::Disconnected))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_tcp_client_read_timeout() {
+        for &nonblocking in &[true, false] {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let mut client = TCPClient::new("127.0.0.1", port, nonblocking);
+            client
+                .set_read_timeout(Some(Duration::from_millis(50)))
+                .unwrap();
+            client.connect().unwrap();
+            let (mut server, _) = listener.accept().unwrap();
+            // The server stalls mid-packet.
+            server.write_all(&PACKET_FRAME_NUMBER[..10]).unwrap();
+
+            let res = loop {
+                match client.next() {
+                    Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                    res => break res,
+                }
+            };
+            assert!(matches!(res, Err(ClientError::Timeout)), "{:?}", res);
+        }
+    }
+
+    #[test]
+    fn test_tcp_client_next_into() {
+        let (mut client, mut server) = connected_tcp_client();
+        server.write_all(PACKET_FRAME_NUMBER).unwrap();
+
+        let mut packet = packet_of(vec![SEOutputData::SETimeStamp(1)]);
+        loop {
+            match client.next_into(&mut packet) {
+                Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                res => break res.unwrap(),
+            }
+        }
+        assert_eq!(packet[..], [SEOutputData::SEFrameNumber(17819)]);
+    }
+
+    #[test]
+    fn test_tcp_client_from_stream() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+        let mut client = TCPClient::from_stream(stream, false).unwrap();
+
+        let sender = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            server.write_all(PACKET_FRAME_NUMBER).unwrap();
+            server
+        });
+        // Blocks as requested, rather than failing with ReadWouldBlock.
+        assert_eq!(
+            client.next().unwrap()[..],
+            [SEOutputData::SEFrameNumber(17819)]
+        );
+        sender.join().unwrap();
+        client.disconnect().unwrap();
+    }
+
+    #[test]
+    fn test_tcp_client_blocking() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mut client = TCPClient::new("127.0.0.1", port, false);
+        client.connect().unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+
+        let sender = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            server.write_all(PACKET_FRAME_NUMBER).unwrap();
+            server
+        });
+        // Blocks until the packet arrives, rather than failing with
+        // ReadWouldBlock.
+        let expected = packet_of(vec![SEOutputData::SEFrameNumber(17819)]);
+        assert_eq!(client.next().unwrap(), expected);
+        let mut server = sender.join().unwrap();
+
+        // The stream is still blocking after a read with timeout.
+        assert!(matches!(
+            client.next_timeout(Duration::from_millis(10)),
+            Ok(None)
+        ));
+        server.write_all(PACKET_FRAME_NUMBER).unwrap();
+        assert_eq!(client.next().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_tcp_client_reset_buffer() {
+        let (mut client, mut server) = connected_tcp_client();
+        server.write_all(&PACKET_FRAME_NUMBER[..10]).unwrap();
+        assert!(matches!(
+            client.next_timeout(Duration::from_millis(50)),
+            Ok(None)
+        ));
+
+        client.reset_buffer();
+        match &mut client.state {
+            TCPClientState::Connected { stream_reader } => {
+                assert!(stream_reader.buffer().is_empty())
+            }
+            _ => unreachable!(),
+        }
+
+        server.write_all(PACKET_FRAME_NUMBER).unwrap();
+        assert_eq!(
+            client.next_timeout(Duration::from_secs(5)).unwrap(),
+            Some(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
+        );
+    }
+
+    #[test]
+    fn test_tcp_client_pause() {
+        let (mut client, mut server) = connected_tcp_client();
+        server.write_all(PACKET_FRAME_NUMBER).unwrap();
+
+        client.pause();
+        assert!(matches!(client.next(), Err(ClientError::Paused)));
+        assert!(matches!(
+            client.next_timeout(Duration::from_secs(5)),
+            Err(ClientError::Paused)
+        ));
+
+        client.resume();
+        assert_eq!(
+            client.next_timeout(Duration::from_secs(5)).unwrap(),
+            Some(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
+        );
+    }
+
+    #[test]
+    fn test_tcp_client_peer_addr() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut client = TCPClient::new("127.0.0.1", addr.port(), true);
+        assert_eq!(client.peer_addr(), None);
+        client.connect().unwrap();
+        assert_eq!(client.peer_addr(), Some(addr));
+    }
+
+    #[test]
+    fn test_tcp_client_next_timeout_partial_packet() {
+        let (mut client, mut server) = connected_tcp_client();
+        let timeout = Duration::from_millis(50);
+
+        server.write_all(&PACKET_FRAME_NUMBER[..10]).unwrap();
+        assert!(matches!(client.next_timeout(timeout), Ok(None)));
+
+        server.write_all(&PACKET_FRAME_NUMBER[10..]).unwrap();
+        assert_eq!(
+            client.next_timeout(Duration::from_secs(5)).unwrap(),
+            Some(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
+        );
+        // Client is back in non-blocking mode.
+        assert!(matches!(client.next(), Err(ClientError::ReadWouldBlock)));
+    }
+}
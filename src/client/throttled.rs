@@ -0,0 +1,165 @@
+use super::{Client, ClientError, Packet};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    time::{Duration, Instant},
+};
+
+// Errors of a client within a period of time, counted by error message.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ErrorSummary {
+    pub counts: BTreeMap<String, u64>,
+    pub period: Duration,
+}
+
+impl fmt::Display for ErrorSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, (message, count)) in self.counts.iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} \"{}\" errors", count, message)?;
+        }
+        write!(f, " in the last {:.1?}", self.period)
+    }
+}
+
+// Client wrapper for reporting errors without flooding logs when the stream
+// is persistently broken. The first error is reported right away; errors
+// following it within interval are only counted, and reported together as a
+// single summary with the next error after interval has passed (or by
+// flush). ReadWouldBlock and Paused are not counted. Errors are still
+// returned from next as usual.
+pub struct ThrottledClient<C, F> {
+    client: C,
+    interval: Duration,
+    report: F,
+    pending: ErrorSummary,
+    last_report: Option<Instant>,
+    totals: BTreeMap<String, u64>,
+}
+
+impl<C: Client, F: FnMut(&ErrorSummary)> ThrottledClient<C, F> {
+    pub fn new(client: C, interval: Duration, report: F) -> Self {
+        ThrottledClient {
+            client,
+            interval,
+            report,
+            pending: ErrorSummary::default(),
+            last_report: None,
+            totals: BTreeMap::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+
+    // Number of errors seen in total, by error message.
+    pub fn error_counts(&self) -> &BTreeMap<String, u64> {
+        &self.totals
+    }
+
+    // Reports the errors counted since the last report, if any.
+    pub fn flush(&mut self) {
+        let now = Instant::now();
+        if !self.pending.counts.is_empty() {
+            self.pending.period = self.last_report.map_or(Duration::ZERO, |t| now - t);
+            (self.report)(&self.pending);
+            self.pending.counts.clear();
+        }
+        self.last_report = Some(now);
+    }
+
+    fn record(&mut self, err: &ClientError) {
+        if let ClientError::ReadWouldBlock | ClientError::Paused = err {
+            return;
+        }
+        let message = err.to_string();
+        *self.totals.entry(message.clone()).or_insert(0) += 1;
+        *self.pending.counts.entry(message).or_insert(0) += 1;
+        let due = match self.last_report {
+            Some(last_report) => last_report.elapsed() >= self.interval,
+            None => true,
+        };
+        if due {
+            self.flush();
+        }
+    }
+}
+
+impl<C: Client, F: FnMut(&ErrorSummary)> Client for ThrottledClient<C, F> {
+    fn connect(&mut self) -> Result<(), ClientError> {
+        self.client.connect().inspect_err(|e| self.record(e))
+    }
+
+    fn disconnect(&mut self) -> Result<(), ClientError> {
+        self.client.disconnect().inspect_err(|e| self.record(e))
+    }
+
+    fn next(&mut self) -> Result<Packet, ClientError> {
+        self.client.next().inspect_err(|e| self.record(e))
+    }
+
+    fn pause(&mut self) {
+        self.client.pause()
+    }
+
+    fn resume(&mut self) {
+        self.client.resume()
+    }
+
+    fn next_into(&mut self, packet: &mut Packet) -> Result<(), ClientError> {
+        self.client
+            .next_into(packet)
+            .inspect_err(|e| self.record(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ParseError;
+
+    struct BrokenClient;
+
+    impl Client for BrokenClient {
+        fn connect(&mut self) -> Result<(), ClientError> {
+            Ok(())
+        }
+
+        fn disconnect(&mut self) -> Result<(), ClientError> {
+            Ok(())
+        }
+
+        fn next(&mut self) -> Result<Packet, ClientError> {
+            Err(ClientError::InvalidPacket(ParseError::Failed))
+        }
+
+        fn pause(&mut self) {}
+
+        fn resume(&mut self) {}
+    }
+
+    #[test]
+    fn test_throttled_client() {
+        let mut reports = Vec::new();
+        let mut client = ThrottledClient::new(BrokenClient, Duration::from_secs(3600), |summary| {
+            reports.push(summary.clone())
+        });
+        for _ in 0..5 {
+            assert!(matches!(client.next(), Err(ClientError::InvalidPacket(_))));
+        }
+        assert_eq!(client.error_counts().get("invalid packet"), Some(&5));
+        client.flush();
+        client.flush();
+        drop(client);
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].counts.get("invalid packet"), Some(&1));
+        assert_eq!(reports[1].counts.get("invalid packet"), Some(&4));
+        assert!(reports[1]
+            .to_string()
+            .starts_with("4 \"invalid packet\" errors in the last"));
+    }
+}
@@ -0,0 +1,450 @@
+use super::{Client, ClientError, Packet};
+use crate::parser;
+use socket2::SockRef;
+use std::{
+    io,
+    net::{Ipv4Addr, SocketAddr, UdpSocket},
+};
+
+enum UDPClientState {
+    Pending {
+        addr: String,
+        nonblocking: bool,
+    },
+    // buf[pos..len] are the bytes of the last datagram not yet decoded, as a
+    // datagram may contain multiple packets.
+    Connected {
+        socket: UdpSocket,
+        buf: Vec<u8>,
+        pos: usize,
+        len: usize,
+    },
+    Disconnected,
+}
+
+impl UDPClientState {
+    fn connected(socket: UdpSocket) -> Self {
+        // Pre-allocate buf.
+        let buf = vec![0; u16::MAX as usize];
+        UDPClientState::Connected {
+            socket,
+            buf,
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+pub struct UDPClient {
+    state: UDPClientState,
+    paused: bool,
+    recv_buffer_size: Option<usize>,
+    // Joined multicast groups, as (group, interface).
+    multicast_groups: Vec<(Ipv4Addr, Ipv4Addr)>,
+}
+
+impl UDPClient {
+    // With nonblocking false, next blocks until a datagram arrives rather
+    // than failing with ClientError::ReadWouldBlock.
+    pub fn new(port: u16, nonblocking: bool) -> Self {
+        let addr = format!("0.0.0.0:{}", port);
+        let state = UDPClientState::Pending { addr, nonblocking };
+        UDPClient {
+            state,
+            paused: false,
+            recv_buffer_size: None,
+            multicast_groups: Vec::new(),
+        }
+    }
+
+    // Client receiving on an already bound socket, such as one with socket
+    // options set by the caller. The client starts out connected, with the
+    // socket in the given blocking mode (see new).
+    pub fn from_socket(socket: UdpSocket, nonblocking: bool) -> Result<Self, ClientError> {
+        socket
+            .set_nonblocking(nonblocking)
+            .map_err(ClientError::Connect)?;
+        Ok(UDPClient {
+            state: UDPClientState::connected(socket),
+            paused: false,
+            recv_buffer_size: None,
+            multicast_groups: Vec::new(),
+        })
+    }
+
+    // Sets the size of the socket's receive buffer (SO_RCVBUF), in bytes.
+    // A larger buffer reduces the number of datagrams dropped when packets
+    // are not read fast enough. Applied on connect, or immediately if
+    // already connected.
+    pub fn set_recv_buffer_size(&mut self, bytes: usize) -> Result<(), ClientError> {
+        self.recv_buffer_size = Some(bytes);
+        match &self.state {
+            UDPClientState::Connected { socket, .. } => SockRef::from(socket)
+                .set_recv_buffer_size(bytes)
+                .map_err(ClientError::Connect),
+            _ => Ok(()),
+        }
+    }
+
+    // Size of the socket's receive buffer as granted by the OS, which may
+    // differ from the requested size (e.g. Linux doubles it). None unless
+    // connected.
+    pub fn recv_buffer_size(&self) -> Option<usize> {
+        match &self.state {
+            UDPClientState::Connected { socket, .. } => {
+                SockRef::from(socket).recv_buffer_size().ok()
+            }
+            _ => None,
+        }
+    }
+
+    // Joins the multicast group on the interface with the given address
+    // (0.0.0.0 to let the OS choose), to receive packets sent to the group.
+    // Applied on connect, or immediately if already connected. Joined groups
+    // are left on disconnect.
+    pub fn join_multicast(
+        &mut self,
+        group: Ipv4Addr,
+        interface: Ipv4Addr,
+    ) -> Result<(), ClientError> {
+        if let UDPClientState::Connected { socket, .. } = &self.state {
+            socket
+                .join_multicast_v4(&group, &interface)
+                .map_err(ClientError::Connect)?;
+        }
+        self.multicast_groups.push((group, interface));
+        Ok(())
+    }
+
+    // Leaves a group joined by join_multicast.
+    pub fn leave_multicast(
+        &mut self,
+        group: Ipv4Addr,
+        interface: Ipv4Addr,
+    ) -> Result<(), ClientError> {
+        self.multicast_groups
+            .retain(|&joined| joined != (group, interface));
+        match &self.state {
+            UDPClientState::Connected { socket, .. } => socket
+                .leave_multicast_v4(&group, &interface)
+                .map_err(ClientError::Disconnect),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn multicast_groups(&self) -> &[(Ipv4Addr, Ipv4Addr)] {
+        &self.multicast_groups
+    }
+
+    // Address of the peer the socket is restricted to, if any.
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        match &self.state {
+            UDPClientState::Connected { socket, .. } => socket.peer_addr().ok(),
+            _ => None,
+        }
+    }
+
+    // Restricts the client to only receive packets from peer, and enables
+    // sending to it. Requires the client to be connected.
+    pub fn connect_to(&mut self, peer: SocketAddr) -> Result<(), ClientError> {
+        match &self.state {
+            UDPClientState::Connected { socket, .. } => {
+                socket.connect(peer).map_err(ClientError::Connect)
+            }
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+
+    // Sends buf to the peer set by connect_to.
+    pub fn send(&mut self, buf: &[u8]) -> Result<usize, ClientError> {
+        match &self.state {
+            UDPClientState::Connected { socket, .. } => socket.send(buf).map_err(ClientError::Send),
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+}
+
+impl Client for UDPClient {
+    fn connect(&mut self) -> Result<(), ClientError> {
+        match &self.state {
+            UDPClientState::Pending { addr, nonblocking } => {
+                let socket = UdpSocket::bind(addr.as_str()).map_err(ClientError::Connect)?;
+                socket
+                    .set_nonblocking(*nonblocking)
+                    .map_err(ClientError::Connect)?;
+                if let Some(size) = self.recv_buffer_size {
+                    SockRef::from(&socket)
+                        .set_recv_buffer_size(size)
+                        .map_err(ClientError::Connect)?;
+                }
+                for (group, interface) in &self.multicast_groups {
+                    socket
+                        .join_multicast_v4(group, interface)
+                        .map_err(ClientError::Connect)?;
+                }
+                self.state = UDPClientState::connected(socket);
+                Ok(())
+            }
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+
+    fn disconnect(&mut self) -> Result<(), ClientError> {
+        match &self.state {
+            UDPClientState::Connected { socket, .. } => {
+                // Leave all groups before reporting the first failure.
+                let leave_res = self
+                    .multicast_groups
+                    .drain(..)
+                    .map(|(group, interface)| socket.leave_multicast_v4(&group, &interface))
+                    .fold(Ok(()), Result::and)
+                    .map_err(ClientError::Disconnect);
+                self.state = UDPClientState::Disconnected;
+                leave_res
+            }
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+
+    fn next(&mut self) -> Result<Packet, ClientError> {
+        let mut packet = Packet::new();
+        self.next_into(&mut packet)?;
+        Ok(packet)
+    }
+
+    fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    fn next_into(&mut self, packet: &mut Packet) -> Result<(), ClientError> {
+        if self.paused {
+            return Err(ClientError::Paused);
+        }
+        if let UDPClientState::Connected {
+            socket,
+            buf,
+            pos,
+            len,
+        } = &mut self.state
+        {
+            if *pos >= *len {
+                let (n, _from) = socket.recv_from(&mut buf[..]).map_err(|e| match e {
+                    ref e if e.kind() == io::ErrorKind::WouldBlock => ClientError::ReadWouldBlock,
+                    _ => ClientError::Read(e),
+                })?;
+                *pos = 0;
+                *len = n;
+            }
+            let start = *pos;
+            let datagram = &buf[start..*len];
+            // Any remaining bytes of the datagram are dropped on errors, as
+            // the start of the next packet can not be known.
+            *pos = *len;
+            let header =
+                parser::parse_packet_header(datagram).map_err(ClientError::InvalidPacket)?;
+            let packet_size = parser::PACKET_HEADER_SIZE + header.length as usize;
+            let data = datagram
+                .get(parser::PACKET_HEADER_SIZE..packet_size)
+                .ok_or(ClientError::InvalidPacket(parser::ParseError::Failed))?;
+            parser::parse_packet_data_into(header, data, packet)
+                .map_err(ClientError::InvalidPacket)?;
+            *pos = start + packet_size;
+            // Only continue within the datagram if another packet follows,
+            // trailing padding is skipped.
+            let rest = &buf[*pos..*len];
+            if rest.len() < parser::PACKET_HEADER_SIZE || parser::parse_packet_header(rest).is_err()
+            {
+                *pos = *len;
+            }
+            Ok(())
+        } else {
+            Err(ClientError::InvalidState)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::test_packets::PACKET_FRAME_NUMBER, se_types::SEOutputData,
+        serializer::serialize_packet,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_udp_client_invalid_state() {
+        let mut client = UDPClient::new(0, true);
+        assert!(matches!(client.next(), Err(ClientError::InvalidState)));
+        assert!(matches!(client.send(&[]), Err(ClientError::InvalidState)));
+
+        client.connect().unwrap();
+        assert!(matches!(client.connect(), Err(ClientError::InvalidState)));
+        client.disconnect().unwrap();
+        assert!(matches!(client.next(), Err(ClientError::InvalidState)));
+    }
+
+    #[test]
+    fn test_udp_client_multicast() {
+        let group = Ipv4Addr::new(239, 255, 42, 99);
+        let mut client = UDPClient::new(0, true);
+        client.join_multicast(group, Ipv4Addr::LOCALHOST).unwrap();
+        client.connect().unwrap();
+        let port = match &client.state {
+            UDPClientState::Connected { socket, .. } => socket.local_addr().unwrap().port(),
+            _ => unreachable!(),
+        };
+
+        let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+        SockRef::from(&sender)
+            .set_multicast_if_v4(&Ipv4Addr::LOCALHOST)
+            .unwrap();
+        sender.set_multicast_loop_v4(true).unwrap();
+        sender.send_to(PACKET_FRAME_NUMBER, (group, port)).unwrap();
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let packet = loop {
+            match client.next() {
+                Err(ClientError::ReadWouldBlock) if std::time::Instant::now() < deadline => {
+                    std::thread::yield_now()
+                }
+                res => break res.unwrap(),
+            }
+        };
+        assert_eq!(packet[..], [SEOutputData::SEFrameNumber(17819)]);
+
+        client.leave_multicast(group, Ipv4Addr::LOCALHOST).unwrap();
+        assert!(client.multicast_groups().is_empty());
+        client.join_multicast(group, Ipv4Addr::LOCALHOST).unwrap();
+        assert_eq!(client.multicast_groups(), [(group, Ipv4Addr::LOCALHOST)]);
+        client.disconnect().unwrap();
+        assert!(client.multicast_groups().is_empty());
+    }
+
+    #[test]
+    fn test_udp_client_connect_to() {
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        peer.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let mut client = UDPClient::new(0, true);
+        client.connect().unwrap();
+        client.connect_to(peer.local_addr().unwrap()).unwrap();
+        assert_eq!(client.peer_addr(), Some(peer.local_addr().unwrap()));
+
+        assert_eq!(client.send(b"ping").unwrap(), 4);
+        let mut buf = [0; 16];
+        let (n, client_addr) = peer.recv_from(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"ping");
+
+        peer.send_to(PACKET_FRAME_NUMBER, client_addr).unwrap();
+        let packet = loop {
+            match client.next() {
+                Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                res => break res.unwrap(),
+            }
+        };
+        assert_eq!(packet[..], [SEOutputData::SEFrameNumber(17819)]);
+    }
+
+    #[test]
+    fn test_udp_client_recv_buffer_size() {
+        let mut client = UDPClient::new(0, true);
+        assert_eq!(client.recv_buffer_size(), None);
+        client.set_recv_buffer_size(64 * 1024).unwrap();
+        client.connect().unwrap();
+        // The OS may round or double the requested size.
+        assert!(client.recv_buffer_size().unwrap() >= 64 * 1024);
+
+        client.set_recv_buffer_size(128 * 1024).unwrap();
+        assert!(client.recv_buffer_size().unwrap() >= 128 * 1024);
+    }
+
+    #[test]
+    fn test_udp_client_multiple_packets_per_datagram() {
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mut client = UDPClient::new(0, true);
+        client.connect().unwrap();
+        client.connect_to(peer.local_addr().unwrap()).unwrap();
+        client.send(b"ping").unwrap();
+        let mut buf = [0; 16];
+        let (_, client_addr) = peer.recv_from(&mut buf).unwrap();
+
+        let second = serialize_packet(&[SEOutputData::SEFrameNumber(2)]);
+        peer.send_to(&[PACKET_FRAME_NUMBER, &second].concat(), client_addr)
+            .unwrap();
+        let first = loop {
+            match client.next() {
+                Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                res => break res.unwrap(),
+            }
+        };
+        assert_eq!(first[..], [SEOutputData::SEFrameNumber(17819)]);
+        assert_eq!(client.next().unwrap()[..], [SEOutputData::SEFrameNumber(2)]);
+        assert!(matches!(client.next(), Err(ClientError::ReadWouldBlock)));
+    }
+
+    #[test]
+    fn test_udp_client_trailing_bytes_in_datagram() {
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mut client = UDPClient::new(0, true);
+        client.connect().unwrap();
+        client.connect_to(peer.local_addr().unwrap()).unwrap();
+        client.send(b"ping").unwrap();
+        let mut buf = [0; 16];
+        let (_, client_addr) = peer.recv_from(&mut buf).unwrap();
+
+        peer.send_to(&[PACKET_FRAME_NUMBER, &[0; 3]].concat(), client_addr)
+            .unwrap();
+        let second = serialize_packet(&[SEOutputData::SEFrameNumber(2)]);
+        peer.send_to(&second, client_addr).unwrap();
+        let first = loop {
+            match client.next() {
+                Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                res => break res.unwrap(),
+            }
+        };
+        assert_eq!(first[..], [SEOutputData::SEFrameNumber(17819)]);
+        let next = loop {
+            match client.next() {
+                Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                res => break res.unwrap(),
+            }
+        };
+        assert_eq!(next[..], [SEOutputData::SEFrameNumber(2)]);
+    }
+
+    #[test]
+    fn test_udp_client_from_socket() {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+        let mut client = UDPClient::from_socket(socket, true).unwrap();
+        assert!(matches!(client.next(), Err(ClientError::ReadWouldBlock)));
+
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        peer.send_to(PACKET_FRAME_NUMBER, addr).unwrap();
+        let packet = loop {
+            match client.next() {
+                Err(ClientError::ReadWouldBlock) => std::thread::yield_now(),
+                res => break res.unwrap(),
+            }
+        };
+        assert_eq!(packet[..], [SEOutputData::SEFrameNumber(17819)]);
+        client.disconnect().unwrap();
+
+        // In blocking mode, next waits for the datagram.
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+        let mut client = UDPClient::from_socket(socket, false).unwrap();
+        let sender = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            peer.send_to(PACKET_FRAME_NUMBER, addr).unwrap();
+        });
+        assert_eq!(
+            client.next().unwrap()[..],
+            [SEOutputData::SEFrameNumber(17819)]
+        );
+        sender.join().unwrap();
+    }
+}
@@ -0,0 +1,129 @@
+use super::{
+    stream_reader::{Stream, StreamReader},
+    Client, ClientError, Packet,
+};
+use std::{
+    os::unix::net::UnixStream,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+enum UnixClientState {
+    Pending {
+        path: PathBuf,
+    },
+    Connected {
+        stream_reader: StreamReader<UnixStream>,
+    },
+    Disconnected,
+}
+
+// Client reading a stream of packets from a Unix domain socket, framed the
+// same way as for TCPClient.
+pub struct UnixClient {
+    state: UnixClientState,
+    paused: bool,
+}
+
+impl UnixClient {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let state = UnixClientState::Pending { path };
+        UnixClient {
+            state,
+            paused: false,
+        }
+    }
+
+    // Blocks for at most timeout waiting for the next packet, see
+    // TCPClient::next_timeout.
+    pub fn next_timeout(&mut self, timeout: Duration) -> Result<Option<Packet>, ClientError> {
+        if self.paused {
+            return Err(ClientError::Paused);
+        }
+        match &mut self.state {
+            UnixClientState::Connected { stream_reader } => stream_reader.next_timeout(timeout),
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+}
+
+impl Client for UnixClient {
+    fn connect(&mut self) -> Result<(), ClientError> {
+        match &self.state {
+            UnixClientState::Pending { path } => {
+                let stream = UnixStream::connect(path).map_err(ClientError::Connect)?;
+                stream.set_nonblocking(true).map_err(ClientError::Connect)?;
+                let stream_reader = StreamReader::new(stream, true);
+                self.state = UnixClientState::Connected { stream_reader };
+                Ok(())
+            }
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+
+    fn disconnect(&mut self) -> Result<(), ClientError> {
+        match &self.state {
+            UnixClientState::Connected { stream_reader } => {
+                let shutdown_res =
+                    Stream::shutdown(&stream_reader.stream).map_err(ClientError::Disconnect);
+                self.state = UnixClientState::Disconnected;
+                shutdown_res
+            }
+            _ => Err(ClientError::InvalidState),
+        }
+    }
+
+    fn next(&mut self) -> Result<Packet, ClientError> {
+        let mut packet = Packet::new();
+        self.next_into(&mut packet)?;
+        Ok(packet)
+    }
+
+    fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    fn next_into(&mut self, packet: &mut Packet) -> Result<(), ClientError> {
+        if self.paused {
+            return Err(ClientError::Paused);
+        }
+        if let UnixClientState::Connected { stream_reader } = &mut self.state {
+            stream_reader.next_into(packet)
+        } else {
+            Err(ClientError::InvalidState)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::test_packets::PACKET_FRAME_NUMBER, se_types::SEOutputData, test_support::packet_of,
+    };
+    use std::{env, fs, io::Write, os::unix::net::UnixListener, process};
+
+    #[test]
+    fn test_unix_client() {
+        let path = env::temp_dir().join(format!("sep-data-test-{}.sock", process::id()));
+        let _ = fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+
+        let mut client = UnixClient::new(&path);
+        client.connect().unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+        server.write_all(PACKET_FRAME_NUMBER).unwrap();
+
+        assert_eq!(
+            client.next_timeout(Duration::from_secs(5)).unwrap(),
+            Some(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
+        );
+        client.disconnect().unwrap();
+        fs::remove_file(&path).unwrap();
+    }
+}
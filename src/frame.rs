@@ -0,0 +1,63 @@
+use crate::{
+    gaze::{gaze_data, GazeData},
+    motion::{head_pose, HeadPose},
+    packet,
+    se_types::SEOutputData,
+};
+
+// Head pose and gaze of a single frame, i.e. of a single packet.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CombinedFrame {
+    pub frame_number: Option<u32>,
+    pub timestamp: Option<u64>,
+    pub head: Option<HeadPose>,
+    pub gaze: Option<GazeData>,
+}
+
+pub fn combined_frame(packet: &[SEOutputData]) -> CombinedFrame {
+    CombinedFrame {
+        frame_number: packet::frame_number(packet),
+        timestamp: packet::timestamp(packet),
+        head: head_pose(packet),
+        gaze: gaze_data(packet),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::se_types::{Point3D, Vect3D};
+
+    #[test]
+    fn test_combined_frame() {
+        let packet = [
+            SEOutputData::SEFrameNumber(42),
+            SEOutputData::SETimeStamp(1_000),
+            SEOutputData::SEHeadPosition(Point3D(0.0, 0.1, 0.6)),
+            SEOutputData::SEHeadPositionQ(0.9),
+            SEOutputData::SEGazeDirection(Vect3D(0.0, 0.0, -1.0)),
+            SEOutputData::SEGazeDirectionQ(0.8),
+        ];
+        assert_eq!(
+            combined_frame(&packet),
+            CombinedFrame {
+                frame_number: Some(42),
+                timestamp: Some(1_000),
+                head: Some(HeadPose {
+                    position: Some(Point3D(0.0, 0.1, 0.6)),
+                    rotation: None,
+                    position_quality: Some(0.9),
+                    rotation_quality: None,
+                }),
+                gaze: Some(GazeData {
+                    origin: None,
+                    direction: Some(Vect3D(0.0, 0.0, -1.0)),
+                    direction_quality: Some(0.8),
+                }),
+            }
+        );
+
+        let frame = combined_frame(&[SEOutputData::SEFrameNumber(43)]);
+        assert_eq!((frame.head, frame.gaze), (None, None));
+    }
+}
@@ -0,0 +1,489 @@
+use crate::{
+    packet::{self, field_f64, find_field},
+    se_types::{Point3D, SEOutputData, SEOutputDataId, Vect3D, WorldIntersection},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GazeMode {
+    // Gaze direction of only one of the eyes.
+    Monocular,
+    // Gaze direction of both the left and the right eye.
+    Binocular,
+    // Only the combined gaze direction, no per-eye directions.
+    CombinedOnly,
+    NoGaze,
+}
+
+// Tracking mode of the packet, based on which gaze direction fields it has.
+pub fn gaze_mode(packet: &[SEOutputData]) -> GazeMode {
+    let has = |id| find_field(packet, id).is_some();
+    match (
+        has(SEOutputDataId::SELeftGazeDirection),
+        has(SEOutputDataId::SERightGazeDirection),
+    ) {
+        (true, true) => GazeMode::Binocular,
+        (true, false) | (false, true) => GazeMode::Monocular,
+        (false, false) if has(SEOutputDataId::SEGazeDirection) => GazeMode::CombinedOnly,
+        (false, false) => GazeMode::NoGaze,
+    }
+}
+
+// Whether the eyes are tracked, with a gaze direction quality of at least
+// min_quality. A tracking state of 0 means that tracking is lost. Packets
+// without tracking state or gaze direction quality pass the respective check.
+pub fn is_tracking_valid(packet: &[SEOutputData], min_quality: f64) -> bool {
+    let tracking = packet.iter().all(|data| match data {
+        SEOutputData::SETrackingState(state) => *state != 0,
+        _ => true,
+    });
+    let quality_ok = match field_f64(packet, SEOutputDataId::SEGazeDirectionQ) {
+        Some(quality) => quality >= min_quality,
+        None => true,
+    };
+    tracking && quality_ok
+}
+
+fn gaze_direction(
+    packet: &[SEOutputData],
+    direction_id: SEOutputDataId,
+    quality_id: SEOutputDataId,
+) -> Option<(Vect3D, f64)> {
+    let direction = match find_field(packet, direction_id)? {
+        SEOutputData::SEGazeDirection(v)
+        | SEOutputData::SELeftGazeDirection(v)
+        | SEOutputData::SERightGazeDirection(v) => *v,
+        _ => return None,
+    };
+    Some((direction, field_f64(packet, quality_id)?))
+}
+
+// Gaze direction and its quality, preferring the combined gaze direction if
+// its quality is at least min_quality, else the eye with the best quality.
+// Falls back to the combined gaze direction if no eye has a gaze direction.
+// Directions without a quality are not considered.
+pub fn best_gaze_direction(packet: &[SEOutputData], min_quality: f64) -> Option<(Vect3D, f64)> {
+    type Id = SEOutputDataId;
+    let combined = gaze_direction(packet, Id::SEGazeDirection, Id::SEGazeDirectionQ);
+    if let Some((_, q)) = combined {
+        if q >= min_quality {
+            return combined;
+        }
+    }
+    let left = gaze_direction(packet, Id::SELeftGazeDirection, Id::SELeftGazeDirectionQ);
+    let right = gaze_direction(packet, Id::SERightGazeDirection, Id::SERightGazeDirectionQ);
+    match (left, right) {
+        (Some(l), Some(r)) => Some(if r.1 > l.1 { r } else { l }),
+        (l, r) => l.or(r).or(combined),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GazeData {
+    pub origin: Option<Point3D>,
+    pub direction: Option<Vect3D>,
+    pub direction_quality: Option<f64>,
+}
+
+// Combined gaze of the packet. None unless it has a gaze origin or direction.
+pub fn gaze_data(packet: &[SEOutputData]) -> Option<GazeData> {
+    let mut origin = None;
+    let mut direction = None;
+    for data in packet {
+        match data {
+            SEOutputData::SEGazeOrigin(v) => origin = origin.or(Some(*v)),
+            SEOutputData::SEGazeDirection(v) => direction = direction.or(Some(*v)),
+            _ => (),
+        }
+    }
+    if origin.is_none() && direction.is_none() {
+        return None;
+    }
+    Some(GazeData {
+        origin,
+        direction,
+        direction_quality: field_f64(packet, SEOutputDataId::SEGazeDirectionQ),
+    })
+}
+
+// Vendor-neutral gaze sample, for feeding analytics written for other eye
+// trackers.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GazeSample {
+    // SETimeStamp of the packet.
+    pub timestamp: u64,
+    // Whether the eye has a gaze direction with a quality above 0.
+    pub left_valid: bool,
+    pub right_valid: bool,
+    pub combined_direction: Option<Vect3D>,
+    // Origin of the combined gaze direction.
+    pub origin: Option<Point3D>,
+}
+
+// Gaze sample of the packet, None if it has no timestamp.
+pub fn to_gaze_sample(packet: &[SEOutputData]) -> Option<GazeSample> {
+    type Id = SEOutputDataId;
+    let valid = |direction_id, quality_id| {
+        find_field(packet, direction_id).is_some()
+            && match field_f64(packet, quality_id) {
+                Some(quality) => quality > 0.0,
+                None => true,
+            }
+    };
+    Some(GazeSample {
+        timestamp: packet::timestamp(packet)?,
+        left_valid: valid(Id::SELeftGazeDirection, Id::SELeftGazeDirectionQ),
+        right_valid: valid(Id::SERightGazeDirection, Id::SERightGazeDirectionQ),
+        combined_direction: packet.iter().find_map(|data| match data {
+            SEOutputData::SEGazeDirection(v) => Some(*v),
+            _ => None,
+        }),
+        origin: packet.iter().find_map(|data| match data {
+            SEOutputData::SEGazeOrigin(v) => Some(*v),
+            _ => None,
+        }),
+    })
+}
+
+// Gaze origin and the world point of the closest gaze intersection, i.e. the
+// segment from the eye to what is looked at. None unless both are present.
+pub fn gaze_ray(packet: &[SEOutputData]) -> Option<(Point3D, Point3D)> {
+    let origin = packet.iter().find_map(|data| match data {
+        SEOutputData::SEGazeOrigin(v) => Some(*v),
+        _ => None,
+    })?;
+    let intersection = packet.iter().find_map(|data| match data {
+        SEOutputData::SEClosestWorldIntersection(v) => Some(v.as_ref()),
+        _ => None,
+    })??;
+    Some((origin, intersection.world_point))
+}
+
+fn dot(Vect3D(ax, ay, az): Vect3D, Vect3D(bx, by, bz): Vect3D) -> f64 {
+    ax * bx + ay * by + az * bz
+}
+
+fn scale(Vect3D(x, y, z): Vect3D, s: f64) -> Vect3D {
+    Vect3D(x * s, y * s, z * s)
+}
+
+// Point the eyes converge on, i.e. the midpoint of the shortest segment
+// between the left and right gaze lines. None if the origin or direction of
+// either eye is missing, or if the gaze directions are parallel.
+pub fn fixation_point(packet: &[SEOutputData]) -> Option<Point3D> {
+    let mut left_origin = None;
+    let mut left_direction = None;
+    let mut right_origin = None;
+    let mut right_direction = None;
+    for data in packet {
+        match data {
+            SEOutputData::SELeftGazeOrigin(v) => left_origin = Some(*v),
+            SEOutputData::SELeftGazeDirection(v) => left_direction = Some(*v),
+            SEOutputData::SERightGazeOrigin(v) => right_origin = Some(*v),
+            SEOutputData::SERightGazeDirection(v) => right_direction = Some(*v),
+            _ => (),
+        }
+    }
+    let (p1, d1) = (left_origin?, left_direction?);
+    let (p2, d2) = (right_origin?, right_direction?);
+
+    let w = p1 - p2;
+    let (a, b, c) = (dot(d1, d1), dot(d1, d2), dot(d2, d2));
+    let (d, e) = (dot(d1, w), dot(d2, w));
+    let denominator = a * c - b * b;
+    if denominator <= f64::EPSILON * a * c {
+        return None;
+    }
+    let closest1 = p1 + scale(d1, (b * e - c * d) / denominator);
+    let closest2 = p2 + scale(d2, (a * e - b * d) / denominator);
+    Some(closest1 + scale(closest2 - closest1, 0.5))
+}
+
+fn eyelid_aperture(upper: Option<Point3D>, lower: Option<Point3D>) -> Option<(f64, Point3D)> {
+    let (upper, lower) = (upper?, lower?);
+    let v = upper - lower;
+    Some((dot(v, v).sqrt(), lower + scale(v, 0.5)))
+}
+
+// Distance between the upper and lower eyelid extreme points of the left eye,
+// and their midpoint. None unless both are present.
+pub fn left_eyelid_aperture(packet: &[SEOutputData]) -> Option<(f64, Point3D)> {
+    let mut upper = None;
+    let mut lower = None;
+    for data in packet {
+        match data {
+            SEOutputData::SELeftUpperEyelidExtremePoint(v) => upper = Some(*v),
+            SEOutputData::SELeftLowerEyelidExtremePoint(v) => lower = Some(*v),
+            _ => (),
+        }
+    }
+    eyelid_aperture(upper, lower)
+}
+
+// Like left_eyelid_aperture, for the right eye.
+pub fn right_eyelid_aperture(packet: &[SEOutputData]) -> Option<(f64, Point3D)> {
+    let mut upper = None;
+    let mut lower = None;
+    for data in packet {
+        match data {
+            SEOutputData::SERightUpperEyelidExtremePoint(v) => upper = Some(*v),
+            SEOutputData::SERightLowerEyelidExtremePoint(v) => lower = Some(*v),
+            _ => (),
+        }
+    }
+    eyelid_aperture(upper, lower)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlinkEvent {
+    // SETimeStamp of the first packet with the eyelids closed.
+    BlinkStart(u64),
+    // SETimeStamp of the first packet with the eyelids open again.
+    BlinkEnd(u64),
+}
+
+// Detects blinks from SEEyelidOpening, rather than relying on the SEBlink
+// counter. A blink starts when the opening drops below close_below and ends
+// when it rises above open_above again, the gap between the two avoiding
+// repeated events from a noisy opening near a single threshold. Packets
+// without SEEyelidOpening or SETimeStamp are skipped.
+#[derive(Debug, Clone)]
+pub struct BlinkDetector {
+    close_below: f64,
+    open_above: f64,
+    closed: bool,
+}
+
+impl BlinkDetector {
+    pub fn new(close_below: f64, open_above: f64) -> Self {
+        assert!(
+            close_below <= open_above,
+            "close_below must not be above open_above"
+        );
+        BlinkDetector {
+            close_below,
+            open_above,
+            closed: false,
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn push(&mut self, packet: &[SEOutputData]) -> Option<BlinkEvent> {
+        let opening = field_f64(packet, SEOutputDataId::SEEyelidOpening)?;
+        let timestamp = packet::timestamp(packet)?;
+        if !self.closed && opening < self.close_below {
+            self.closed = true;
+            Some(BlinkEvent::BlinkStart(timestamp))
+        } else if self.closed && opening > self.open_above {
+            self.closed = false;
+            Some(BlinkEvent::BlinkEnd(timestamp))
+        } else {
+            None
+        }
+    }
+}
+
+// Screen-space coordinates of an intersection with a screen of the given
+// size, with (0, 0) at the top left and (1, 1) at the bottom right corner of
+// the screen. Smart Eye Pro places the origin of the object coordinate system
+// of a screen in its lower left corner, with x to the right and y upwards, in
+// meters. Points outside the screen map to coordinates outside [0, 1].
+pub fn screen_uv(
+    intersection: &WorldIntersection,
+    screen_width_mm: f64,
+    screen_height_mm: f64,
+) -> (f64, f64) {
+    let Point3D(x, y, _) = intersection.object_point;
+    let u = x * 1000.0 / screen_width_mm;
+    let v = 1.0 - y * 1000.0 / screen_height_mm;
+    (u, v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blink_detector() {
+        let mut detector = BlinkDetector::new(0.003, 0.006);
+        let openings = [
+            0.010, 0.008, 0.004, 0.002, 0.0035, 0.001, 0.005, 0.007, 0.009,
+        ];
+        let mut events = Vec::new();
+        for (i, &opening) in openings.iter().enumerate() {
+            let packet = [
+                SEOutputData::SETimeStamp(i as u64 * 100),
+                SEOutputData::SEEyelidOpening(opening),
+            ];
+            events.extend(detector.push(&packet));
+            // Packets lacking the opening are skipped.
+            assert_eq!(detector.push(&[SEOutputData::SETimeStamp(i as u64)]), None);
+        }
+        assert_eq!(
+            events,
+            vec![BlinkEvent::BlinkStart(300), BlinkEvent::BlinkEnd(700)]
+        );
+        assert!(!detector.is_closed());
+    }
+
+    #[test]
+    fn test_gaze_mode() {
+        let direction = Vect3D(0.0, 0.0, 1.0);
+        let mut packet = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEGazeDirection(direction),
+        ];
+        assert_eq!(gaze_mode(&packet), GazeMode::CombinedOnly);
+
+        packet.push(SEOutputData::SELeftGazeDirection(direction));
+        assert_eq!(gaze_mode(&packet), GazeMode::Monocular);
+
+        packet.push(SEOutputData::SERightGazeDirection(direction));
+        assert_eq!(gaze_mode(&packet), GazeMode::Binocular);
+
+        assert_eq!(
+            gaze_mode(&[SEOutputData::SEFrameNumber(1)]),
+            GazeMode::NoGaze
+        );
+    }
+
+    #[test]
+    fn test_is_tracking_valid() {
+        let mut packet = vec![
+            SEOutputData::SETrackingState(1),
+            SEOutputData::SEGazeDirectionQ(0.9),
+        ];
+        assert!(is_tracking_valid(&packet, 0.5));
+
+        packet[1] = SEOutputData::SEGazeDirectionQ(0.2);
+        assert!(!is_tracking_valid(&packet, 0.5));
+
+        packet.pop();
+        assert!(is_tracking_valid(&packet, 0.5));
+
+        packet[0] = SEOutputData::SETrackingState(0);
+        assert!(!is_tracking_valid(&packet, 0.5));
+    }
+
+    #[test]
+    fn test_best_gaze_direction() {
+        let combined = Vect3D(0.0, 0.0, -1.0);
+        let left = Vect3D(0.1, 0.0, -1.0);
+        let right = Vect3D(-0.1, 0.0, -1.0);
+        let mut packet = vec![
+            SEOutputData::SEGazeDirection(combined),
+            SEOutputData::SEGazeDirectionQ(0.8),
+            SEOutputData::SELeftGazeDirection(left),
+            SEOutputData::SELeftGazeDirectionQ(0.3),
+            SEOutputData::SERightGazeDirection(right),
+            SEOutputData::SERightGazeDirectionQ(0.6),
+        ];
+        assert_eq!(best_gaze_direction(&packet, 0.5), Some((combined, 0.8)));
+
+        packet[1] = SEOutputData::SEGazeDirectionQ(0.2);
+        assert_eq!(best_gaze_direction(&packet, 0.5), Some((right, 0.6)));
+
+        packet.truncate(2);
+        assert_eq!(best_gaze_direction(&packet, 0.5), Some((combined, 0.2)));
+        assert_eq!(best_gaze_direction(&packet[..1], 0.5), None);
+    }
+
+    #[test]
+    fn test_gaze_ray() {
+        let mut packet = vec![
+            SEOutputData::SEGazeOrigin(Point3D(0.0, 0.1, 0.6)),
+            SEOutputData::SEClosestWorldIntersection(Some(WorldIntersection {
+                world_point: Point3D(0.2, 0.3, 0.0),
+                object_point: Point3D(0.1, 0.1, 0.0),
+                object_name: String::from("Screen"),
+            })),
+        ];
+        assert_eq!(
+            gaze_ray(&packet),
+            Some((Point3D(0.0, 0.1, 0.6), Point3D(0.2, 0.3, 0.0)))
+        );
+
+        packet[1] = SEOutputData::SEClosestWorldIntersection(None);
+        assert_eq!(gaze_ray(&packet), None);
+
+        packet.pop();
+        assert_eq!(gaze_ray(&packet), None);
+    }
+
+    #[test]
+    fn test_fixation_point() {
+        let mut packet = vec![
+            SEOutputData::SELeftGazeOrigin(Point3D(-0.03, 0.0, 0.6)),
+            SEOutputData::SELeftGazeDirection(Vect3D(0.06, 0.2, -1.2)),
+            SEOutputData::SERightGazeOrigin(Point3D(0.03, 0.0, 0.6)),
+            SEOutputData::SERightGazeDirection(Vect3D(-0.03, 0.1, -0.6)),
+        ];
+        let Point3D(x, y, z) = fixation_point(&packet).unwrap();
+        assert!(x.abs() < 1e-12);
+        assert!((y - 0.1).abs() < 1e-12);
+        assert!(z.abs() < 1e-12);
+
+        packet[3] = SEOutputData::SERightGazeDirection(Vect3D(0.06, 0.2, -1.2));
+        assert_eq!(fixation_point(&packet), None);
+
+        packet.pop();
+        assert_eq!(fixation_point(&packet), None);
+    }
+
+    #[test]
+    fn test_screen_uv() {
+        let mut intersection = WorldIntersection {
+            world_point: Point3D(0.0, 0.0, 0.0),
+            object_point: Point3D(0.26, 0.16, 0.0),
+            object_name: String::from("Screen"),
+        };
+        assert_eq!(screen_uv(&intersection, 520.0, 320.0), (0.5, 0.5));
+
+        intersection.object_point = Point3D(0.0, 0.32, 0.0);
+        assert_eq!(screen_uv(&intersection, 520.0, 320.0), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_left_eyelid_aperture() {
+        let mut packet = vec![
+            SEOutputData::SELeftUpperEyelidExtremePoint(Point3D(0.03, 0.006, 0.6)),
+            SEOutputData::SELeftLowerEyelidExtremePoint(Point3D(0.03, -0.004, 0.6)),
+        ];
+        let (aperture, midpoint) = left_eyelid_aperture(&packet).unwrap();
+        assert!((aperture - 0.01).abs() < 1e-12);
+        assert!((midpoint.1 - 0.001).abs() < 1e-12);
+        assert_eq!((midpoint.0, midpoint.2), (0.03, 0.6));
+        assert_eq!(right_eyelid_aperture(&packet), None);
+
+        packet.pop();
+        assert_eq!(left_eyelid_aperture(&packet), None);
+    }
+
+    #[test]
+    fn test_to_gaze_sample() {
+        let direction = Vect3D(0.0, 0.0, -1.0);
+        let mut packet = vec![
+            SEOutputData::SETimeStamp(1000),
+            SEOutputData::SEGazeDirection(direction),
+            SEOutputData::SELeftGazeDirection(direction),
+            SEOutputData::SELeftGazeDirectionQ(0.7),
+            SEOutputData::SERightGazeDirection(direction),
+            SEOutputData::SERightGazeDirectionQ(0.0),
+        ];
+        assert_eq!(
+            to_gaze_sample(&packet),
+            Some(GazeSample {
+                timestamp: 1000,
+                left_valid: true,
+                right_valid: false,
+                combined_direction: Some(direction),
+                origin: None,
+            })
+        );
+
+        packet.remove(0);
+        assert_eq!(to_gaze_sample(&packet), None);
+    }
+}
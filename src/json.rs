@@ -0,0 +1,137 @@
+use crate::{
+    client::Packet,
+    se_types::{SEOutputData, SEOutputDataId},
+};
+use serde_json::{Map, Value};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+// JSON object of the packet, mapping the name of each output data to its
+// value. Names are looked up in names, falling back to the name of the id.
+// If an output data occurs more than once, the last value is kept.
+fn packet_object(
+    packet: &[SEOutputData],
+    names: Option<&HashMap<SEOutputDataId, String>>,
+) -> serde_json::Result<Map<String, Value>> {
+    let mut object = Map::new();
+    for data in packet {
+        // Output data serializes as an object with the name as single key.
+        if let Value::Object(field) = serde_json::to_value(data)? {
+            let id = data.id();
+            let name = names
+                .and_then(|names| names.get(&id))
+                .map_or(id.name(), String::as_str);
+            object.extend(field.into_iter().map(|(_, v)| (name.to_string(), v)));
+        }
+    }
+    Ok(object)
+}
+
+// Writes packets as newline-delimited JSON (NDJSON), one JSON object per
+// packet and line. Packets are written as they are read from the iterator.
+pub fn write_ndjson<W: Write>(packets: impl Iterator<Item = Packet>, w: W) -> io::Result<()> {
+    write_ndjson_names(packets, w, None)
+}
+
+// As write_ndjson, but with the keys of the output data in names replaced by
+// the given names, e.g. to match the column names of another tool.
+pub fn write_ndjson_with_names<W: Write>(
+    packets: impl Iterator<Item = Packet>,
+    w: W,
+    names: &HashMap<SEOutputDataId, String>,
+) -> io::Result<()> {
+    write_ndjson_names(packets, w, Some(names))
+}
+
+fn write_ndjson_names<W: Write>(
+    packets: impl Iterator<Item = Packet>,
+    mut w: W,
+    names: Option<&HashMap<SEOutputDataId, String>>,
+) -> io::Result<()> {
+    for packet in packets {
+        serde_json::to_writer(&mut w, &packet_object(&packet, names)?)?;
+        w.write_all(b"\n")?;
+    }
+    w.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        se_types::{Point2D, Point3D, Quaternion, SEStructItem, SEVariant, WorldIntersection},
+        test_support::packet_of,
+    };
+
+    #[test]
+    fn test_write_ndjson() {
+        let packets = vec![
+            packet_of(vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SEHeadPosition(Point3D(0.0, 0.5, 0.6)),
+            ]),
+            packet_of(vec![SEOutputData::SEFrameNumber(2)]),
+        ];
+        let mut out = Vec::new();
+        write_ndjson(packets.into_iter(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.ends_with('\n'));
+        let lines: Vec<Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["SEFrameNumber"], 1);
+        assert_eq!(
+            lines[0]["SEHeadPosition"],
+            serde_json::json!({ "x": 0.0, "y": 0.5, "z": 0.6 })
+        );
+        assert_eq!(lines[1], serde_json::json!({ "SEFrameNumber": 2 }));
+    }
+
+    #[test]
+    fn test_write_ndjson_with_names() {
+        let packets = vec![packet_of(vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEHeadPosition(Point3D(0.0, 0.5, 0.6)),
+        ])];
+        let mut names = HashMap::new();
+        names.insert(SEOutputDataId::SEFrameNumber, String::from("frame"));
+        let mut out = Vec::new();
+        write_ndjson_with_names(packets.into_iter(), &mut out, &names).unwrap();
+
+        let line: Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            line,
+            serde_json::json!({
+                "frame": 1,
+                "SEHeadPosition": { "x": 0.0, "y": 0.5, "z": 0.6 },
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let packet = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEHeadRotationQuaternion(Quaternion(1.0, 0.0, 0.5, 0.25)),
+            SEOutputData::SEGPSPosition(Point2D(57.7, 11.97)),
+            SEOutputData::SEClosestWorldIntersection(Some(WorldIntersection {
+                world_point: Point3D(1.0, 2.0, 3.0),
+                object_point: Point3D(0.1, 0.2, 0.0),
+                object_name: String::from("Screen"),
+            })),
+            SEOutputData::SECameraPositions(vec![SEVariant::Struct(vec![SEStructItem(
+                String::from("position"),
+                SEVariant::Point3D(Point3D(0.4, 0.5, 0.6)),
+            )])]),
+        ];
+        let json = serde_json::to_string(&packet).unwrap();
+        assert!(json.contains(r#"{"w":1.0,"x":0.0,"y":0.5,"z":0.25}"#));
+        let decoded: Vec<SEOutputData> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, packet);
+    }
+}
@@ -1,3 +1,18 @@
+pub mod adapters;
+#[cfg(feature = "bincode")]
+pub mod bincode;
 pub mod client;
+pub mod frame;
+pub mod gaze;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod motion;
+pub mod packet;
 pub mod parser;
+#[cfg(feature = "prost")]
+pub mod proto;
 pub mod se_types;
+pub mod serializer;
+pub mod stats;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
@@ -0,0 +1,182 @@
+use crate::{
+    packet,
+    se_types::{Point3D, Quaternion, SEOutputData, SEOutputDataId, Vect3D},
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HeadPose {
+    pub position: Option<Point3D>,
+    // SEHeadRotationQuaternion, else SEHeadRotationRodrigues converted to a
+    // quaternion.
+    pub rotation: Option<Quaternion>,
+    pub position_quality: Option<f64>,
+    pub rotation_quality: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct HeadVelocity {
+    // Linear velocity of the head position, in mm/s.
+    pub linear: Vect3D,
+    // Angular velocity of the head rotation, as rotation axis scaled by
+    // rad/s. None unless both packets have a head rotation.
+    pub angular: Option<Vect3D>,
+}
+
+// Estimates head velocity from the change in SEHeadPosition and head rotation
+// between consecutive packets, over the change in SETimeStamp. Packets
+// missing position or timestamp are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct HeadVelocityEstimator {
+    // Timestamp, position and rotation of the previous packet.
+    previous: Option<(u64, Point3D, Option<Quaternion>)>,
+}
+
+fn quaternion_mul(Quaternion(aw, ax, ay, az): Quaternion, b: Quaternion) -> Quaternion {
+    let Quaternion(bw, bx, by, bz) = b;
+    Quaternion(
+        aw * bw - ax * bx - ay * by - az * bz,
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+    )
+}
+
+fn quaternion_conj(Quaternion(w, x, y, z): Quaternion) -> Quaternion {
+    Quaternion(w, -x, -y, -z)
+}
+
+// Quaternion of a rotation vector, i.e. the rotation axis scaled by the
+// rotation angle in radians.
+fn quaternion_from_rodrigues(Vect3D(x, y, z): Vect3D) -> Quaternion {
+    let angle = (x * x + y * y + z * z).sqrt();
+    if angle == 0.0 {
+        return Quaternion(1.0, 0.0, 0.0, 0.0);
+    }
+    let s = (angle / 2.0).sin() / angle;
+    Quaternion((angle / 2.0).cos(), x * s, y * s, z * s)
+}
+
+// Rotation vector of a unit quaternion, taking the shorter way around.
+fn rodrigues_from_quaternion(Quaternion(w, x, y, z): Quaternion) -> Vect3D {
+    let (w, x, y, z) = if w < 0.0 {
+        (-w, -x, -y, -z)
+    } else {
+        (w, x, y, z)
+    };
+    let norm = (x * x + y * y + z * z).sqrt();
+    if norm == 0.0 {
+        return Vect3D(0.0, 0.0, 0.0);
+    }
+    let s = 2.0 * norm.atan2(w) / norm;
+    Vect3D(x * s, y * s, z * s)
+}
+
+fn head_rotation(packet: &[SEOutputData]) -> Option<Quaternion> {
+    let mut rodrigues = None;
+    for data in packet {
+        match data {
+            SEOutputData::SEHeadRotationQuaternion(q) => return Some(*q),
+            SEOutputData::SEHeadRotationRodrigues(r) => rodrigues = Some(*r),
+            _ => (),
+        }
+    }
+    rodrigues.map(quaternion_from_rodrigues)
+}
+
+// Head pose of the packet. None unless it has a head position or rotation.
+pub fn head_pose(packet: &[SEOutputData]) -> Option<HeadPose> {
+    let position = packet.iter().find_map(|data| match data {
+        SEOutputData::SEHeadPosition(v) => Some(*v),
+        _ => None,
+    });
+    let rotation = head_rotation(packet);
+    if position.is_none() && rotation.is_none() {
+        return None;
+    }
+    Some(HeadPose {
+        position,
+        rotation,
+        position_quality: packet::field_f64(packet, SEOutputDataId::SEHeadPositionQ),
+        rotation_quality: packet::field_f64(packet, SEOutputDataId::SEHeadRotationQ),
+    })
+}
+
+impl HeadVelocityEstimator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Velocity since the previous packet with position and timestamp. None
+    // for the first such packet, for skipped packets, and if the timestamp
+    // did not increase (in which case the estimation starts over).
+    pub fn push(&mut self, packet: &[SEOutputData]) -> Option<HeadVelocity> {
+        let time_stamp = packet::timestamp(packet)?;
+        let position = packet.iter().find_map(|data| match data {
+            SEOutputData::SEHeadPosition(v) => Some(*v),
+            _ => None,
+        })?;
+        let rotation = head_rotation(packet);
+        let (prev_time_stamp, prev_position, prev_rotation) =
+            self.previous.replace((time_stamp, position, rotation))?;
+        if time_stamp <= prev_time_stamp {
+            return None;
+        }
+
+        let dt = (time_stamp - prev_time_stamp) as f64 / packet::TIME_STAMP_TICKS_PER_SECOND as f64;
+        let Vect3D(dx, dy, dz) = position - prev_position;
+        // Positions are in meters.
+        let linear = Vect3D(dx * 1000.0 / dt, dy * 1000.0 / dt, dz * 1000.0 / dt);
+        let angular = match (prev_rotation, rotation) {
+            (Some(prev), Some(current)) => {
+                let delta = quaternion_mul(current, quaternion_conj(prev));
+                let Vect3D(x, y, z) = rodrigues_from_quaternion(delta);
+                Some(Vect3D(x / dt, y / dt, z / dt))
+            }
+            _ => None,
+        };
+        Some(HeadVelocity { linear, angular })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vect_eq(Vect3D(ax, ay, az): Vect3D, Vect3D(bx, by, bz): Vect3D) {
+        assert!(
+            (ax - bx).abs() < 1e-9 && (ay - by).abs() < 1e-9 && (az - bz).abs() < 1e-9,
+            "{:?} != {:?}",
+            (ax, ay, az),
+            (bx, by, bz)
+        );
+    }
+
+    #[test]
+    fn test_head_velocity_estimator() {
+        let mut estimator = HeadVelocityEstimator::new();
+        assert_eq!(
+            estimator.push(&[
+                SEOutputData::SETimeStamp(1_000_000),
+                SEOutputData::SEHeadPosition(Point3D(0.0, 0.0, 0.6)),
+                SEOutputData::SEHeadRotationRodrigues(Vect3D(0.0, 0.1, 0.0)),
+            ]),
+            None
+        );
+        // Skipped, as it has no position.
+        assert_eq!(
+            estimator.push(&[SEOutputData::SETimeStamp(1_100_000)]),
+            None
+        );
+
+        // 10 mm along x and 0.02 rad around y in 0.1 s.
+        let velocity = estimator
+            .push(&[
+                SEOutputData::SETimeStamp(2_000_000),
+                SEOutputData::SEHeadPosition(Point3D(0.01, 0.0, 0.6)),
+                SEOutputData::SEHeadRotationRodrigues(Vect3D(0.0, 0.12, 0.0)),
+            ])
+            .unwrap();
+        assert_vect_eq(velocity.linear, Vect3D(100.0, 0.0, 0.0));
+        assert_vect_eq(velocity.angular.unwrap(), Vect3D(0.0, 0.2, 0.0));
+    }
+}
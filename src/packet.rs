@@ -0,0 +1,544 @@
+use crate::se_types::{
+    Matrix2x2, Matrix3X3, Point2D, Point3D, Quaternion, SEOutputData, SEOutputDataId, SEStructItem,
+    SETypeId, SEVariant, Vect2D, Vect3D, WorldIntersection, ZoneId,
+};
+use std::{
+    collections::{BTreeSet, HashMap},
+    ops::RangeInclusive,
+};
+
+// First field in the packet with the given id.
+pub fn find_field(packet: &[SEOutputData], id: SEOutputDataId) -> Option<&SEOutputData> {
+    packet.iter().find(|data| data.id() == id)
+}
+
+// Value of the first field with the given id, if it is numeric.
+pub fn field_f64(packet: &[SEOutputData], id: SEOutputDataId) -> Option<f64> {
+    find_field(packet, id).and_then(|data| SEVariant::from(data.clone()).as_f64())
+}
+
+// SETimeStamp is in units of 100 ns.
+pub(crate) const TIME_STAMP_TICKS_PER_SECOND: u64 = 10_000_000;
+
+pub fn timestamp(packet: &[SEOutputData]) -> Option<u64> {
+    packet.iter().find_map(|data| match data {
+        SEOutputData::SETimeStamp(v) => Some(*v),
+        _ => None,
+    })
+}
+
+pub fn frame_number(packet: &[SEOutputData]) -> Option<u32> {
+    packet.iter().find_map(|data| match data {
+        SEOutputData::SEFrameNumber(v) => Some(*v),
+        _ => None,
+    })
+}
+
+pub fn zone_id(packet: &[SEOutputData]) -> Option<ZoneId> {
+    packet.iter().find_map(SEOutputData::as_zone_id)
+}
+
+// Name of the current gaze zone of the packet, looked up in names by zone id.
+pub fn zone_name<'a>(packet: &[SEOutputData], names: &'a HashMap<u16, String>) -> Option<&'a str> {
+    let ZoneId(id) = zone_id(packet)?;
+    names.get(&id).map(String::as_str)
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct PupilDiameters {
+    pub combined: Option<f64>,
+    pub left: Option<f64>,
+    pub right: Option<f64>,
+}
+
+// Combined, left and right pupil diameters of the packet, taken from either
+// the filtered or the unfiltered pupil diameter fields.
+pub fn pupil_diameters(packet: &[SEOutputData], filtered: bool) -> PupilDiameters {
+    let (combined, left, right) = if filtered {
+        (
+            SEOutputDataId::SEFilteredPupilDiameter,
+            SEOutputDataId::SEFilteredLeftPupilDiameter,
+            SEOutputDataId::SEFilteredRightPupilDiameter,
+        )
+    } else {
+        (
+            SEOutputDataId::SEPupilDiameter,
+            SEOutputDataId::SELeftPupilDiameter,
+            SEOutputDataId::SERightPupilDiameter,
+        )
+    };
+    PupilDiameters {
+        combined: field_f64(packet, combined),
+        left: field_f64(packet, left),
+        right: field_f64(packet, right),
+    }
+}
+
+// All world intersections of the packet, from both single and plural world
+// intersection fields, tagged with the id of the field they came from.
+pub fn all_intersections(packet: &[SEOutputData]) -> Vec<(SEOutputDataId, WorldIntersection)> {
+    let mut intersections = Vec::new();
+    for data in packet {
+        let id = data.id();
+        if !matches!(
+            id.type_id(),
+            SETypeId::WorldIntersection | SETypeId::WorldIntersections
+        ) {
+            continue;
+        }
+        match SEVariant::from(data.clone()) {
+            SEVariant::WorldIntersection(Some(v)) => intersections.push((id, v)),
+            SEVariant::WorldIntersections(vs) => {
+                intersections.extend(vs.into_iter().map(|v| (id, v)))
+            }
+            _ => (),
+        }
+    }
+    intersections
+}
+
+// Whether the packets have the same fields, regardless of their order. Fields
+// occurring more than once must occur equally often in both packets.
+pub fn eq_unordered(a: &[SEOutputData], b: &[SEOutputData]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut matched = vec![false; b.len()];
+    a.iter().all(|x| {
+        let found = b
+            .iter()
+            .zip(matched.iter_mut())
+            .find(|(y, matched)| !**matched && x == *y);
+        match found {
+            Some((_, matched)) => {
+                *matched = true;
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+fn collect_variant_keys(v: &SEVariant, keys: &mut BTreeSet<String>) {
+    match v {
+        SEVariant::Vector(items) => {
+            for item in items {
+                collect_variant_keys(item, keys);
+            }
+        }
+        SEVariant::Struct(items) => {
+            for SEStructItem(key, value) in items {
+                keys.insert(key.clone());
+                collect_variant_keys(value, keys);
+            }
+        }
+        _ => (),
+    }
+}
+
+// All distinct struct keys found anywhere in the packet, including in
+// structs nested in vectors and other structs.
+pub fn collect_struct_keys(packet: &[SEOutputData]) -> BTreeSet<String> {
+    let mut keys = BTreeSet::new();
+    for data in packet {
+        collect_variant_keys(&SEVariant::from(data.clone()), &mut keys);
+    }
+    keys
+}
+
+// Expected shape of a packet: fields that must be present, and allowed
+// ranges for numeric fields.
+#[derive(Debug, Clone, Default)]
+pub struct PacketTemplate {
+    required: Vec<SEOutputDataId>,
+    ranges: Vec<(SEOutputDataId, RangeInclusive<f64>)>,
+}
+
+impl PacketTemplate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn require(mut self, id: SEOutputDataId) -> Self {
+        self.required.push(id);
+        self
+    }
+
+    // Constrains the value of a numeric field, if present. Combine with
+    // require to also make the field mandatory.
+    pub fn range(mut self, id: SEOutputDataId, range: RangeInclusive<f64>) -> Self {
+        self.ranges.push((id, range));
+        self
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RangeViolation {
+    pub id: SEOutputDataId,
+    pub value: f64,
+    pub range: RangeInclusive<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TemplateResult {
+    pub missing: Vec<SEOutputDataId>,
+    pub violations: Vec<RangeViolation>,
+}
+
+impl TemplateResult {
+    pub fn is_match(&self) -> bool {
+        self.missing.is_empty() && self.violations.is_empty()
+    }
+}
+
+pub fn matches_template(packet: &[SEOutputData], template: &PacketTemplate) -> TemplateResult {
+    let missing = template
+        .required
+        .iter()
+        .copied()
+        .filter(|&id| find_field(packet, id).is_none())
+        .collect();
+    let violations = template
+        .ranges
+        .iter()
+        .filter_map(|(id, range)| {
+            let value = field_f64(packet, *id)?;
+            if range.contains(&value) {
+                None
+            } else {
+                Some(RangeViolation {
+                    id: *id,
+                    value,
+                    range: range.clone(),
+                })
+            }
+        })
+        .collect();
+    TemplateResult {
+        missing,
+        violations,
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum FieldDiff {
+    Added(SEOutputDataId),
+    Removed(SEOutputDataId),
+    Changed {
+        id: SEOutputDataId,
+        from: SEOutputData,
+        to: SEOutputData,
+    },
+}
+
+fn f64s_approx_eq(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() <= epsilon)
+}
+
+fn world_intersection_approx_eq(
+    a: &WorldIntersection,
+    b: &WorldIntersection,
+    epsilon: f64,
+) -> bool {
+    let coordinates = |v: &WorldIntersection| {
+        let (Point3D(x, y, z), Point3D(ox, oy, oz)) = (v.world_point, v.object_point);
+        [x, y, z, ox, oy, oz]
+    };
+    a.object_name == b.object_name && f64s_approx_eq(&coordinates(a), &coordinates(b), epsilon)
+}
+
+// Whether the variants are equal, allowing floating point values to differ by
+// at most epsilon.
+fn variant_approx_eq(a: &SEVariant, b: &SEVariant, epsilon: f64) -> bool {
+    match (a, b) {
+        (SEVariant::F64(a), SEVariant::F64(b)) => f64s_approx_eq(&[*a], &[*b], epsilon),
+        (SEVariant::F32(a), SEVariant::F32(b)) => {
+            f64s_approx_eq(&[f64::from(*a)], &[f64::from(*b)], epsilon)
+        }
+        (SEVariant::Point2D(Point2D(ax, ay)), SEVariant::Point2D(Point2D(bx, by)))
+        | (SEVariant::Vect2D(Vect2D(ax, ay)), SEVariant::Vect2D(Vect2D(bx, by))) => {
+            f64s_approx_eq(&[*ax, *ay], &[*bx, *by], epsilon)
+        }
+        (SEVariant::Point3D(Point3D(ax, ay, az)), SEVariant::Point3D(Point3D(bx, by, bz)))
+        | (SEVariant::Vect3D(Vect3D(ax, ay, az)), SEVariant::Vect3D(Vect3D(bx, by, bz))) => {
+            f64s_approx_eq(&[*ax, *ay, *az], &[*bx, *by, *bz], epsilon)
+        }
+        (
+            SEVariant::Quaternion(Quaternion(aw, ax, ay, az)),
+            SEVariant::Quaternion(Quaternion(bw, bx, by, bz)),
+        ) => f64s_approx_eq(&[*aw, *ax, *ay, *az], &[*bw, *bx, *by, *bz], epsilon),
+        (SEVariant::Matrix3X3(Matrix3X3(a)), SEVariant::Matrix3X3(Matrix3X3(b))) => {
+            f64s_approx_eq(&a.concat(), &b.concat(), epsilon)
+        }
+        (SEVariant::Matrix2x2(Matrix2x2(a)), SEVariant::Matrix2x2(Matrix2x2(b))) => {
+            f64s_approx_eq(&a.concat(), &b.concat(), epsilon)
+        }
+        (SEVariant::Vector(a), SEVariant::Vector(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(a, b)| variant_approx_eq(a, b, epsilon))
+        }
+        (SEVariant::Struct(a), SEVariant::Struct(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(SEStructItem(ak, av), SEStructItem(bk, bv))| {
+                        ak == bk && variant_approx_eq(av, bv, epsilon)
+                    })
+        }
+        (SEVariant::WorldIntersection(Some(a)), SEVariant::WorldIntersection(Some(b))) => {
+            world_intersection_approx_eq(a, b, epsilon)
+        }
+        (SEVariant::WorldIntersections(a), SEVariant::WorldIntersections(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(a, b)| world_intersection_approx_eq(a, b, epsilon))
+        }
+        _ => a == b,
+    }
+}
+
+// Fields added, removed or changed going from packet a to packet b, keyed
+// by id (only the first field with a given id is compared). Floating point
+// values differing by at most epsilon are considered unchanged. Removed and
+// changed fields are listed in the order of a, followed by the added fields
+// in the order of b.
+pub fn diff_packets(a: &[SEOutputData], b: &[SEOutputData], epsilon: f64) -> Vec<FieldDiff> {
+    let mut diffs = Vec::new();
+    for (i, from) in a.iter().enumerate() {
+        let id = from.id();
+        if find_field(&a[..i], id).is_some() {
+            continue;
+        }
+        match find_field(b, id) {
+            None => diffs.push(FieldDiff::Removed(id)),
+            Some(to) => {
+                let (from_v, to_v) = (SEVariant::from(from.clone()), SEVariant::from(to.clone()));
+                if !variant_approx_eq(&from_v, &to_v, epsilon) {
+                    diffs.push(FieldDiff::Changed {
+                        id,
+                        from: from.clone(),
+                        to: to.clone(),
+                    });
+                }
+            }
+        }
+    }
+    for (i, to) in b.iter().enumerate() {
+        let id = to.id();
+        if find_field(&b[..i], id).is_none() && find_field(a, id).is_none() {
+            diffs.push(FieldDiff::Added(id));
+        }
+    }
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_vector_value;
+
+    #[test]
+    fn test_all_intersections() {
+        let intersection = |name: &str| WorldIntersection {
+            world_point: Point3D(0.0, 0.0, 0.0),
+            object_point: Point3D(0.1, 0.2, 0.0),
+            object_name: String::from(name),
+        };
+        let packet = [
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEClosestWorldIntersection(Some(intersection("Screen"))),
+            SEOutputData::SELeftClosestWorldIntersection(None),
+            SEOutputData::SELeftAllWorldIntersections(vec![
+                intersection("Screen"),
+                intersection("Wall"),
+            ]),
+        ];
+        assert_eq!(
+            all_intersections(&packet),
+            vec![
+                (
+                    SEOutputDataId::SEClosestWorldIntersection,
+                    intersection("Screen")
+                ),
+                (
+                    SEOutputDataId::SELeftAllWorldIntersections,
+                    intersection("Screen")
+                ),
+                (
+                    SEOutputDataId::SELeftAllWorldIntersections,
+                    intersection("Wall")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_eq_unordered() {
+        let a = [
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SETimeStamp(100),
+            SEOutputData::SETimeStamp(100),
+        ];
+        let b = [
+            SEOutputData::SETimeStamp(100),
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SETimeStamp(100),
+        ];
+        assert_ne!(a, b);
+        assert!(eq_unordered(&a, &b));
+
+        let c = [
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SETimeStamp(100),
+        ];
+        assert!(!eq_unordered(&a, &c));
+        assert!(!eq_unordered(&a, &b[..2]));
+    }
+
+    #[test]
+    fn test_pupil_diameters() {
+        let packet = [
+            SEOutputData::SELeftPupilDiameter(0.004),
+            SEOutputData::SERightPupilDiameter(0.005),
+            SEOutputData::SEFilteredLeftPupilDiameter(0.0045),
+        ];
+        assert_eq!(
+            pupil_diameters(&packet, false),
+            PupilDiameters {
+                combined: None,
+                left: Some(0.004),
+                right: Some(0.005),
+            }
+        );
+        assert_eq!(
+            pupil_diameters(&packet, true),
+            PupilDiameters {
+                combined: None,
+                left: Some(0.0045),
+                right: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_collect_struct_keys() {
+        let vector: &[u8] = &[
+            0x00, 0x02, // numElements
+            0x00, 0x0B, // elem[0].typeId (=SEType_Vector)
+            0x00, 0x01, // elem[0].numElements
+            0x00, 0x00, // elem[0].elem[0].typeId (=SEType_u8)
+            0x04, // elem[0].elem[0] (=4)
+            0x00, 0x0C, // elem[1].typeId (SEType_Struct)
+            0x00, 0x01, // elem[1].numElements
+            0x00, 0x03, // elem[1].elem[0].id.length
+            0x41, 0x62, 0x43, // elem[1].elem[0].id.chars ("AbC")
+            0x00, 0x01, // elem[1].elem[0].typeId (=SEType_u16)
+            0x05, 0x39, // elem[1].elem[0] (=1337)
+        ];
+        let packet = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SECameraPositions(parse_vector_value(vector).unwrap()),
+        ];
+        let keys = collect_struct_keys(&packet);
+        assert_eq!(keys.into_iter().collect::<Vec<_>>(), vec!["AbC"]);
+    }
+
+    #[test]
+    fn test_diff_packets() {
+        let a = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEHeadHeading(0.5),
+            SEOutputData::SEGazeHeading(0.1),
+            SEOutputData::SEZoneId(2),
+        ];
+        let b = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEHeadHeading(0.5 + 1e-9),
+            SEOutputData::SEGazeHeading(0.2),
+            SEOutputData::SETimeStamp(100),
+        ];
+        assert_eq!(
+            diff_packets(&a, &b, 1e-6),
+            vec![
+                FieldDiff::Changed {
+                    id: SEOutputDataId::SEGazeHeading,
+                    from: SEOutputData::SEGazeHeading(0.1),
+                    to: SEOutputData::SEGazeHeading(0.2),
+                },
+                FieldDiff::Removed(SEOutputDataId::SEZoneId),
+                FieldDiff::Added(SEOutputDataId::SETimeStamp),
+            ]
+        );
+        assert_eq!(diff_packets(&a, &b, 0.0).len(), 4);
+        assert_eq!(diff_packets(&a, &a, 0.0), vec![]);
+    }
+
+    #[test]
+    fn test_diff_packets_matrices() {
+        let camera = |noise: f64| {
+            SEOutputData::SECameraPositions(vec![
+                SEVariant::Matrix3X3(Matrix3X3([
+                    [1.0, 0.0, 0.0],
+                    [0.0, 1.0 - noise, 0.0],
+                    [0.0, 0.0, 1.0],
+                ])),
+                SEVariant::Matrix2x2(Matrix2x2([[2.0, 0.0], [0.0, 2.0 + noise]])),
+            ])
+        };
+        let a = vec![camera(0.0)];
+        assert_eq!(diff_packets(&a, &[camera(1e-9)], 1e-6), vec![]);
+        assert_eq!(
+            diff_packets(&a, &[camera(0.1)], 1e-6),
+            vec![FieldDiff::Changed {
+                id: SEOutputDataId::SECameraPositions,
+                from: camera(0.0),
+                to: camera(0.1),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_zone_name() {
+        let mut names = HashMap::new();
+        names.insert(2, String::from("Dashboard"));
+        let packet = vec![SEOutputData::SEFrameNumber(1), SEOutputData::SEZoneId(2)];
+        assert_eq!(zone_id(&packet), Some(ZoneId(2)));
+        assert_eq!(zone_name(&packet, &names), Some("Dashboard"));
+        assert_eq!(zone_name(&[SEOutputData::SEZoneId(3)], &names), None);
+    }
+
+    #[test]
+    fn test_matches_template() {
+        let template = PacketTemplate::new()
+            .require(SEOutputDataId::SEFrameNumber)
+            .require(SEOutputDataId::SETimeStamp)
+            .range(SEOutputDataId::SEPupilDiameter, 2.0..=8.0);
+
+        let packet = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SETimeStamp(100),
+            SEOutputData::SEPupilDiameter(4.5),
+        ];
+        assert!(matches_template(&packet, &template).is_match());
+
+        let packet = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEPupilDiameter(9.5),
+        ];
+        assert_eq!(
+            matches_template(&packet, &template),
+            TemplateResult {
+                missing: vec![SEOutputDataId::SETimeStamp],
+                violations: vec![RangeViolation {
+                    id: SEOutputDataId::SEPupilDiameter,
+                    value: 9.5,
+                    range: 2.0..=8.0,
+                }],
+            }
+        );
+    }
+}
@@ -0,0 +1,326 @@
+// Decoding of packets into an arena (a bumpalo::Bump), for sustained high
+// packet rates: all strings and nested vectors of a packet are allocated in
+// the arena, which can be reset between packets instead of freeing many small
+// allocations.
+use super::{
+    check_sub_packet, parse_f32, parse_f64, parse_matrix_2x2, parse_matrix_3x3,
+    parse_packet_header, parse_point_2d, parse_point_3d, parse_quaternion, parse_raw_sub_packet,
+    parse_s32, parse_u16, parse_u32, parse_u64, parse_u8, parse_user_marker, parse_vect_2d,
+    parse_vect_3d, to_parse_error, DecodeContext, DecodeError, IResult, ParseError,
+    PACKET_HEADER_SIZE,
+};
+use crate::se_types::*;
+use bumpalo::{collections::Vec as BumpVec, Bump};
+use nom::{bytes::streaming::take, error::ErrorKind};
+use std::convert::TryInto;
+
+// Like SEVariant, but borrowing strings and collections from an arena.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ArenaVariant<'a> {
+    U8(SETypeU8),
+    U16(SETypeU16),
+    U32(SETypeU32),
+    S32(SETypeS32),
+    U64(SETypeU64),
+    F64(SETypeF64),
+    Point2D(SETypePoint2D),
+    Vect2D(SETypeVect2D),
+    Point3D(SETypePoint3D),
+    Vect3D(SETypeVect3D),
+    String(&'a str),
+    Vector(&'a [ArenaVariant<'a>]),
+    Struct(&'a [(&'a str, ArenaVariant<'a>)]),
+    WorldIntersection(Option<ArenaWorldIntersection<'a>>),
+    WorldIntersections(&'a [ArenaWorldIntersection<'a>]),
+    F32(SETypeF32),
+    Matrix3X3(SETypeMatrix3X3),
+    Matrix2x2(SETypeMatrix2x2),
+    Quaternion(SETypeQuaternion),
+    UserMarker(SETypeUserMarker),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ArenaWorldIntersection<'a> {
+    pub world_point: Point3D,
+    pub object_point: Point3D,
+    pub object_name: &'a str,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ArenaOutputData<'a> {
+    pub id: SEOutputDataId,
+    pub value: ArenaVariant<'a>,
+}
+
+impl<'a> ArenaWorldIntersection<'a> {
+    pub fn to_owned(&self) -> WorldIntersection {
+        WorldIntersection {
+            world_point: self.world_point,
+            object_point: self.object_point,
+            object_name: self.object_name.to_string(),
+        }
+    }
+}
+
+impl<'a> ArenaVariant<'a> {
+    // Copies the value out of the arena.
+    pub fn to_owned(&self) -> SEVariant {
+        match *self {
+            ArenaVariant::U8(v) => SEVariant::U8(v),
+            ArenaVariant::U16(v) => SEVariant::U16(v),
+            ArenaVariant::U32(v) => SEVariant::U32(v),
+            ArenaVariant::S32(v) => SEVariant::S32(v),
+            ArenaVariant::U64(v) => SEVariant::U64(v),
+            ArenaVariant::F64(v) => SEVariant::F64(v),
+            ArenaVariant::Point2D(v) => SEVariant::Point2D(v),
+            ArenaVariant::Vect2D(v) => SEVariant::Vect2D(v),
+            ArenaVariant::Point3D(v) => SEVariant::Point3D(v),
+            ArenaVariant::Vect3D(v) => SEVariant::Vect3D(v),
+            ArenaVariant::String(v) => SEVariant::String(v.to_string()),
+            ArenaVariant::Vector(items) => {
+                SEVariant::Vector(items.iter().map(ArenaVariant::to_owned).collect())
+            }
+            ArenaVariant::Struct(items) => SEVariant::Struct(
+                items
+                    .iter()
+                    .map(|(key, v)| SEStructItem(key.to_string(), v.to_owned()))
+                    .collect(),
+            ),
+            ArenaVariant::WorldIntersection(v) => {
+                SEVariant::WorldIntersection(v.as_ref().map(ArenaWorldIntersection::to_owned))
+            }
+            ArenaVariant::WorldIntersections(items) => SEVariant::WorldIntersections(
+                items.iter().map(ArenaWorldIntersection::to_owned).collect(),
+            ),
+            ArenaVariant::F32(v) => SEVariant::F32(v),
+            ArenaVariant::Matrix3X3(v) => SEVariant::Matrix3X3(v),
+            ArenaVariant::Matrix2x2(v) => SEVariant::Matrix2x2(v),
+            ArenaVariant::Quaternion(v) => SEVariant::Quaternion(v),
+            ArenaVariant::UserMarker(v) => SEVariant::UserMarker(v),
+        }
+    }
+}
+
+impl<'a> ArenaOutputData<'a> {
+    // Copies the output data out of the arena.
+    pub fn to_owned(&self) -> SEOutputData {
+        SEOutputData::from_variant(self.id, self.value.to_owned())
+            .expect("value decoded by the type of the id")
+    }
+}
+
+fn parse_str_in<'i, 'a>(i: &'i [u8], arena: &'a Bump) -> IResult<&'i [u8], &'a str> {
+    let (rest, length) = parse_u16(i)?;
+    let (rest, bytes) = take(length)(rest)?;
+    let s = std::str::from_utf8(bytes)
+        .map_err(|_| nom::Err::Error(DecodeError::Nom(i, ErrorKind::MapRes)))?;
+    Ok((rest, arena.alloc_str(s)))
+}
+
+fn parse_world_intersection_item_in<'i, 'a>(
+    i: &'i [u8],
+    arena: &'a Bump,
+) -> IResult<&'i [u8], ArenaWorldIntersection<'a>> {
+    let (i, world_point) = parse_point_3d(i)?;
+    let (i, object_point) = parse_point_3d(i)?;
+    let (i, object_name) = parse_str_in(i, arena)?;
+    Ok((
+        i,
+        ArenaWorldIntersection {
+            world_point,
+            object_point,
+            object_name,
+        },
+    ))
+}
+
+// Parses a u16 count followed by that many items into the arena. The count
+// is untrusted and the arena only frees on reset, so nothing is reserved up
+// front based on it.
+fn parse_count_in<'i, 'a, T, F>(
+    i: &'i [u8],
+    arena: &'a Bump,
+    mut item: F,
+) -> IResult<&'i [u8], &'a [T]>
+where
+    F: FnMut(&'i [u8]) -> IResult<&'i [u8], T>,
+{
+    let (mut i, length) = parse_u16(i)?;
+    let mut items = BumpVec::new_in(arena);
+    for _ in 0..length {
+        let (rest, v) = item(i)?;
+        items.push(v);
+        i = rest;
+    }
+    Ok((i, items.into_bump_slice()))
+}
+
+// Parses a value of the given type.
+fn parse_value_in<'i, 'a>(
+    type_id: SETypeId,
+    i: &'i [u8],
+    arena: &'a Bump,
+    ctx: &DecodeContext,
+) -> IResult<&'i [u8], ArenaVariant<'a>> {
+    match type_id {
+        SETypeId::U8 => parse_u8(i).map(|(i, v)| (i, ArenaVariant::U8(v))),
+        SETypeId::U16 => parse_u16(i).map(|(i, v)| (i, ArenaVariant::U16(v))),
+        SETypeId::U32 => parse_u32(i).map(|(i, v)| (i, ArenaVariant::U32(v))),
+        SETypeId::S32 => parse_s32(i).map(|(i, v)| (i, ArenaVariant::S32(v))),
+        SETypeId::U64 => parse_u64(i).map(|(i, v)| (i, ArenaVariant::U64(v))),
+        SETypeId::F64 => parse_f64(i).map(|(i, v)| (i, ArenaVariant::F64(v))),
+        SETypeId::Point2D => parse_point_2d(i).map(|(i, v)| (i, ArenaVariant::Point2D(v))),
+        SETypeId::Vect2D => parse_vect_2d(i).map(|(i, v)| (i, ArenaVariant::Vect2D(v))),
+        SETypeId::Point3D => parse_point_3d(i).map(|(i, v)| (i, ArenaVariant::Point3D(v))),
+        SETypeId::Vect3D => parse_vect_3d(i).map(|(i, v)| (i, ArenaVariant::Vect3D(v))),
+        SETypeId::String => parse_str_in(i, arena).map(|(i, v)| (i, ArenaVariant::String(v))),
+        SETypeId::Vector => ctx
+            .nested(i, || {
+                parse_count_in(i, arena, |i| parse_variant_in(i, arena, ctx))
+            })
+            .map(|(i, v)| (i, ArenaVariant::Vector(v))),
+        SETypeId::Struct => ctx
+            .nested(i, || {
+                parse_count_in(i, arena, |i| {
+                    let (i, key) = parse_str_in(i, arena)?;
+                    let (i, v) = parse_variant_in(i, arena, ctx)?;
+                    Ok((i, (key, v)))
+                })
+            })
+            .map(|(i, v)| (i, ArenaVariant::Struct(v))),
+        SETypeId::WorldIntersection => {
+            let (rest, exists) = parse_u16(i)?;
+            match exists {
+                0 => Ok((rest, ArenaVariant::WorldIntersection(None))),
+                1 => parse_world_intersection_item_in(rest, arena)
+                    .map(|(i, v)| (i, ArenaVariant::WorldIntersection(Some(v)))),
+                flag => Err(nom::Err::Failure(DecodeError::InvalidExistsFlag {
+                    input: i,
+                    flag,
+                })),
+            }
+        }
+        SETypeId::WorldIntersections => {
+            parse_count_in(i, arena, |i| parse_world_intersection_item_in(i, arena))
+                .map(|(i, v)| (i, ArenaVariant::WorldIntersections(v)))
+        }
+        SETypeId::PacketHeader | SETypeId::SubPacketHeader => {
+            Err(nom::Err::Failure(DecodeError::InvalidVariantType(type_id)))
+        }
+        SETypeId::F32 => parse_f32(i).map(|(i, v)| (i, ArenaVariant::F32(v))),
+        SETypeId::Matrix3X3 => parse_matrix_3x3(i).map(|(i, v)| (i, ArenaVariant::Matrix3X3(v))),
+        SETypeId::Matrix2x2 => parse_matrix_2x2(i).map(|(i, v)| (i, ArenaVariant::Matrix2x2(v))),
+        SETypeId::Quaternion => parse_quaternion(i).map(|(i, v)| (i, ArenaVariant::Quaternion(v))),
+        SETypeId::UserMarker => parse_user_marker(i).map(|(i, v)| (i, ArenaVariant::UserMarker(v))),
+    }
+}
+
+// Parses a type id followed by a value of that type.
+fn parse_variant_in<'i, 'a>(
+    i: &'i [u8],
+    arena: &'a Bump,
+    ctx: &DecodeContext,
+) -> IResult<&'i [u8], ArenaVariant<'a>> {
+    ctx.spend(i)?;
+    let (rest, type_id) = parse_u16(i)?;
+    let type_id: SETypeId = type_id
+        .try_into()
+        .map_err(|_| nom::Err::Failure(DecodeError::UnknownTypeId { input: i, type_id }))?;
+    parse_value_in(type_id, rest, arena, ctx)
+}
+
+// Like parse_packet, but allocates the sub-packets, and all strings and
+// vectors within them, in arena. Resetting the arena frees them all at once.
+pub fn parse_packet_in<'a>(
+    i: &[u8],
+    arena: &'a Bump,
+) -> Result<BumpVec<'a, ArenaOutputData<'a>>, ParseError> {
+    let header = parse_packet_header(i)?;
+    let payload = i
+        .get(PACKET_HEADER_SIZE..PACKET_HEADER_SIZE + header.length as usize)
+        .ok_or(ParseError::Failed)?;
+    let ctx = DecodeContext::unlimited();
+    let mut out = BumpVec::new_in(arena);
+    let mut data = payload;
+    while !data.is_empty() {
+        check_sub_packet(data)?;
+        let (rest, (header, sub_packet)) =
+            parse_raw_sub_packet(data).map_err(|e| to_parse_error(payload, e))?;
+        let (remaining, value) = parse_value_in(header.id.type_id(), sub_packet, arena, &ctx)
+            .map_err(|e| to_parse_error(payload, e))?;
+        if !remaining.is_empty() {
+            return Err(ParseError::Failed);
+        }
+        out.push(ArenaOutputData {
+            id: header.id,
+            value,
+        });
+        data = rest;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Packet, parser::parse_packet, serializer::serialize_packet};
+
+    #[test]
+    fn test_parse_packet_in() {
+        let nested = SEOutputData::SECameraPositions(vec![
+            SEVariant::Struct(vec![
+                SEStructItem(
+                    String::from("name"),
+                    SEVariant::String(String::from("cam0")),
+                ),
+                SEStructItem(
+                    String::from("position"),
+                    SEVariant::Vector(vec![SEVariant::Point3D(Point3D(0.1, 0.2, 0.3))]),
+                ),
+            ]),
+            SEVariant::Point3D(Point3D(0.4, 0.5, 0.6)),
+        ]);
+        let first = serialize_packet(&[SEOutputData::SEFrameNumber(1), nested.clone()]);
+        let second = serialize_packet(&[SEOutputData::SEFrameNumber(2)]);
+
+        let mut arena = Bump::new();
+        {
+            let packet = parse_packet_in(&first, &arena).unwrap();
+            assert_eq!(packet.len(), 2);
+            match packet[1].value {
+                ArenaVariant::Vector(
+                    [ArenaVariant::Struct([("name", ArenaVariant::String("cam0")), _]), _],
+                ) => (),
+                v => panic!("unexpected value {:?}", v),
+            }
+            let owned: Packet = packet.iter().map(ArenaOutputData::to_owned).collect();
+            assert_eq!(Ok(owned), parse_packet(&first));
+        }
+        arena.reset();
+
+        let packet = parse_packet_in(&second, &arena).unwrap();
+        assert_eq!(packet[0].to_owned(), SEOutputData::SEFrameNumber(2));
+        assert_eq!(
+            parse_packet_in(&first[..first.len() - 1], &arena).map(|p| p.len()),
+            Err(ParseError::Failed)
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_in_large_counts() {
+        // Vectors nested 30 deep, each claiming 65535 items.
+        let mut data = vec![0xFF, 0xFF];
+        for _ in 0..29 {
+            data.extend_from_slice(&[0x00, SETypeId::Vector as u8, 0xFF, 0xFF]);
+        }
+        let mut packet = b"SEPD\x00\x04".to_vec();
+        packet.extend_from_slice(&(data.len() as u16 + 4).to_be_bytes());
+        packet.extend_from_slice(&(SEOutputDataId::SECameraPositions as u16).to_be_bytes());
+        packet.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        packet.extend_from_slice(&data);
+
+        let arena = Bump::new();
+        assert!(parse_packet_in(&packet, &arena).is_err());
+        assert!(arena.allocated_bytes() < 64 * 1024);
+    }
+}
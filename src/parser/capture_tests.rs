@@ -1,11 +1,12 @@
 use super::*;
+use crate::test_support::packet_of;
 
 #[test]
 fn test_parse_packet_capture_sep_default_license_all_output() {
     let packet = include_bytes!("../../assets/captures/SEP_DEFAULT_LICENSE_ALL_OUTPUT.bin");
     assert_eq!(
         parse_packet(packet),
-        Ok(vec![
+        Ok(packet_of(vec![
             SEOutputData::SEFrameNumber(41589),
             SEOutputData::SEEstimatedDelay(314802991),
             SEOutputData::SETimeStamp(8462866257),
@@ -305,6 +306,6 @@ fn test_parse_packet_capture_sep_default_license_all_output() {
             SEOutputData::SEFilteredEstimatedRightGazeDirectionQ(0.7940276265144348),
             SEOutputData::SEFilteredEstimatedRightGazeHeading(-0.36365418016632733),
             SEOutputData::SEFilteredEstimatedRightGazePitch(0.15582549372422766)
-        ])
+        ]))
     );
 }
@@ -0,0 +1,113 @@
+use super::{
+    packet_offsets, parse_packet_data_into, parse_packet_header, ParseError, Progress,
+    PACKET_HEADER_SIZE,
+};
+use crate::client::Packet;
+use memmap2::Mmap;
+use std::{fs::File, io, ops::Range, path::Path, vec};
+
+struct MmapPacketIter<F> {
+    mmap: Mmap,
+    offsets: vec::IntoIter<Range<usize>>,
+    progress: Option<Progress<F>>,
+}
+
+impl<F: FnMut(u64, u64)> Iterator for MmapPacketIter<F> {
+    type Item = Result<Packet, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = match self.offsets.next() {
+            Some(range) => range,
+            None => {
+                if let Some(mut progress) = self.progress.take() {
+                    progress.finish(self.mmap.len() as u64);
+                }
+                return None;
+            }
+        };
+        if let Some(progress) = &mut self.progress {
+            progress.update(range.end as u64);
+        }
+        let buf = &self.mmap[range];
+        let mut packet = Packet::new();
+        let res = parse_packet_header(buf).and_then(|header| {
+            parse_packet_data_into(header, &buf[PACKET_HEADER_SIZE..], &mut packet)
+        });
+        Some(res.map(|_| packet))
+    }
+}
+
+// Iterates the packets of a capture file without reading it into memory,
+// by memory mapping it. The file must not be modified while iterating.
+pub fn iter_packets_mmap(
+    path: &Path,
+) -> io::Result<impl Iterator<Item = Result<Packet, ParseError>>> {
+    iter_packets_mmap_with_progress(path, |_, _| ())
+}
+
+// Like iter_packets_mmap, periodically calling progress with the number of
+// bytes of the file processed so far and the size of the file.
+pub fn iter_packets_mmap_with_progress<F: FnMut(u64, u64)>(
+    path: &Path,
+    progress: F,
+) -> io::Result<impl Iterator<Item = Result<Packet, ParseError>>> {
+    let file = File::open(path)?;
+    // Safety: modifying the file while mapped is undefined behaviour, which
+    // the caller is required to prevent.
+    let mmap = unsafe { Mmap::map(&file)? };
+    let offsets = packet_offsets(&mmap).into_iter();
+    let progress = Some(Progress::new(progress, mmap.len() as u64));
+    Ok(MmapPacketIter {
+        mmap,
+        offsets,
+        progress,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{se_types::SEOutputData, serializer::serialize_packet, test_support::packet_of};
+    use std::{env, fs, process};
+
+    #[test]
+    fn test_iter_packets_mmap() {
+        let first = packet_of(vec![SEOutputData::SEFrameNumber(1)]);
+        let second = packet_of(vec![
+            SEOutputData::SEFrameNumber(2),
+            SEOutputData::SETimeStamp(100),
+        ]);
+        let path = env::temp_dir().join(format!("sep-data-test-{}.bin", process::id()));
+        fs::write(
+            &path,
+            [serialize_packet(&first), serialize_packet(&second)].concat(),
+        )
+        .unwrap();
+
+        let packets: Vec<_> = iter_packets_mmap(&path).unwrap().collect();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(packets, vec![Ok(first), Ok(second)]);
+    }
+
+    #[test]
+    fn test_iter_packets_mmap_with_progress() {
+        let packet = serialize_packet(&[SEOutputData::SEFrameNumber(1)]);
+        let capture = packet.repeat(20_000);
+        let path = env::temp_dir().join(format!("sep-data-test-progress-{}.bin", process::id()));
+        fs::write(&path, &capture).unwrap();
+
+        let mut calls = Vec::new();
+        let count = iter_packets_mmap_with_progress(&path, |processed, total| {
+            calls.push((processed, total))
+        })
+        .unwrap()
+        .count();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(count, 20_000);
+        let total = capture.len() as u64;
+        assert!(calls.len() > 2);
+        assert!(calls.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(calls.iter().all(|&(_, t)| t == total));
+        assert_eq!(calls.last(), Some(&(total, total)));
+    }
+}
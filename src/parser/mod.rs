@@ -1,30 +1,199 @@
-use crate::se_types::*;
+use crate::{client::Packet, se_types::*};
 use nom::{
     bytes::streaming::{tag, take},
-    combinator::{all_consuming, eof, map, map_parser, map_res},
-    multi::{count, many_till},
+    combinator::{all_consuming, map_res},
+    error::{ErrorKind, FromExternalError},
+    multi::count,
     number::complete::{be_f32, be_f64, be_i32, be_u16, be_u32, be_u64, be_u8},
     sequence::tuple,
-    IResult,
 };
-use std::convert::{TryFrom, TryInto};
+use std::{
+    cell::Cell,
+    convert::{TryFrom, TryInto},
+    iter,
+    ops::Range,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
-#[error("Parse failed")]
-pub struct ParseFailedError {}
+pub enum ParseError {
+    #[error("Parse failed")]
+    Failed,
+
+    // The declared packet length left bytes at the end of the packet that
+    // are too few to form a sub-packet, e.g. due to a bad length in the sender.
+    #[error("{remaining} trailing bytes at end of packet")]
+    TrailingBytes { remaining: usize },
+
+    // A variant with a type id not known by the parser. Since the size of
+    // the variant can not be known, the packet can not be parsed further.
+    // The offset is that of the type id, relative to the start of the
+    // parsed data (i.e. the packet payload when parsing packet data).
+    #[error("unknown variant type id {type_id:#06x} at offset {offset}")]
+    UnknownTypeId { type_id: u16, offset: usize },
+
+    // A sub-packet declared a length smaller than any valid value of its type.
+    #[error("sub-packet {id:?} declared length {declared}, expected at least {minimum}")]
+    SubPacketTooShort {
+        id: SEOutputDataId,
+        declared: usize,
+        minimum: usize,
+    },
+
+    // A sub-packet declared a length larger than the bytes remaining in the
+    // packet, i.e. the packet is inconsistent rather than truncated.
+    #[error("sub-packet {id:?} declared length {declared}, but only {available} bytes remain")]
+    SubPacketOverrun {
+        id: SEOutputDataId,
+        declared: usize,
+        available: usize,
+    },
+
+    // A variant with a type id that is known, but never valid as the type of
+    // a variant (i.e. PacketHeader or SubPacketHeader).
+    #[error("invalid variant type {0:?}")]
+    InvalidVariantType(SETypeId),
+
+    // The "exists" flag of a WorldIntersection or UserMarker was neither 0
+    // nor 1. The offset is that of the flag, as for UnknownTypeId.
+    #[error("invalid exists flag {flag:#06x} at offset {offset}")]
+    InvalidExistsFlag { flag: u16, offset: usize },
+}
 
-pub const PACKET_HEADER_SIZE: usize = 4 + 2 + 2;
+#[cfg(feature = "arena")]
+mod arena;
+#[cfg(feature = "arena")]
+pub use arena::{parse_packet_in, ArenaOutputData, ArenaVariant, ArenaWorldIntersection};
+
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::{iter_packets_mmap, iter_packets_mmap_with_progress};
 
+mod validate;
+pub use validate::{validate_capture, validate_capture_with_progress, CaptureReport};
+
+// Error of the nom parsers. Most errors are only reported as
+// ParseError::Failed, but some carry details for more precise reporting.
 #[derive(Debug, PartialEq)]
+enum DecodeError<I> {
+    Nom(I, ErrorKind),
+    UnknownTypeId { input: I, type_id: u16 },
+    InvalidVariantType(SETypeId),
+    InvalidExistsFlag { input: I, flag: u16 },
+}
+
+impl<I> nom::error::ParseError<I> for DecodeError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        DecodeError::Nom(input, kind)
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for DecodeError<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: E) -> Self {
+        DecodeError::Nom(input, kind)
+    }
+}
+
+type IResult<I, O> = nom::IResult<I, O, DecodeError<I>>;
+
+// Converts an error from parsing a part of base to a ParseError.
+fn to_parse_error(base: &[u8], err: nom::Err<DecodeError<&[u8]>>) -> ParseError {
+    match err {
+        nom::Err::Error(DecodeError::UnknownTypeId { input, type_id })
+        | nom::Err::Failure(DecodeError::UnknownTypeId { input, type_id }) => {
+            ParseError::UnknownTypeId {
+                type_id,
+                offset: input.as_ptr() as usize - base.as_ptr() as usize,
+            }
+        }
+        nom::Err::Error(DecodeError::InvalidExistsFlag { input, flag })
+        | nom::Err::Failure(DecodeError::InvalidExistsFlag { input, flag }) => {
+            ParseError::InvalidExistsFlag {
+                flag,
+                offset: input.as_ptr() as usize - base.as_ptr() as usize,
+            }
+        }
+        nom::Err::Error(DecodeError::InvalidVariantType(type_id))
+        | nom::Err::Failure(DecodeError::InvalidVariantType(type_id)) => {
+            ParseError::InvalidVariantType(type_id)
+        }
+        _ => ParseError::Failed,
+    }
+}
+
+pub const PACKET_HEADER_SIZE: usize = 4 + 2 + 2;
+pub const SUB_PACKET_HEADER_SIZE: usize = 2 + 2;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct PacketHeader {
     pub length: u16,
 }
 
-#[derive(Debug, PartialEq)]
-struct SubPacketHeader {
-    id: SEOutputDataId,
-    length: u16,
+// Default limit on how deeply vectors and structs may be nested.
+pub const DEFAULT_MAX_DEPTH: usize = 32;
+
+// State shared by the decoding of a single packet, limiting the total number
+// of variants decoded so that pathological input (e.g. huge vectors) cannot
+// make a single parse spend excessive time, and the nesting of vectors and
+// structs so that it cannot overflow the stack.
+struct DecodeContext {
+    budget: Cell<usize>,
+    depth: Cell<usize>,
+    max_depth: usize,
+    // See Decoder::tolerant.
+    tolerant: bool,
+}
+
+impl DecodeContext {
+    fn new(budget: usize) -> Self {
+        DecodeContext {
+            budget: Cell::new(budget),
+            depth: Cell::new(0),
+            max_depth: DEFAULT_MAX_DEPTH,
+            tolerant: false,
+        }
+    }
+
+    fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    fn spend<'a>(&self, i: &'a [u8]) -> Result<(), nom::Err<DecodeError<&'a [u8]>>> {
+        match self.budget.get().checked_sub(1) {
+            Some(budget) => {
+                self.budget.set(budget);
+                Ok(())
+            }
+            None => Err(nom::Err::Failure(DecodeError::Nom(i, ErrorKind::TooLarge))),
+        }
+    }
+
+    // Runs parse, which decodes the content of a vector or struct at i, one
+    // nesting level deeper.
+    fn nested<'a, O, F>(&self, i: &'a [u8], parse: F) -> IResult<&'a [u8], O>
+    where
+        F: FnOnce() -> IResult<&'a [u8], O>,
+    {
+        let depth = self.depth.get();
+        if depth >= self.max_depth {
+            return Err(nom::Err::Failure(DecodeError::Nom(i, ErrorKind::TooLarge)));
+        }
+        self.depth.set(depth + 1);
+        let res = parse();
+        self.depth.set(depth);
+        res
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SubPacketHeader {
+    pub id: SEOutputDataId,
+    pub length: u16,
 }
 
 fn parse_u8(i: &[u8]) -> IResult<&[u8], u8> {
@@ -98,6 +267,30 @@ fn parse_quaternion(i: &[u8]) -> IResult<&[u8], Quaternion> {
     Ok((i, Quaternion(w, x, y, z)))
 }
 
+// Nine f64 in row-major order.
+fn parse_matrix_3x3(i: &[u8]) -> IResult<&[u8], Matrix3X3> {
+    let mut rows = [[0.0; 3]; 3];
+    let mut i = i;
+    for v in rows.iter_mut().flatten() {
+        let (rest, x) = parse_f64(i)?;
+        *v = x;
+        i = rest;
+    }
+    Ok((i, Matrix3X3(rows)))
+}
+
+// Four f64 in row-major order.
+fn parse_matrix_2x2(i: &[u8]) -> IResult<&[u8], Matrix2x2> {
+    let mut rows = [[0.0; 2]; 2];
+    let mut i = i;
+    for v in rows.iter_mut().flatten() {
+        let (rest, x) = parse_f64(i)?;
+        *v = x;
+        i = rest;
+    }
+    Ok((i, Matrix2x2(rows)))
+}
+
 fn parse_world_intersection_item(i: &[u8]) -> IResult<&[u8], WorldIntersection> {
     let (i, world_point) = parse_point_3d(i)?;
     let (i, object_point) = parse_point_3d(i)?;
@@ -112,15 +305,18 @@ fn parse_world_intersection_item(i: &[u8]) -> IResult<&[u8], WorldIntersection>
     ))
 }
 
-fn parse_world_intersection(i: &[u8]) -> IResult<&[u8], Option<WorldIntersection>> {
-    let (i, exists) = parse_u16(i)?;
+fn parse_world_intersection(input: &[u8]) -> IResult<&[u8], Option<WorldIntersection>> {
+    let (i, exists) = parse_u16(input)?;
     match exists {
         0 => Ok((i, None)),
         1 => {
             let (i, world_intersection) = parse_world_intersection_item(i)?;
             Ok((i, Some(world_intersection)))
         }
-        _ => unimplemented!(),
+        flag => Err(nom::Err::Failure(DecodeError::InvalidExistsFlag {
+            input,
+            flag,
+        })),
     }
 }
 
@@ -148,20 +344,28 @@ fn parse_user_marker_item(i: &[u8]) -> IResult<&[u8], UserMarker> {
     ))
 }
 
-fn parse_user_marker(i: &[u8]) -> IResult<&[u8], Option<UserMarker>> {
-    let (i, exists) = parse_u16(i)?;
+fn parse_user_marker(input: &[u8]) -> IResult<&[u8], Option<UserMarker>> {
+    let (i, exists) = parse_u16(input)?;
     match exists {
         0 => Ok((i, None)),
         1 => {
             let (i, user_marker) = parse_user_marker_item(i)?;
             Ok((i, Some(user_marker)))
         }
-        _ => unimplemented!(),
+        flag => Err(nom::Err::Failure(DecodeError::InvalidExistsFlag {
+            input,
+            flag,
+        })),
     }
 }
 
-fn parse_variant(i: &[u8]) -> IResult<&[u8], SEVariant> {
-    let (i, type_id): (&[u8], SETypeId) = map_res(parse_u16, |id: u16| id.try_into())(i)?;
+fn parse_variant<'a>(i: &'a [u8], ctx: &DecodeContext) -> IResult<&'a [u8], SEVariant> {
+    ctx.spend(i)?;
+    let (rest, type_id) = parse_u16(i)?;
+    let type_id: SETypeId = type_id
+        .try_into()
+        .map_err(|_| nom::Err::Failure(DecodeError::UnknownTypeId { input: i, type_id }))?;
+    let i = rest;
     match type_id {
         SETypeId::U8 => {
             let (i, v) = parse_u8(i)?;
@@ -208,13 +412,11 @@ fn parse_variant(i: &[u8]) -> IResult<&[u8], SEVariant> {
             Ok((i, SEVariant::String(v)))
         }
         SETypeId::Vector => {
-            // TODO: limit recursion?
-            let (i, v) = parse_vector(i)?;
+            let (i, v) = parse_vector(i, ctx)?;
             Ok((i, SEVariant::Vector(v)))
         }
         SETypeId::Struct => {
-            // TODO: limit recursion?
-            let (i, v) = parse_struct(i)?;
+            let (i, v) = parse_struct(i, ctx)?;
             Ok((i, SEVariant::Struct(v)))
         }
         SETypeId::WorldIntersection => {
@@ -225,14 +427,21 @@ fn parse_variant(i: &[u8]) -> IResult<&[u8], SEVariant> {
             let (i, v) = parse_world_intersections(i)?;
             Ok((i, SEVariant::WorldIntersections(v)))
         }
-        SETypeId::PacketHeader => unimplemented!(),
-        SETypeId::SubPacketHeader => unimplemented!(),
+        SETypeId::PacketHeader | SETypeId::SubPacketHeader => {
+            Err(nom::Err::Failure(DecodeError::InvalidVariantType(type_id)))
+        }
         SETypeId::F32 => {
             let (i, v) = parse_f32(i)?;
             Ok((i, SEVariant::F32(v)))
         }
-        SETypeId::Matrix3X3 => todo!(),
-        SETypeId::Matrix2x2 => todo!(),
+        SETypeId::Matrix3X3 => {
+            let (i, v) = parse_matrix_3x3(i)?;
+            Ok((i, SEVariant::Matrix3X3(v)))
+        }
+        SETypeId::Matrix2x2 => {
+            let (i, v) = parse_matrix_2x2(i)?;
+            Ok((i, SEVariant::Matrix2x2(v)))
+        }
         SETypeId::Quaternion => {
             let (i, v) = parse_quaternion(i)?;
             Ok((i, SEVariant::Quaternion(v)))
@@ -244,29 +453,29 @@ fn parse_variant(i: &[u8]) -> IResult<&[u8], SEVariant> {
     }
 }
 
-fn parse_vector_item(i: &[u8]) -> IResult<&[u8], SEVectorItem> {
-    parse_variant(i)
+fn parse_vector_item<'a>(i: &'a [u8], ctx: &DecodeContext) -> IResult<&'a [u8], SEVectorItem> {
+    parse_variant(i, ctx)
 }
 
-fn parse_vector(i: &[u8]) -> IResult<&[u8], Vec<SEVectorItem>> {
+fn parse_vector<'a>(i: &'a [u8], ctx: &DecodeContext) -> IResult<&'a [u8], Vec<SEVectorItem>> {
     let (i, length) = parse_u16(i)?;
     let length = length as usize;
-    count(parse_vector_item, length)(i)
+    ctx.nested(i, || count(|i| parse_vector_item(i, ctx), length)(i))
 }
 
-fn parse_struct_item(i: &[u8]) -> IResult<&[u8], SEStructItem> {
+fn parse_struct_item<'a>(i: &'a [u8], ctx: &DecodeContext) -> IResult<&'a [u8], SEStructItem> {
     let (i, key) = parse_string(i)?;
-    let (i, value) = parse_variant(i)?;
+    let (i, value) = parse_variant(i, ctx)?;
     Ok((i, SEStructItem(key, value)))
 }
 
-fn parse_struct(i: &[u8]) -> IResult<&[u8], Vec<SEStructItem>> {
+fn parse_struct<'a>(i: &'a [u8], ctx: &DecodeContext) -> IResult<&'a [u8], Vec<SEStructItem>> {
     let (i, length) = parse_u16(i)?;
     let length = length as usize;
-    count(parse_struct_item, length)(i)
+    ctx.nested(i, || count(|i| parse_struct_item(i, ctx), length)(i))
 }
 
-fn parse_sub_packet_header(i: &[u8]) -> IResult<&[u8], SubPacketHeader> {
+fn decode_sub_packet_header(i: &[u8]) -> IResult<&[u8], SubPacketHeader> {
     let (i, header_data) = take(4usize)(i)?;
     let (_, (id, length)) = all_consuming(tuple((
         map_res(parse_u16, SEOutputDataId::try_from),
@@ -275,9 +484,16 @@ fn parse_sub_packet_header(i: &[u8]) -> IResult<&[u8], SubPacketHeader> {
     Ok((i, SubPacketHeader { id, length }))
 }
 
+// Output data ids that this build of the parser can decode, e.g. for warning
+// about received sub-packets that can not be decoded. Currently all of them.
+pub fn supported_output_ids() -> Vec<SEOutputDataId> {
+    SEOutputDataId::ALL.to_vec()
+}
+
 fn parse_sub_packet_data(
     data_id: SEOutputDataId,
-) -> impl Fn(&[u8]) -> IResult<&[u8], SEOutputData> {
+    ctx: &DecodeContext,
+) -> impl Fn(&[u8]) -> IResult<&[u8], SEOutputData> + '_ {
     type Id = SEOutputDataId;
     type Data = SEOutputData;
     move |i: &[u8]| match data_id {
@@ -302,11 +518,11 @@ fn parse_sub_packet_data(
             Ok((i, Data::SEFrameRate(v)))
         }
         Id::SECameraPositions => {
-            let (i, v) = parse_vector(i)?;
+            let (i, v) = parse_vector(i, ctx)?;
             Ok((i, Data::SECameraPositions(v)))
         }
         Id::SECameraRotations => {
-            let (i, v) = parse_vector(i)?;
+            let (i, v) = parse_vector(i, ctx)?;
             Ok((i, Data::SECameraRotations(v)))
         }
         Id::SEUserDefinedData => {
@@ -330,7 +546,7 @@ fn parse_sub_packet_data(
             Ok((i, Data::SEUserMarker(v)))
         }
         Id::SECameraClocks => {
-            let (i, v) = parse_vector(i)?;
+            let (i, v) = parse_vector(i, ctx)?;
             Ok((i, Data::SECameraClocks(v)))
         }
         Id::SEHeadPosition => {
@@ -509,9 +725,19 @@ fn parse_sub_packet_data(
             let (i, v) = parse_u32(i)?;
             Ok((i, Data::SEBlink(v)))
         }
-        Id::SETrackingState => unimplemented!("SETrackingState"),
-        Id::SEEyeglassesStatus => unimplemented!("SEEyeglassesStatus"),
-        Id::SEReflexReductionStateDEPRECATED => unimplemented!("SEReflexReductionState"),
+        Id::SETrackingState => {
+            let (i, v) = parse_u8(i)?;
+            Ok((i, Data::SETrackingState(v)))
+        }
+        Id::SEEyeglassesStatus => {
+            let (i, v) = parse_u8(i)?;
+            Ok((i, Data::SEEyeglassesStatus(v)))
+        }
+        // Deprecated, but still present in old recordings.
+        Id::SEReflexReductionStateDEPRECATED => {
+            let (i, v) = parse_u8(i)?;
+            Ok((i, Data::SEReflexReductionStateDEPRECATED(v)))
+        }
         Id::SELeftBlinkClosingMidTime => {
             let (i, v) = parse_u64(i)?;
             Ok((i, Data::SELeftBlinkClosingMidTime(v)))
@@ -1039,43 +1265,489 @@ fn parse_sub_packet_data(
     }
 }
 
-fn parse_sub_packet(i: &[u8]) -> IResult<&[u8], SEOutputData> {
-    let (i, header) = parse_sub_packet_header(i)?;
+fn parse_raw_sub_packet(i: &[u8]) -> IResult<&[u8], (SubPacketHeader, &[u8])> {
+    let (i, header) = decode_sub_packet_header(i)?;
     let (i, data) = take(header.length)(i)?;
-    let (_, sub_packet) = all_consuming(parse_sub_packet_data(header.id))(data)?;
+    Ok((i, (header, data)))
+}
+
+fn parse_sub_packet<'a>(i: &'a [u8], ctx: &DecodeContext) -> IResult<&'a [u8], SEOutputData> {
+    let (i, (header, data)) = parse_raw_sub_packet(i)?;
+    let (_, sub_packet) = if ctx.tolerant && i.is_empty() {
+        parse_sub_packet_data(header.id, ctx)(data)?
+    } else {
+        all_consuming(parse_sub_packet_data(header.id, ctx))(data)?
+    };
     Ok((i, sub_packet))
 }
 
-pub fn parse_packet_header(i: &[u8]) -> Result<PacketHeader, ParseFailedError> {
+// Which eye's data to keep when parsing with parse_packet_data_filtered.
+// Data not related to a specific eye (e.g. head pose) is always kept.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EyeSelector {
+    // Only left eye data, dropping right eye and combined data.
+    Left,
+    // Only right eye data, dropping left eye and combined data.
+    Right,
+    Both,
+}
+
+impl EyeSelector {
+    pub fn selects(&self, id: SEOutputDataId) -> bool {
+        match (self, id.eye()) {
+            (_, None) | (EyeSelector::Both, _) => true,
+            (EyeSelector::Left, Some(eye)) => eye == Eye::Left,
+            (EyeSelector::Right, Some(eye)) => eye == Eye::Right,
+        }
+    }
+}
+
+// Checks the sub-packet at the start of the (non-empty) remaining packet
+// data for errors that can be reported more precisely than a failed parse.
+fn check_sub_packet(i: &[u8]) -> Result<(), ParseError> {
+    if i.len() < SUB_PACKET_HEADER_SIZE {
+        return Err(ParseError::TrailingBytes { remaining: i.len() });
+    }
+    if let Ok((_, header)) = decode_sub_packet_header(i) {
+        let declared = header.length as usize;
+        let minimum = header.id.type_id().min_size();
+        if declared < minimum {
+            return Err(ParseError::SubPacketTooShort {
+                id: header.id,
+                declared,
+                minimum,
+            });
+        }
+        let available = i.len() - SUB_PACKET_HEADER_SIZE;
+        if declared > available {
+            return Err(ParseError::SubPacketOverrun {
+                id: header.id,
+                declared,
+                available,
+            });
+        }
+    }
+    Ok(())
+}
+
+// Whether i, the start of the data of a packet with the given header, looks
+// like packet data: the packet is empty, or i starts with the header of a
+// known sub-packet that fits within the packet. Used to tell packet headers
+// apart from "SEPD" occurring within packet data. Requires i to hold the
+// first SUB_PACKET_HEADER_SIZE bytes of the data, if the packet has that many.
+pub(crate) fn is_plausible_packet_data(header: PacketHeader, i: &[u8]) -> bool {
+    let length = header.length as usize;
+    if length == 0 {
+        return true;
+    }
+    match decode_sub_packet_header(i) {
+        Ok((_, sub_header)) => SUB_PACKET_HEADER_SIZE + sub_header.length as usize <= length,
+        Err(_) => false,
+    }
+}
+
+// Iterator over the (id, undecoded data) of each sub-packet in a packet
+// payload. Stops after the first error.
+pub struct SubPacketIter<'a> {
+    i: &'a [u8],
+}
+
+impl<'a> Iterator for SubPacketIter<'a> {
+    type Item = Result<(SEOutputDataId, &'a [u8]), ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i.is_empty() {
+            return None;
+        }
+        if let Err(err) = check_sub_packet(self.i) {
+            self.i = &[];
+            return Some(Err(err));
+        }
+        match parse_raw_sub_packet(self.i) {
+            Ok((i, (header, data))) => {
+                self.i = i;
+                Some(Ok((header.id, data)))
+            }
+            Err(_) => {
+                self.i = &[];
+                Some(Err(ParseError::Failed))
+            }
+        }
+    }
+}
+
+pub fn sub_packets(i: &[u8]) -> SubPacketIter<'_> {
+    SubPacketIter { i }
+}
+
+pub fn parse_packet_header(i: &[u8]) -> Result<PacketHeader, ParseError> {
     let (_, (_sync_id, _type, length)) =
-        tuple((tag(b"SEPD"), tag(b"\x00\x04"), parse_u16))(i).map_err(|_| ParseFailedError {})?;
+        tuple((tag(b"SEPD"), tag(b"\x00\x04"), parse_u16))(i).map_err(|_| ParseError::Failed)?;
     Ok(PacketHeader { length })
 }
 
-pub fn parse_packet_data(
+// Length of the packet data as declared by the header of the packet at the
+// start of i.
+pub fn peek_length(i: &[u8]) -> Result<u16, ParseError> {
+    Ok(parse_packet_header(i)?.length)
+}
+
+// Length of the packet data as needed by the sub-packets following the header
+// of the packet at the start of i, i.e. the summed size of its sub-packets.
+// Stops at the first sub-packet with an unknown id or that does not fit in i,
+// so a wrong declared length can be told apart from the actual one.
+pub fn needed_length(i: &[u8]) -> Result<usize, ParseError> {
+    parse_packet_header(i)?;
+    let data = &i[PACKET_HEADER_SIZE..];
+    let mut length = 0;
+    while let Ok((_, header)) = decode_sub_packet_header(&data[length..]) {
+        let end = length + SUB_PACKET_HEADER_SIZE + header.length as usize;
+        if end > data.len() {
+            break;
+        }
+        length = end;
+    }
+    Ok(length)
+}
+
+// Rewrites the length in the header of the packet at the start of buf to the
+// needed_length of the packet, repairing packets of captures where the sender
+// wrote a wrong length.
+pub fn repair_header_length(buf: &mut [u8]) -> Result<(), ParseError> {
+    let length = u16::try_from(needed_length(buf)?).map_err(|_| ParseError::Failed)?;
+    buf[PACKET_HEADER_SIZE - 2..PACKET_HEADER_SIZE].copy_from_slice(&length.to_be_bytes());
+    Ok(())
+}
+
+pub fn parse_sub_packet_header(i: &[u8]) -> Result<SubPacketHeader, ParseError> {
+    let (_, header) = decode_sub_packet_header(i).map_err(|_| ParseError::Failed)?;
+    Ok(header)
+}
+
+pub fn parse_packet_data(header: PacketHeader, i: &[u8]) -> Result<Packet, ParseError> {
+    let mut sub_packets = Packet::new();
+    decode_packet_data_into(header, i, &mut sub_packets, &DecodeContext::unlimited())?;
+    Ok(sub_packets)
+}
+
+// Like parse_packet_data, but with the sub-packets ordered by id rather than
+// as sent. The sort is stable, so sub-packets with the same id keep their
+// relative order.
+pub fn parse_packet_data_sorted(header: PacketHeader, i: &[u8]) -> Result<Packet, ParseError> {
+    let mut sub_packets = parse_packet_data(header, i)?;
+    sub_packets.sort_by_key(|data| data.id() as u16);
+    Ok(sub_packets)
+}
+
+// Like parse_packet_data, but decodes into out (after clearing it) so that
+// its allocation can be reused between packets. The content of out is
+// unspecified if an error is returned.
+pub fn parse_packet_data_into(
     header: PacketHeader,
     i: &[u8],
-) -> Result<Vec<SEOutputData>, ParseFailedError> {
-    let mut parser = map(
-        map_parser(take(header.length), many_till(parse_sub_packet, eof)),
-        |(sub_packets, _eof)| sub_packets,
-    );
-    match parser(i) {
-        Ok((_, sub_packets)) => Ok(sub_packets),
-        _ => Err(ParseFailedError {}),
+    out: &mut Packet,
+) -> Result<(), ParseError> {
+    out.clear();
+    decode_packet_data_into(header, i, out, &DecodeContext::unlimited())
+}
+
+// Decodes the sub-packets of the packet data, appending them to out.
+fn decode_packet_data_into<P: Extend<SEOutputData>>(
+    header: PacketHeader,
+    i: &[u8],
+    out: &mut P,
+    ctx: &DecodeContext,
+) -> Result<(), ParseError> {
+    let mut data = i.get(..header.length as usize).ok_or(ParseError::Failed)?;
+    while !data.is_empty() {
+        check_sub_packet(data)?;
+        let (rest, sub_packet) = parse_sub_packet(data, ctx).map_err(|e| to_parse_error(i, e))?;
+        out.extend(iter::once(sub_packet));
+        data = rest;
+    }
+    Ok(())
+}
+
+// Like parse_packet_data, but only decodes the sub-packets of the selected
+// eye. Other sub-packets are skipped over by their length without decoding.
+pub fn parse_packet_data_filtered(
+    header: PacketHeader,
+    i: &[u8],
+    selector: EyeSelector,
+) -> Result<Packet, ParseError> {
+    let ctx = DecodeContext::unlimited();
+    let mut out = Packet::new();
+    for sub_packet in sub_packets(i.get(..header.length as usize).ok_or(ParseError::Failed)?) {
+        let (id, data) = sub_packet?;
+        if selector.selects(id) {
+            let (_, sub_packet) = all_consuming(parse_sub_packet_data(id, &ctx))(data)
+                .map_err(|e| to_parse_error(i, e))?;
+            out.push(sub_packet);
+        }
+    }
+    Ok(out)
+}
+
+// Like parse_packet_data, but also returns the length declared by the header
+// of each sub-packet, e.g. for checking that an encoder produces the same
+// lengths as the sender.
+pub fn parse_packet_data_with_lengths(
+    header: PacketHeader,
+    i: &[u8],
+) -> Result<Vec<(SEOutputData, u16)>, ParseError> {
+    let ctx = DecodeContext::unlimited();
+    let mut out = Vec::new();
+    for sub_packet in sub_packets(i.get(..header.length as usize).ok_or(ParseError::Failed)?) {
+        let (id, data) = sub_packet?;
+        let (_, sub_packet) = all_consuming(parse_sub_packet_data(id, &ctx))(data)
+            .map_err(|e| to_parse_error(i, e))?;
+        out.push((sub_packet, data.len() as u16));
+    }
+    Ok(out)
+}
+
+pub fn parse_vector_value(i: &[u8]) -> Result<Vec<SEVectorItem>, ParseError> {
+    let ctx = DecodeContext::unlimited();
+    let res = all_consuming(|i| parse_vector(i, &ctx))(i);
+    res.map(|(_, v)| v).map_err(|e| to_parse_error(i, e))
+}
+
+pub fn parse_struct_value(i: &[u8]) -> Result<Vec<SEStructItem>, ParseError> {
+    let ctx = DecodeContext::unlimited();
+    let res = all_consuming(|i| parse_struct(i, &ctx))(i);
+    res.map(|(_, v)| v).map_err(|e| to_parse_error(i, e))
+}
+
+// Parses the packet at the start of i. Any bytes following the packet (as
+// given by its declared length) are ignored.
+pub fn parse_packet(i: &[u8]) -> Result<Packet, ParseError> {
+    let (_, sub_packets) = parse_next_packet(i, &DecodeContext::unlimited())?;
+    Ok(sub_packets)
+}
+
+// Like parse_packet, but fails if decoding the packet requires decoding more
+// than budget variants (including those nested in vectors and structs). This
+// caps the time spent on a single packet regardless of its shape.
+pub fn parse_packet_with_budget(i: &[u8], budget: usize) -> Result<Packet, ParseError> {
+    Decoder::new().budget(budget).parse_packet(i)
+}
+
+// Packet decoding with non-default options, e.g.
+// Decoder::new().coordinate_system(CoordinateSystem::flip_y()).parse_packet(i)
+#[derive(Debug, Clone, Copy)]
+pub struct Decoder {
+    budget: usize,
+    max_depth: usize,
+    coordinate_system: CoordinateSystem,
+    tolerant: bool,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder {
+            budget: usize::MAX,
+            max_depth: DEFAULT_MAX_DEPTH,
+            coordinate_system: CoordinateSystem::IDENTITY,
+            tolerant: false,
+        }
     }
 }
 
-pub fn parse_packet(i: &[u8]) -> Result<Vec<SEOutputData>, ParseFailedError> {
+impl Decoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // See parse_packet_with_budget.
+    pub fn budget(mut self, budget: usize) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    // Fails on vectors and structs nested more than max_depth levels deep,
+    // instead of risking a stack overflow. Defaults to DEFAULT_MAX_DEPTH.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    // Converts all 3D points and vectors to the coordinate system.
+    pub fn coordinate_system(mut self, coordinate_system: CoordinateSystem) -> Self {
+        self.coordinate_system = coordinate_system;
+        self
+    }
+
+    // Accepts a last sub-packet whose declared length covers more bytes than
+    // its value, ignoring the excess. Some senders set the length of the last
+    // sub-packet to the number of bytes until the end of the packet, which
+    // may include padding.
+    pub fn tolerant(mut self, tolerant: bool) -> Self {
+        self.tolerant = tolerant;
+        self
+    }
+
+    fn context(&self) -> DecodeContext {
+        DecodeContext {
+            max_depth: self.max_depth,
+            tolerant: self.tolerant,
+            ..DecodeContext::new(self.budget)
+        }
+    }
+
+    pub fn parse_packet(&self, i: &[u8]) -> Result<Packet, ParseError> {
+        let (_, mut sub_packets) = parse_next_packet(i, &self.context())?;
+        self.coordinate_system.apply(&mut sub_packets);
+        Ok(sub_packets)
+    }
+
+    pub fn parse_packet_data(&self, header: PacketHeader, i: &[u8]) -> Result<Packet, ParseError> {
+        let mut sub_packets = Packet::new();
+        decode_packet_data_into(header, i, &mut sub_packets, &self.context())?;
+        self.coordinate_system.apply(&mut sub_packets);
+        Ok(sub_packets)
+    }
+}
+
+// Byte ranges of the packets in a buffer, such as a capture file, found by
+// scanning for packet headers. The packets are not decoded. Bytes not part of
+// a packet, including a trailing incomplete packet, are skipped.
+pub fn packet_offsets(buf: &[u8]) -> Vec<Range<usize>> {
+    let mut offsets = Vec::new();
+    let mut pos = 0;
+    while pos + PACKET_HEADER_SIZE <= buf.len() {
+        if let Ok(header) = parse_packet_header(&buf[pos..]) {
+            let end = pos + PACKET_HEADER_SIZE + header.length as usize;
+            if end <= buf.len() {
+                offsets.push(pos..end);
+                pos = end;
+                continue;
+            }
+        }
+        pos += 1;
+    }
+    offsets
+}
+
+// FNV-1a hash of the packet payload (the bytes following the packet header),
+// for cheaply detecting identical packets without decoding them. Bytes past
+// the length declared by the header are not part of the packet and ignored.
+pub fn packet_fingerprint(i: &[u8]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+    let end = parse_packet_header(i)
+        .map_or(i.len(), |header| {
+            PACKET_HEADER_SIZE + header.length as usize
+        })
+        .min(i.len());
+    let payload = i.get(PACKET_HEADER_SIZE..end).unwrap_or(&[]);
+    payload.iter().fold(FNV_OFFSET_BASIS, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+// Parses the packet at the start of i, returning the bytes following it.
+fn parse_next_packet<'a>(
+    i: &'a [u8],
+    ctx: &DecodeContext,
+) -> Result<(&'a [u8], Packet), ParseError> {
     let header = parse_packet_header(i)?;
-    parse_packet_data(header, &i[PACKET_HEADER_SIZE..])
+    let packet_size = PACKET_HEADER_SIZE + header.length as usize;
+    if i.len() < packet_size {
+        return Err(ParseError::Failed);
+    }
+    let mut sub_packets = Packet::new();
+    decode_packet_data_into(
+        header,
+        &i[PACKET_HEADER_SIZE..packet_size],
+        &mut sub_packets,
+        ctx,
+    )?;
+    Ok((&i[packet_size..], sub_packets))
+}
+
+// Iterator over the packets of a buffer of back-to-back packets, such as a
+// capture file. Stops after the first error.
+pub struct PacketIter<'a> {
+    i: &'a [u8],
+    skip_empty: bool,
+}
+
+impl<'a> PacketIter<'a> {
+    // Skips packets without any sub-packets, instead of yielding them as
+    // empty packets.
+    pub fn skip_empty(mut self, skip_empty: bool) -> Self {
+        self.skip_empty = skip_empty;
+        self
+    }
+}
+
+impl<'a> Iterator for PacketIter<'a> {
+    type Item = Result<Packet, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.i.is_empty() {
+            match parse_next_packet(self.i, &DecodeContext::unlimited()) {
+                Ok((i, sub_packets)) => {
+                    self.i = i;
+                    if self.skip_empty && sub_packets.is_empty() {
+                        continue;
+                    }
+                    return Some(Ok(sub_packets));
+                }
+                Err(err) => {
+                    self.i = &[];
+                    return Some(Err(err));
+                }
+            }
+        }
+        None
+    }
+}
+
+pub fn iter_packets(i: &[u8]) -> PacketIter<'_> {
+    PacketIter {
+        i,
+        skip_empty: false,
+    }
+}
+
+// Calls a progress callback with the bytes processed and the total bytes
+// (0 if unknown) whenever another PROGRESS_INTERVAL bytes are processed,
+// and once when done.
+struct Progress<F> {
+    callback: F,
+    total: u64,
+    reported: u64,
+}
+
+const PROGRESS_INTERVAL: u64 = 64 * 1024;
+
+impl<F: FnMut(u64, u64)> Progress<F> {
+    fn new(callback: F, total: u64) -> Self {
+        Progress {
+            callback,
+            total,
+            reported: 0,
+        }
+    }
+
+    fn update(&mut self, processed: u64) {
+        if processed >= self.reported + PROGRESS_INTERVAL {
+            self.reported = processed;
+            (self.callback)(processed, self.total);
+        }
+    }
+
+    fn finish(&mut self, processed: u64) {
+        self.reported = processed;
+        (self.callback)(processed, self.total);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const SUB_PACKET_HEADER_SIZE: usize = 2 + 2;
+    use crate::test_support::packet_of;
 
     const PACKET_EMPTY: &[u8] = &[
         0x53, 0x45, 0x50, 0x44, // Sync Id
@@ -1193,6 +1865,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_matrix_3x3() {
+        let matrix: &[u8] = &[
+            0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [0][0] (1.0)
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [0][1] (2.0)
+            0x40, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [0][2] (3.0)
+            0xBF, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [1][0] (-1.0)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [1][1] (0.0)
+            0x3F, 0xE0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [1][2] (0.5)
+            0x40, 0xC3, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, // [2][0] (10000.0)
+            0x40, 0x5E, 0xDD, 0x2F, 0x1A, 0x9F, 0xBE, 0x77, // [2][1] (123.456)
+            0xBF, 0xB9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A, // [2][2] (-0.1)
+        ];
+        let expected = Matrix3X3([[1.0, 2.0, 3.0], [-1.0, 0.0, 0.5], [10000.0, 123.456, -0.1]]);
+        assert_eq!(parse_matrix_3x3(matrix), Ok((&b""[..], expected)));
+
+        let variant = [&[0x00, 0x12][..], matrix].concat();
+        assert_eq!(
+            parse_variant(&variant, &DecodeContext::unlimited()),
+            Ok((&b""[..], SEVariant::Matrix3X3(expected)))
+        );
+        assert_eq!(
+            crate::serializer::serialize_variant(&SEVariant::Matrix3X3(expected)),
+            variant
+        );
+    }
+
+    #[test]
+    fn test_parse_matrix_2x2() {
+        let matrix: &[u8] = &[
+            0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [0][0] (1.0)
+            0xBF, 0xB9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A, // [0][1] (-0.1)
+            0x40, 0x5E, 0xDD, 0x2F, 0x1A, 0x9F, 0xBE, 0x77, // [1][0] (123.456)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // [1][1] (0.0)
+        ];
+        let expected = Matrix2x2([[1.0, -0.1], [123.456, 0.0]]);
+        assert_eq!(parse_matrix_2x2(matrix), Ok((&b""[..], expected)));
+
+        let variant = [&[0x00, 0x13][..], matrix].concat();
+        assert_eq!(
+            parse_variant(&variant, &DecodeContext::unlimited()),
+            Ok((&b""[..], SEVariant::Matrix2x2(expected)))
+        );
+        assert_eq!(
+            crate::serializer::serialize_variant(&SEVariant::Matrix2x2(expected)),
+            variant
+        );
+    }
+
     #[test]
     fn test_parse_world_intersection_item() {
         let world_intersection_item: &[u8] = &[
@@ -1251,6 +1972,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_invalid_exists_flag() {
+        let invalid: &[u8] = &[0x00, 0x02, 0x00];
+        assert_eq!(
+            parse_world_intersection(invalid),
+            Err(nom::Err::Failure(DecodeError::InvalidExistsFlag {
+                input: invalid,
+                flag: 2
+            }))
+        );
+        assert_eq!(
+            parse_user_marker(invalid),
+            Err(nom::Err::Failure(DecodeError::InvalidExistsFlag {
+                input: invalid,
+                flag: 2
+            }))
+        );
+
+        let packet: &[u8] = &[
+            0x53, 0x45, 0x50, 0x44, // Sync Id
+            0x00, 0x04, // Packet type
+            0x00, 0x06, // Packet length
+            0x00, 0x40, // Id (0x0040 = SEClosestWorldIntersection)
+            0x00, 0x02, // Length
+            0x00, 0x02, // Exists (invalid)
+        ];
+        assert_eq!(
+            parse_packet(packet),
+            Err(ParseError::InvalidExistsFlag { flag: 2, offset: 4 })
+        );
+    }
+
     #[test]
     fn test_parse_world_intersections() {
         let world_intersections: &[u8] = &[
@@ -1344,7 +2097,62 @@ mod tests {
             0x00, 0x00, // typeId (=SEType_u8)
             0x01, // Element (=1)
         ];
-        assert_eq!(parse_variant(variant), Ok((&b""[..], SEVariant::U8(1))));
+        assert_eq!(
+            parse_variant(variant, &DecodeContext::unlimited()),
+            Ok((&b""[..], SEVariant::U8(1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_variant_invalid_type() {
+        let vector: &[u8] = &[
+            0x00, 0x01, // numElements
+            0x00, 0x0F, // elem[0].typeId (=SEType_PacketHeader)
+            0x53, 0x45, 0x50, 0x44, 0x00, 0x04, 0x00, 0x00,
+        ];
+        assert_eq!(
+            parse_vector_value(vector),
+            Err(ParseError::InvalidVariantType(SETypeId::PacketHeader))
+        );
+        assert_eq!(
+            parse_variant(
+                &[0x00, 0x10, 0x00, 0x01, 0x00, 0x00],
+                &DecodeContext::unlimited()
+            ),
+            Err(nom::Err::Failure(DecodeError::InvalidVariantType(
+                SETypeId::SubPacketHeader
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parse_variant_unknown_type_id() {
+        let variant: &[u8] = &[
+            0xFF, 0xFF, // typeId (unknown)
+            0x01,
+        ];
+        assert_eq!(
+            parse_variant(variant, &DecodeContext::unlimited()),
+            Err(nom::Err::Failure(DecodeError::UnknownTypeId {
+                input: variant,
+                type_id: 0xFFFF
+            }))
+        );
+
+        let vector: &[u8] = &[
+            0x00, 0x02, // numElements
+            0x00, 0x00, // elem[0].typeId (=SEType_u8)
+            0x01, // elem[0] (=1)
+            0xFF, 0xFF, // elem[1].typeId (unknown)
+            0x01,
+        ];
+        assert_eq!(
+            parse_vector_value(vector),
+            Err(ParseError::UnknownTypeId {
+                type_id: 0xFFFF,
+                offset: 5
+            })
+        );
     }
 
     #[test]
@@ -1354,7 +2162,7 @@ mod tests {
             0x01, // Element (=1)
         ];
         assert_eq!(
-            parse_vector_item(vector_item),
+            parse_vector_item(vector_item, &DecodeContext::unlimited()),
             Ok((&b""[..], SEVectorItem::U8(1)))
         );
     }
@@ -1379,7 +2187,7 @@ mod tests {
             0x05, 0x39, // elem[3].elem[0] (=1337)
         ];
         assert_eq!(
-            parse_vector(vector),
+            parse_vector(vector, &DecodeContext::unlimited()),
             Ok((
                 &b""[..],
                 vec![
@@ -1395,6 +2203,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_vector_max_depth() {
+        // Vectors nested depth levels deep, the innermost holding a u8.
+        let nested = |depth: usize| {
+            // typeId (=SEType_Vector), numElements
+            let vector_header = [0x00, 0x0B, 0x00, 0x01];
+            let mut variant = vector_header.repeat(depth);
+            variant.extend_from_slice(&[0x00, 0x00, 0x01]);
+            variant
+        };
+        let ctx = DecodeContext::unlimited();
+        assert!(parse_variant(&nested(DEFAULT_MAX_DEPTH), &ctx).is_ok());
+        assert!(matches!(
+            parse_variant(&nested(DEFAULT_MAX_DEPTH + 1), &ctx),
+            Err(nom::Err::Failure(DecodeError::Nom(_, ErrorKind::TooLarge)))
+        ));
+        // Deep enough to overflow the stack without the limit.
+        assert_eq!(
+            parse_vector_value(&nested(100_000)[2..]),
+            Err(ParseError::Failed)
+        );
+
+        // SECameraPositions holding vectors nested 3 levels deep.
+        let data = &nested(3)[2..];
+        let mut packet = b"SEPD\x00\x04".to_vec();
+        packet.extend_from_slice(&(data.len() as u16 + 4).to_be_bytes());
+        packet.extend_from_slice(&[0x00, 0x06]);
+        packet.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        packet.extend_from_slice(data);
+        assert!(Decoder::new().max_depth(3).parse_packet(&packet).is_ok());
+        assert_eq!(
+            Decoder::new().max_depth(2).parse_packet(&packet),
+            Err(ParseError::Failed)
+        );
+    }
+
     #[test]
     fn test_parse_struct_item() {
         let struct_item: &[u8] = &[
@@ -1404,7 +2248,7 @@ mod tests {
             0x05, 0x39, // elem[0] (=1337)
         ];
         assert_eq!(
-            parse_struct_item(struct_item),
+            parse_struct_item(struct_item, &DecodeContext::unlimited()),
             Ok((
                 &b""[..],
                 SEStructItem(String::from("AbC"), SEVariant::U16(1337))
@@ -1422,7 +2266,7 @@ mod tests {
             0x05, 0x39, // elem[0] (=1337)
         ];
         assert_eq!(
-            parse_struct(s),
+            parse_struct(s, &DecodeContext::unlimited()),
             Ok((
                 &b""[..],
                 vec![SEStructItem(String::from("AbC"), SEVariant::U16(1337))]
@@ -1430,6 +2274,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_repair_header_length() {
+        let expected = parse_packet(PACKET_TIME_STAMP_FRAME_NUMBER).unwrap();
+        assert_eq!(peek_length(PACKET_TIME_STAMP_FRAME_NUMBER), Ok(0x14));
+        assert_eq!(needed_length(PACKET_TIME_STAMP_FRAME_NUMBER), Ok(0x14));
+
+        for &length in &[0x30, 0x0A] {
+            let mut packet = PACKET_TIME_STAMP_FRAME_NUMBER.to_vec();
+            packet[7] = length; // Packet length
+            assert_eq!(peek_length(&packet), Ok(length as u16));
+            assert!(parse_packet(&packet).is_err());
+
+            repair_header_length(&mut packet).unwrap();
+            assert_eq!(packet, PACKET_TIME_STAMP_FRAME_NUMBER);
+            assert_eq!(parse_packet(&packet), Ok(expected.clone()));
+        }
+
+        // The incomplete sub-packet is not counted.
+        let mut packet = INCOMPLETE_PACKET_FRAME_NUMBER.to_vec();
+        repair_header_length(&mut packet).unwrap();
+        assert_eq!(parse_packet(&packet), Ok(packet_of(vec![])));
+        assert_eq!(
+            repair_header_length(&mut [0x00; 8]),
+            Err(ParseError::Failed)
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_data_with_lengths() {
+        let header = parse_packet_header(PACKET_TIME_STAMP_FRAME_NUMBER).unwrap();
+        assert_eq!(
+            parse_packet_data_with_lengths(
+                header,
+                &PACKET_TIME_STAMP_FRAME_NUMBER[PACKET_HEADER_SIZE..]
+            ),
+            Ok(vec![
+                (SEOutputData::SETimeStamp(4479080464640), 0x08),
+                (SEOutputData::SEFrameNumber(17819), 0x04),
+            ])
+        );
+        let header = parse_packet_header(INCOMPLETE_PACKET_FRAME_NUMBER).unwrap();
+        assert!(parse_packet_data_with_lengths(
+            header,
+            &INCOMPLETE_PACKET_FRAME_NUMBER[PACKET_HEADER_SIZE..]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_packet_data_trailing_bytes() {
+        let mut packet = PACKET_FRAME_NUMBER.to_vec();
+        packet[7] += 1; // Packet length
+        packet.push(0x00);
+        let header = parse_packet_header(&packet).unwrap();
+        assert_eq!(
+            parse_packet_data(header, &packet[PACKET_HEADER_SIZE..]),
+            Err(ParseError::TrailingBytes { remaining: 1 })
+        );
+        assert_eq!(
+            parse_packet(&packet),
+            Err(ParseError::TrailingBytes { remaining: 1 })
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_data_sub_packet_too_short() {
+        let packet: &[u8] = &[
+            0x53, 0x45, 0x50, 0x44, // Sync Id
+            0x00, 0x04, // Packet type
+            0x00, 0x04, // Packet length
+            0x00, 0x01, // Id (0x0001 = SEFrameNumber)
+            0x00, 0x00, // Length
+        ];
+        assert_eq!(
+            parse_packet(packet),
+            Err(ParseError::SubPacketTooShort {
+                id: SEOutputDataId::SEFrameNumber,
+                declared: 0,
+                minimum: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_data_sub_packet_overrun() {
+        let packet: &[u8] = &[
+            0x53, 0x45, 0x50, 0x44, // Sync Id
+            0x00, 0x04, // Packet type
+            0x00, 0x08, // Packet length
+            0x00, 0x01, // Id (0x0001 = SEFrameNumber)
+            0x00, 0x06, // Length
+            0x00, 0x00, 0x00, 0x01, // Data
+            0x00, 0x00, // Bytes following the packet
+        ];
+        assert_eq!(
+            parse_packet(packet),
+            Err(ParseError::SubPacketOverrun {
+                id: SEOutputDataId::SEFrameNumber,
+                declared: 6,
+                available: 4,
+            })
+        );
+
+        // A truncated packet is not an overrun.
+        assert_eq!(
+            parse_packet(INCOMPLETE_PACKET_FRAME_NUMBER),
+            Err(ParseError::Failed)
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_data_filtered() {
+        let packet = crate::serializer::serialize_packet(&[
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEGazeDirection(Vect3D(0.0, 0.0, 1.0)),
+            SEOutputData::SELeftGazeDirection(Vect3D(0.1, 0.0, 1.0)),
+            SEOutputData::SERightGazeDirection(Vect3D(-0.1, 0.0, 1.0)),
+        ]);
+        let header = parse_packet_header(&packet).unwrap();
+        let data = &packet[PACKET_HEADER_SIZE..];
+        assert_eq!(
+            parse_packet_data_filtered(header, data, EyeSelector::Left),
+            Ok(packet_of(vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SELeftGazeDirection(Vect3D(0.1, 0.0, 1.0)),
+            ]))
+        );
+        assert_eq!(
+            parse_packet_data_filtered(header, data, EyeSelector::Both),
+            parse_packet_data(header, data)
+        );
+    }
+
+    #[test]
+    fn test_parse_vector_and_struct_value() {
+        let vector: &[u8] = &[
+            0x00, 0x02, // numElements
+            0x00, 0x00, // elem[0].typeId (=SEType_u8)
+            0x01, // elem[0] (=1)
+            0x00, 0x0C, // elem[1].typeId (SEType_Struct)
+            0x00, 0x01, // elem[1].numElements
+            0x00, 0x03, // elem[1].elem[0].id.length
+            0x41, 0x62, 0x43, // elem[1].elem[0].id.chars ("AbC")
+            0x00, 0x01, // elem[1].elem[0].typeId (=SEType_u16)
+            0x05, 0x39, // elem[1].elem[0] (=1337)
+        ];
+        assert_eq!(
+            parse_vector_value(vector),
+            Ok(vec![
+                SEVectorItem::U8(1),
+                SEVectorItem::Struct(vec![SEStructItem(
+                    String::from("AbC"),
+                    SEVariant::U16(1337)
+                )])
+            ])
+        );
+        assert_eq!(
+            parse_struct_value(&vector[7..]),
+            Ok(vec![SEStructItem(
+                String::from("AbC"),
+                SEVariant::U16(1337)
+            )])
+        );
+        assert_eq!(
+            parse_vector_value(&vector[..vector.len() - 1]),
+            Err(ParseError::Failed)
+        );
+    }
+
     #[test]
     fn test_parse_packet_header() {
         assert_eq!(
@@ -1457,14 +2470,14 @@ mod tests {
         assert!(parse_packet_header(invalid_type).is_err());
 
         let empty = &b""[..];
-        assert_eq!(parse_packet_header(empty), Err(ParseFailedError {}))
+        assert_eq!(parse_packet_header(empty), Err(ParseError::Failed))
     }
 
     #[test]
-    fn test_parse_sub_packet_header() {
+    fn test_decode_sub_packet_header() {
         let sub_packet = &PACKET_FRAME_NUMBER[PACKET_HEADER_SIZE..];
         assert_eq!(
-            parse_sub_packet_header(sub_packet),
+            decode_sub_packet_header(sub_packet),
             Ok((
                 &sub_packet[SUB_PACKET_HEADER_SIZE..],
                 SubPacketHeader {
@@ -1476,7 +2489,7 @@ mod tests {
 
         let sub_packet = &PACKET_TIME_STAMP_FRAME_NUMBER[PACKET_HEADER_SIZE..];
         assert_eq!(
-            parse_sub_packet_header(sub_packet),
+            decode_sub_packet_header(sub_packet),
             Ok((
                 &sub_packet[SUB_PACKET_HEADER_SIZE..],
                 SubPacketHeader {
@@ -1488,26 +2501,49 @@ mod tests {
 
         let empty = &b""[..];
         assert_eq!(
-            parse_sub_packet_header(empty),
+            decode_sub_packet_header(empty),
             Err(nom::Err::Incomplete(nom::Needed::new(
                 SUB_PACKET_HEADER_SIZE
             )))
         )
     }
 
+    #[test]
+    fn test_parse_sub_packet_header() {
+        let sub_packet = &PACKET_TIME_STAMP_FRAME_NUMBER[PACKET_HEADER_SIZE..];
+        assert_eq!(
+            parse_sub_packet_header(sub_packet),
+            Ok(SubPacketHeader {
+                id: SEOutputDataId::SETimeStamp,
+                length: 8
+            })
+        );
+
+        let unknown_id: &[u8] = &[0xFF, 0xFF, 0x00, 0x04];
+        assert_eq!(parse_sub_packet_header(unknown_id), Err(ParseError::Failed));
+        assert_eq!(
+            parse_sub_packet_header(&[0x00, 0x01]),
+            Err(ParseError::Failed)
+        );
+    }
+
     #[test]
     fn test_parse_sub_packet_data() {
         let sub_packet = &PACKET_FRAME_NUMBER[PACKET_HEADER_SIZE..];
         let sub_packet_data = &sub_packet[SUB_PACKET_HEADER_SIZE..];
         assert_eq!(
-            parse_sub_packet_data(SEOutputDataId::SEFrameNumber)(sub_packet_data),
+            parse_sub_packet_data(SEOutputDataId::SEFrameNumber, &DecodeContext::unlimited())(
+                sub_packet_data
+            ),
             Ok((&sub_packet_data[4..], SEOutputData::SEFrameNumber(17819)))
         );
 
         let sub_packet = &PACKET_TIME_STAMP_FRAME_NUMBER[PACKET_HEADER_SIZE..];
         let sub_packet_data = &sub_packet[SUB_PACKET_HEADER_SIZE..];
         assert_eq!(
-            parse_sub_packet_data(SEOutputDataId::SETimeStamp)(sub_packet_data),
+            parse_sub_packet_data(SEOutputDataId::SETimeStamp, &DecodeContext::unlimited())(
+                sub_packet_data
+            ),
             Ok((
                 &sub_packet_data[8..],
                 SEOutputData::SETimeStamp(4479080464640)
@@ -1519,13 +2555,13 @@ mod tests {
     fn test_parse_sub_packet() {
         let sub_packet = &PACKET_FRAME_NUMBER[PACKET_HEADER_SIZE..];
         assert_eq!(
-            parse_sub_packet(sub_packet),
+            parse_sub_packet(sub_packet, &DecodeContext::unlimited()),
             Ok((&b""[..], SEOutputData::SEFrameNumber(17819)))
         );
 
         let sub_packet = &PACKET_TIME_STAMP_FRAME_NUMBER[PACKET_HEADER_SIZE..];
         assert_eq!(
-            parse_sub_packet(sub_packet),
+            parse_sub_packet(sub_packet, &DecodeContext::unlimited()),
             Ok((
                 &sub_packet[SUB_PACKET_HEADER_SIZE + 8..],
                 SEOutputData::SETimeStamp(4479080464640)
@@ -1534,36 +2570,355 @@ mod tests {
 
         let sub_packet = &INCOMPLETE_PACKET_FRAME_NUMBER[PACKET_HEADER_SIZE..];
         assert_eq!(
-            parse_sub_packet(sub_packet),
+            parse_sub_packet(sub_packet, &DecodeContext::unlimited()),
             Err(nom::Err::Incomplete(nom::Needed::new(4)))
         );
     }
 
+    #[test]
+    fn test_sub_packets() {
+        let payload = &PACKET_TIME_STAMP_FRAME_NUMBER[PACKET_HEADER_SIZE..];
+        let mut iter = sub_packets(payload);
+        assert_eq!(
+            iter.next(),
+            Some(Ok((SEOutputDataId::SETimeStamp, &payload[4..12])))
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Ok((SEOutputDataId::SEFrameNumber, &payload[16..20])))
+        );
+        assert_eq!(iter.next(), None);
+
+        let payload = &INCOMPLETE_PACKET_FRAME_NUMBER[PACKET_HEADER_SIZE..];
+        let mut iter = sub_packets(payload);
+        assert_eq!(
+            iter.next(),
+            Some(Err(ParseError::SubPacketOverrun {
+                id: SEOutputDataId::SEFrameNumber,
+                declared: 4,
+                available: 0,
+            }))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_parse_packet() {
+        assert_eq!(
+            parse_packet(PACKET_FRAME_NUMBER),
+            Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
+        );
+
+        let buf = [PACKET_FRAME_NUMBER, PACKET_TIME_STAMP_FRAME_NUMBER].concat();
+        assert_eq!(
+            parse_packet(&buf),
+            Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
+        );
+
+        let truncated = &PACKET_TIME_STAMP_FRAME_NUMBER[..PACKET_TIME_STAMP_FRAME_NUMBER.len() - 1];
+        assert_eq!(parse_packet(truncated), Err(ParseError::Failed));
+    }
+
+    #[test]
+    fn test_parse_packet_unknown_output_id() {
+        // Used to panic on the unknown id.
+        let packet = b"SEPD\x00\x04\x00\x04\xFF\xFF\x00\x00";
+        assert_eq!(parse_packet(packet), Err(ParseError::Failed));
+    }
+
+    #[test]
+    fn test_parse_packet_with_budget() {
+        const NUM_ELEMENTS: u16 = 10000;
+        let mut sub_packet = vec![
+            0x00, 0x06, // Id (0x0006 = SECameraPositions)
+            0x00, 0x00, // Length (set below)
+        ];
+        sub_packet.extend_from_slice(&NUM_ELEMENTS.to_be_bytes());
+        for _ in 0..NUM_ELEMENTS {
+            sub_packet.extend_from_slice(&[0x00, 0x00, 0x01]); // SEType_u8 (=1)
+        }
+        let length = (sub_packet.len() - SUB_PACKET_HEADER_SIZE) as u16;
+        sub_packet[2..4].copy_from_slice(&length.to_be_bytes());
+        let mut packet = vec![0x53, 0x45, 0x50, 0x44, 0x00, 0x04];
+        packet.extend_from_slice(&(sub_packet.len() as u16).to_be_bytes());
+        packet.extend_from_slice(&sub_packet);
+
+        assert!(parse_packet(&packet).is_ok());
+        assert_eq!(
+            parse_packet_with_budget(&packet, 100),
+            Err(ParseError::Failed)
+        );
+        assert!(parse_packet_with_budget(&packet, NUM_ELEMENTS as usize).is_ok());
+
+        // Fields that are not variants do not count against the budget.
+        assert_eq!(
+            parse_packet_with_budget(PACKET_FRAME_NUMBER, 0),
+            Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
+        );
+    }
+
+    #[test]
+    fn test_iter_packets() {
+        let buf = [PACKET_FRAME_NUMBER, PACKET_EMPTY, PACKET_FRAME_NUMBER].concat();
+        let packets: Vec<_> = iter_packets(&buf).collect();
+        assert_eq!(
+            packets,
+            vec![
+                Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)])),
+                Ok(packet_of(vec![])),
+                Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)])),
+            ]
+        );
+
+        let packets: Vec<_> = iter_packets(&buf).skip_empty(true).collect();
+        assert_eq!(
+            packets,
+            vec![
+                Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)])),
+                Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)])),
+            ]
+        );
+
+        let buf = [PACKET_FRAME_NUMBER, INCOMPLETE_PACKET_FRAME_NUMBER].concat();
+        let packets: Vec<_> = iter_packets(&buf).collect();
+        assert_eq!(
+            packets,
+            vec![
+                Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)])),
+                Err(ParseError::Failed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_packet_offsets() {
+        let buf = [
+            PACKET_FRAME_NUMBER,
+            PACKET_TIME_STAMP_FRAME_NUMBER,
+            &[0x53, 0x45, 0x50], // Junk
+        ]
+        .concat();
+        let first_end = PACKET_FRAME_NUMBER.len();
+        assert_eq!(
+            packet_offsets(&buf),
+            vec![
+                0..first_end,
+                first_end..first_end + PACKET_TIME_STAMP_FRAME_NUMBER.len()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_packet_fingerprint() {
+        let packet = PACKET_FRAME_NUMBER.to_vec();
+        assert_eq!(
+            packet_fingerprint(&packet),
+            packet_fingerprint(PACKET_FRAME_NUMBER)
+        );
+
+        let mut changed = packet.clone();
+        *changed.last_mut().unwrap() ^= 0x01;
+        assert_ne!(packet_fingerprint(&packet), packet_fingerprint(&changed));
+
+        let trailing = [PACKET_FRAME_NUMBER, b"SEPD\x00\x04"].concat();
+        assert_eq!(
+            packet_fingerprint(&trailing),
+            packet_fingerprint(PACKET_FRAME_NUMBER)
+        );
+    }
+
     #[test]
     fn test_parse_packet_data() {
         let packet = &PACKET_FRAME_NUMBER;
         let header = parse_packet_header(packet).unwrap();
         assert_eq!(
             parse_packet_data(header, &packet[PACKET_HEADER_SIZE..]),
-            Ok(vec![SEOutputData::SEFrameNumber(17819)])
+            Ok(packet_of(vec![SEOutputData::SEFrameNumber(17819)]))
         );
 
         let packet = &PACKET_TIME_STAMP_FRAME_NUMBER;
         let header = parse_packet_header(packet).unwrap();
         assert_eq!(
             parse_packet_data(header, &packet[PACKET_HEADER_SIZE..]),
-            Ok(vec![
+            Ok(packet_of(vec![
                 SEOutputData::SETimeStamp(4479080464640),
                 SEOutputData::SEFrameNumber(17819)
-            ])
+            ]))
         );
 
         let packet = &INCOMPLETE_PACKET_FRAME_NUMBER;
         let header = parse_packet_header(packet).unwrap();
         assert_eq!(
             parse_packet_data(header, &packet[PACKET_HEADER_SIZE..]),
-            Err(ParseFailedError {})
+            Err(ParseError::Failed)
+        );
+    }
+
+    #[test]
+    fn test_decoder_coordinate_system() {
+        let packet = crate::serializer::serialize_packet(&[
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SEHeadPosition(Point3D(0.1, 0.2, 0.6)),
+        ]);
+        let decoder = Decoder::new().coordinate_system(CoordinateSystem::flip_y());
+        assert_eq!(
+            decoder.parse_packet(&packet),
+            Ok(packet_of(vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SEHeadPosition(Point3D(0.1, -0.2, 0.6)),
+            ]))
+        );
+        let header = parse_packet_header(&packet).unwrap();
+        assert_eq!(
+            decoder.parse_packet_data(header, &packet[PACKET_HEADER_SIZE..]),
+            decoder.parse_packet(&packet)
+        );
+        assert_eq!(Decoder::new().parse_packet(&packet), parse_packet(&packet));
+    }
+
+    #[test]
+    fn test_parse_packet_tracking_state() {
+        let packet: &[u8] = &[
+            0x53, 0x45, 0x50, 0x44, // Sync Id
+            0x00, 0x04, // Packet type
+            0x00, 0x05, // Packet length
+            0x00, 0xC0, // Id (0x00C0 = SETrackingState)
+            0x00, 0x01, // Length
+            0x01, // Tracking
+        ];
+        assert_eq!(
+            parse_packet(packet),
+            Ok(packet_of(vec![SEOutputData::SETrackingState(1)]))
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_reflex_reduction_state() {
+        let packet: &[u8] = &[
+            0x53, 0x45, 0x50, 0x44, // Sync Id
+            0x00, 0x04, // Packet type
+            0x00, 0x05, // Packet length
+            0x00, 0xC2, // Id (0x00C2 = SEReflexReductionStateDEPRECATED)
+            0x00, 0x01, // Length
+            0x02,
+        ];
+        assert_eq!(
+            parse_packet(packet),
+            Ok(packet_of(vec![
+                SEOutputData::SEReflexReductionStateDEPRECATED(2)
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_eyeglasses_status() {
+        // Used to panic, found by fuzzing.
+        let packet = b"SEPD\x00\x04\x00\x05\x00\xC1\x00\x01\x00";
+        assert_eq!(
+            parse_packet(packet),
+            Ok(packet_of(vec![SEOutputData::SEEyeglassesStatus(0)]))
+        );
+    }
+
+    #[test]
+    fn test_supported_output_ids() {
+        let supported = supported_output_ids();
+        assert!(supported.contains(&SEOutputDataId::SEFrameNumber));
+        assert!(supported.contains(&SEOutputDataId::SEGazeDirection));
+        assert!(supported.contains(&SEOutputDataId::SETrackingState));
+        assert!(supported.contains(&SEOutputDataId::SEEyeglassesStatus));
+        assert_eq!(supported.len(), SEOutputDataId::ALL.len());
+    }
+
+    #[test]
+    fn test_decoder_tolerant() {
+        let packet: &[u8] = &[
+            0x53, 0x45, 0x50, 0x44, // Sync Id
+            0x00, 0x04, // Packet Type
+            0x00, 0x16, // Length
+            0x00, 0x01, // Id (0x0001 = SEFrameNumber)
+            0x00, 0x04, // Length
+            0x00, 0x00, 0x00, 0x01, // Data
+            0x00, 0x03, // Id (0x0003 = SETimeStamp)
+            0x00, 0x0A, // Length (covering 2 bytes of padding)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x64, // Data
+            0x00, 0x00, // Padding
+        ];
+        assert!(parse_packet(packet).is_err());
+        assert_eq!(
+            Decoder::new().tolerant(false).parse_packet(packet),
+            parse_packet(packet)
         );
+        assert_eq!(
+            Decoder::new().tolerant(true).parse_packet(packet),
+            Ok(packet_of(vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SETimeStamp(100)
+            ]))
+        );
+
+        // Only the last sub-packet may have excess bytes.
+        let mut padded_first = packet[..8].to_vec();
+        padded_first.extend_from_slice(&[0x00, 0x01, 0x00, 0x06, 0x00, 0x00, 0x00, 0x01, 0x00]);
+        padded_first.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert!(Decoder::new()
+            .tolerant(true)
+            .parse_packet(&padded_first)
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_packet_data_sorted() {
+        let packet = crate::serializer::serialize_packet(&[
+            SEOutputData::SETimeStamp(100),
+            SEOutputData::SEFrameNumber(1),
+        ]);
+        let header = parse_packet_header(&packet).unwrap();
+        assert_eq!(
+            parse_packet_data_sorted(header, &packet[PACKET_HEADER_SIZE..]),
+            Ok(packet_of(vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SETimeStamp(100),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_parse_packet_data_into() {
+        let mut out = Packet::new();
+        for packet in &[
+            PACKET_TIME_STAMP_FRAME_NUMBER,
+            PACKET_FRAME_NUMBER,
+            PACKET_EMPTY,
+        ] {
+            let header = parse_packet_header(packet).unwrap();
+            parse_packet_data_into(header, &packet[PACKET_HEADER_SIZE..], &mut out).unwrap();
+            assert_eq!(
+                Ok(out.clone()),
+                parse_packet_data(header, &packet[PACKET_HEADER_SIZE..])
+            );
+        }
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_parse_packet_data_into_inline() {
+        let fields: Vec<_> = (0..8).map(SEOutputData::SEFrameNumber).collect();
+        let packet = crate::serializer::serialize_packet(&fields);
+        let header = parse_packet_header(&packet).unwrap();
+        let mut out = Packet::new();
+        parse_packet_data_into(header, &packet[PACKET_HEADER_SIZE..], &mut out).unwrap();
+        assert_eq!(out[..], fields[..]);
+        assert!(!out.spilled());
+    }
+
+    #[test]
+    fn test_parse_packet_data_header_reuse() {
+        let packet = &PACKET_FRAME_NUMBER;
+        let header = parse_packet_header(packet).unwrap();
+        let sub_packets = parse_packet_data(header, &packet[PACKET_HEADER_SIZE..]).unwrap();
+        assert_eq!(header.length, 8);
+        assert_eq!(sub_packets[..], [SEOutputData::SEFrameNumber(17819)]);
     }
 }
 
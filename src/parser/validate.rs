@@ -0,0 +1,201 @@
+use super::{
+    parse_packet_data_into, parse_packet_header, ParseError, Progress, PACKET_HEADER_SIZE,
+};
+use crate::{client::Packet, packet};
+use std::{
+    io::{self, Read},
+    ops::RangeInclusive,
+};
+
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, PartialEq, Default)]
+pub struct CaptureReport {
+    // Packets found, including those that failed to parse.
+    pub packets: usize,
+    // Packets that failed to parse, with the offset of their packet header.
+    pub errors: Vec<(u64, ParseError)>,
+    // Bytes not part of any packet, skipped while searching for the next
+    // packet header. Includes a trailing incomplete packet.
+    pub skipped_bytes: u64,
+    // Lowest and highest frame number of the parsed packets.
+    pub frame_numbers: Option<RangeInclusive<u32>>,
+    // Reading stopped early due to this error.
+    pub read_error: Option<io::ErrorKind>,
+}
+
+impl CaptureReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.skipped_bytes == 0 && self.read_error.is_none()
+    }
+}
+
+// Buffered reader of a capture, keeping track of the offset in it.
+struct CaptureReader<R> {
+    r: R,
+    buf: Vec<u8>,
+    pos: usize,
+    offset: u64,
+}
+
+impl<R: Read> CaptureReader<R> {
+    fn available(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    // Reads until at least n bytes are available, returning false if the
+    // end of the capture was reached first.
+    fn fill(&mut self, n: usize) -> io::Result<bool> {
+        while self.available().len() < n {
+            if self.pos > 0 {
+                self.buf.drain(..self.pos);
+                self.pos = 0;
+            }
+            let old_len = self.buf.len();
+            self.buf.resize(old_len + READ_CHUNK_SIZE, 0);
+            let res = self.r.read(&mut self.buf[old_len..]);
+            self.buf.truncate(old_len + res.as_ref().map_or(0, |n| *n));
+            match res {
+                Ok(0) => return Ok(false),
+                Ok(_) => (),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(true)
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.pos += n;
+        self.offset += n as u64;
+    }
+}
+
+// Reads and parses all packets of a capture, such as a file of back-to-back
+// packets, reporting any problems found. Bytes that are not part of a packet
+// are skipped, resuming at the next packet header. The packets themselves
+// are not kept, so captures of any size can be checked.
+pub fn validate_capture<R: Read>(r: R) -> CaptureReport {
+    validate_capture_with_progress(r, |_, _| ())
+}
+
+// Like validate_capture, periodically calling progress with the number of
+// bytes read so far. The total size of the capture is not known, so the
+// second argument is always 0.
+pub fn validate_capture_with_progress<R: Read, F: FnMut(u64, u64)>(
+    r: R,
+    progress: F,
+) -> CaptureReport {
+    let mut progress = Progress::new(progress, 0);
+    let mut reader = CaptureReader {
+        r,
+        buf: Vec::new(),
+        pos: 0,
+        offset: 0,
+    };
+    let mut report = CaptureReport::default();
+    let mut packet = Packet::new();
+    loop {
+        match reader.fill(PACKET_HEADER_SIZE) {
+            Ok(true) => (),
+            Ok(false) => break,
+            Err(e) => {
+                report.read_error = Some(e.kind());
+                return report;
+            }
+        }
+        progress.update(reader.offset);
+        let header = match parse_packet_header(reader.available()) {
+            Ok(header) => header,
+            Err(_) => {
+                reader.consume(1);
+                report.skipped_bytes += 1;
+                continue;
+            }
+        };
+        let packet_size = PACKET_HEADER_SIZE + header.length as usize;
+        match reader.fill(packet_size) {
+            Ok(true) => (),
+            Ok(false) => break,
+            Err(e) => {
+                report.read_error = Some(e.kind());
+                return report;
+            }
+        }
+
+        report.packets += 1;
+        let data = &reader.available()[PACKET_HEADER_SIZE..packet_size];
+        match parse_packet_data_into(header, data, &mut packet) {
+            Ok(()) => {
+                if let Some(frame) = packet::frame_number(&packet) {
+                    report.frame_numbers = Some(match report.frame_numbers {
+                        Some(range) => *range.start().min(&frame)..=*range.end().max(&frame),
+                        None => frame..=frame,
+                    });
+                }
+            }
+            Err(err) => report.errors.push((reader.offset, err)),
+        }
+        reader.consume(packet_size);
+    }
+    // Whatever remains is too short to be a complete packet.
+    let remaining = reader.available().len() as u64;
+    report.skipped_bytes += remaining;
+    progress.finish(reader.offset + remaining);
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{se_types::SEOutputData, serializer::serialize_packet};
+
+    #[test]
+    fn test_validate_capture() {
+        let first = serialize_packet(&[
+            SEOutputData::SEFrameNumber(7),
+            SEOutputData::SETimeStamp(100),
+        ]);
+        let second = serialize_packet(&[SEOutputData::SEFrameNumber(5)]);
+        // A packet whose declared length leaves a trailing byte.
+        let corrupt_packet = [0x53, 0x45, 0x50, 0x44, 0x00, 0x04, 0x00, 0x01, 0xFF];
+        let garbage = [0x01, 0x02, 0x53, 0x45];
+        let capture = [
+            &first[..],
+            &garbage,
+            &corrupt_packet,
+            &second,
+            &garbage[..3],
+        ]
+        .concat();
+
+        let report = validate_capture(&capture[..]);
+        assert_eq!(report.packets, 3);
+        let corrupt_offset = (first.len() + garbage.len()) as u64;
+        assert_eq!(
+            report.errors,
+            vec![(corrupt_offset, ParseError::TrailingBytes { remaining: 1 })]
+        );
+        assert_eq!(report.skipped_bytes, 7);
+        assert_eq!(report.frame_numbers, Some(5..=7));
+        assert_eq!(report.read_error, None);
+        assert!(!report.is_valid());
+
+        assert!(validate_capture(&first[..]).is_valid());
+    }
+
+    #[test]
+    fn test_validate_capture_progress() {
+        let packet = serialize_packet(&[SEOutputData::SEFrameNumber(1)]);
+        let capture = packet.repeat(20_000);
+        let mut calls = Vec::new();
+        let report = validate_capture_with_progress(&capture[..], |processed, total| {
+            calls.push((processed, total))
+        });
+        assert_eq!(report.packets, 20_000);
+        assert!(calls.len() > 2);
+        assert!(calls.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(calls.iter().all(|&(_, total)| total == 0));
+        assert_eq!(calls.last(), Some(&(capture.len() as u64, 0)));
+    }
+}
@@ -0,0 +1,414 @@
+// Protobuf messages of proto/sep_packet.proto, for transporting packets over
+// e.g. gRPC.
+use crate::{client::Packet, parser::ParseError, se_types::*};
+use std::convert::TryFrom;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SepPacket {
+    #[prost(message, repeated, tag = "1")]
+    pub fields: Vec<SepField>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SepField {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub value: Option<SepValue>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SepValue {
+    #[prost(
+        oneof = "sep_value::Kind",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 20, 21"
+    )]
+    pub kind: Option<sep_value::Kind>,
+}
+
+pub mod sep_value {
+    use super::*;
+
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Kind {
+        #[prost(uint32, tag = "1")]
+        U8(u32),
+        #[prost(uint32, tag = "2")]
+        U16(u32),
+        #[prost(uint32, tag = "3")]
+        U32(u32),
+        #[prost(sint32, tag = "4")]
+        S32(i32),
+        #[prost(uint64, tag = "5")]
+        U64(u64),
+        #[prost(double, tag = "6")]
+        F64(f64),
+        #[prost(float, tag = "7")]
+        F32(f32),
+        #[prost(message, tag = "8")]
+        Point2D(Vec2),
+        #[prost(message, tag = "9")]
+        Vect2D(Vec2),
+        #[prost(message, tag = "10")]
+        Point3D(Vec3),
+        #[prost(message, tag = "11")]
+        Vect3D(Vec3),
+        #[prost(string, tag = "12")]
+        String(String),
+        #[prost(message, tag = "13")]
+        Vector(SepVector),
+        #[prost(message, tag = "14")]
+        Struct(SepStruct),
+        #[prost(message, tag = "15")]
+        WorldIntersection(OptionalWorldIntersection),
+        #[prost(message, tag = "16")]
+        WorldIntersections(WorldIntersections),
+        #[prost(message, tag = "17")]
+        Quaternion(Quaternion),
+        #[prost(message, tag = "18")]
+        UserMarker(OptionalUserMarker),
+        #[prost(message, tag = "20")]
+        Matrix3X3(Matrix),
+        #[prost(message, tag = "21")]
+        Matrix2x2(Matrix),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Vec2 {
+    #[prost(double, tag = "1")]
+    pub x: f64,
+    #[prost(double, tag = "2")]
+    pub y: f64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Vec3 {
+    #[prost(double, tag = "1")]
+    pub x: f64,
+    #[prost(double, tag = "2")]
+    pub y: f64,
+    #[prost(double, tag = "3")]
+    pub z: f64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Quaternion {
+    #[prost(double, tag = "1")]
+    pub w: f64,
+    #[prost(double, tag = "2")]
+    pub x: f64,
+    #[prost(double, tag = "3")]
+    pub y: f64,
+    #[prost(double, tag = "4")]
+    pub z: f64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Matrix {
+    // Row-major.
+    #[prost(double, repeated, tag = "1")]
+    pub values: Vec<f64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SepVector {
+    #[prost(message, repeated, tag = "1")]
+    pub items: Vec<SepValue>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SepStructItem {
+    #[prost(string, tag = "1")]
+    pub key: String,
+    #[prost(message, optional, tag = "2")]
+    pub value: Option<SepValue>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SepStruct {
+    #[prost(message, repeated, tag = "1")]
+    pub items: Vec<SepStructItem>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WorldIntersection {
+    #[prost(message, optional, tag = "1")]
+    pub world_point: Option<Vec3>,
+    #[prost(message, optional, tag = "2")]
+    pub object_point: Option<Vec3>,
+    #[prost(string, tag = "3")]
+    pub object_name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OptionalWorldIntersection {
+    #[prost(message, optional, tag = "1")]
+    pub value: Option<WorldIntersection>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WorldIntersections {
+    #[prost(message, repeated, tag = "1")]
+    pub items: Vec<WorldIntersection>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UserMarker {
+    #[prost(sint32, tag = "1")]
+    pub error: i32,
+    #[prost(uint64, tag = "2")]
+    pub time_stamp: u64,
+    #[prost(uint64, tag = "3")]
+    pub camera_clock: u64,
+    #[prost(uint32, tag = "4")]
+    pub camera_idx: u32,
+    #[prost(uint64, tag = "5")]
+    pub data: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OptionalUserMarker {
+    #[prost(message, optional, tag = "1")]
+    pub value: Option<UserMarker>,
+}
+
+fn to_vec3(x: f64, y: f64, z: f64) -> Vec3 {
+    Vec3 { x, y, z }
+}
+
+fn to_world_intersection(v: &crate::se_types::WorldIntersection) -> WorldIntersection {
+    let Point3D(x, y, z) = v.world_point;
+    let world_point = Some(to_vec3(x, y, z));
+    let Point3D(x, y, z) = v.object_point;
+    let object_point = Some(to_vec3(x, y, z));
+    WorldIntersection {
+        world_point,
+        object_point,
+        object_name: v.object_name.clone(),
+    }
+}
+
+fn to_value(v: &SEVariant) -> SepValue {
+    use sep_value::Kind;
+    let kind = match v {
+        SEVariant::U8(v) => Kind::U8(u32::from(*v)),
+        SEVariant::U16(v) => Kind::U16(u32::from(*v)),
+        SEVariant::U32(v) => Kind::U32(*v),
+        SEVariant::S32(v) => Kind::S32(*v),
+        SEVariant::U64(v) => Kind::U64(*v),
+        SEVariant::F64(v) => Kind::F64(*v),
+        SEVariant::F32(v) => Kind::F32(*v),
+        SEVariant::Point2D(Point2D(x, y)) => Kind::Point2D(Vec2 { x: *x, y: *y }),
+        SEVariant::Vect2D(Vect2D(x, y)) => Kind::Vect2D(Vec2 { x: *x, y: *y }),
+        SEVariant::Point3D(Point3D(x, y, z)) => Kind::Point3D(to_vec3(*x, *y, *z)),
+        SEVariant::Vect3D(Vect3D(x, y, z)) => Kind::Vect3D(to_vec3(*x, *y, *z)),
+        SEVariant::String(v) => Kind::String(v.clone()),
+        SEVariant::Vector(items) => Kind::Vector(SepVector {
+            items: items.iter().map(to_value).collect(),
+        }),
+        SEVariant::Struct(items) => Kind::Struct(SepStruct {
+            items: items
+                .iter()
+                .map(|SEStructItem(key, value)| SepStructItem {
+                    key: key.clone(),
+                    value: Some(to_value(value)),
+                })
+                .collect(),
+        }),
+        SEVariant::WorldIntersection(v) => Kind::WorldIntersection(OptionalWorldIntersection {
+            value: v.as_ref().map(to_world_intersection),
+        }),
+        SEVariant::WorldIntersections(v) => Kind::WorldIntersections(WorldIntersections {
+            items: v.iter().map(to_world_intersection).collect(),
+        }),
+        SEVariant::Quaternion(crate::se_types::Quaternion(w, x, y, z)) => {
+            Kind::Quaternion(Quaternion {
+                w: *w,
+                x: *x,
+                y: *y,
+                z: *z,
+            })
+        }
+        SEVariant::UserMarker(v) => Kind::UserMarker(OptionalUserMarker {
+            value: v.map(|v| UserMarker {
+                error: v.error,
+                time_stamp: v.time_stamp,
+                camera_clock: v.camera_clock,
+                camera_idx: u32::from(v.camera_idx),
+                data: v.data,
+            }),
+        }),
+        SEVariant::Matrix3X3(crate::se_types::Matrix3X3(rows)) => Kind::Matrix3X3(Matrix {
+            values: rows.concat(),
+        }),
+        SEVariant::Matrix2x2(crate::se_types::Matrix2x2(rows)) => Kind::Matrix2x2(Matrix {
+            values: rows.concat(),
+        }),
+    };
+    SepValue { kind: Some(kind) }
+}
+
+fn from_vec3(v: &Option<Vec3>) -> Result<(f64, f64, f64), ParseError> {
+    let v = v.as_ref().ok_or(ParseError::Failed)?;
+    Ok((v.x, v.y, v.z))
+}
+
+fn from_world_intersection(
+    v: &WorldIntersection,
+) -> Result<crate::se_types::WorldIntersection, ParseError> {
+    let (x, y, z) = from_vec3(&v.world_point)?;
+    let world_point = Point3D(x, y, z);
+    let (x, y, z) = from_vec3(&v.object_point)?;
+    let object_point = Point3D(x, y, z);
+    Ok(crate::se_types::WorldIntersection {
+        world_point,
+        object_point,
+        object_name: v.object_name.clone(),
+    })
+}
+
+fn from_value(v: &SepValue) -> Result<SEVariant, ParseError> {
+    use sep_value::Kind;
+    let narrow = |_| ParseError::Failed;
+    let variant = match v.kind.as_ref().ok_or(ParseError::Failed)? {
+        Kind::U8(v) => SEVariant::U8(u8::try_from(*v).map_err(narrow)?),
+        Kind::U16(v) => SEVariant::U16(u16::try_from(*v).map_err(narrow)?),
+        Kind::U32(v) => SEVariant::U32(*v),
+        Kind::S32(v) => SEVariant::S32(*v),
+        Kind::U64(v) => SEVariant::U64(*v),
+        Kind::F64(v) => SEVariant::F64(*v),
+        Kind::F32(v) => SEVariant::F32(*v),
+        Kind::Point2D(Vec2 { x, y }) => SEVariant::Point2D(Point2D(*x, *y)),
+        Kind::Vect2D(Vec2 { x, y }) => SEVariant::Vect2D(Vect2D(*x, *y)),
+        Kind::Point3D(Vec3 { x, y, z }) => SEVariant::Point3D(Point3D(*x, *y, *z)),
+        Kind::Vect3D(Vec3 { x, y, z }) => SEVariant::Vect3D(Vect3D(*x, *y, *z)),
+        Kind::String(v) => SEVariant::String(v.clone()),
+        Kind::Vector(v) => {
+            SEVariant::Vector(v.items.iter().map(from_value).collect::<Result<_, _>>()?)
+        }
+        Kind::Struct(v) => SEVariant::Struct(
+            v.items
+                .iter()
+                .map(|item| {
+                    let value = item.value.as_ref().ok_or(ParseError::Failed)?;
+                    Ok(SEStructItem(item.key.clone(), from_value(value)?))
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        Kind::WorldIntersection(v) => {
+            SEVariant::WorldIntersection(v.value.as_ref().map(from_world_intersection).transpose()?)
+        }
+        Kind::WorldIntersections(v) => SEVariant::WorldIntersections(
+            v.items
+                .iter()
+                .map(from_world_intersection)
+                .collect::<Result<_, _>>()?,
+        ),
+        Kind::Quaternion(Quaternion { w, x, y, z }) => {
+            SEVariant::Quaternion(crate::se_types::Quaternion(*w, *x, *y, *z))
+        }
+        Kind::UserMarker(v) => SEVariant::UserMarker(match &v.value {
+            Some(v) => Some(crate::se_types::UserMarker {
+                error: v.error,
+                time_stamp: v.time_stamp,
+                camera_clock: v.camera_clock,
+                camera_idx: u8::try_from(v.camera_idx).map_err(narrow)?,
+                data: v.data,
+            }),
+            None => None,
+        }),
+        Kind::Matrix3X3(Matrix { values }) => match values[..] {
+            [a, b, c, d, e, f, g, h, i] => SEVariant::Matrix3X3(crate::se_types::Matrix3X3([
+                [a, b, c],
+                [d, e, f],
+                [g, h, i],
+            ])),
+            _ => return Err(ParseError::Failed),
+        },
+        Kind::Matrix2x2(Matrix { values }) => match values[..] {
+            [a, b, c, d] => SEVariant::Matrix2x2(crate::se_types::Matrix2x2([[a, b], [c, d]])),
+            _ => return Err(ParseError::Failed),
+        },
+    };
+    Ok(variant)
+}
+
+pub fn to_proto(packet: &[SEOutputData]) -> SepPacket {
+    let fields = packet
+        .iter()
+        .map(|data| SepField {
+            id: data.id() as u32,
+            value: Some(to_value(&SEVariant::from(data.clone()))),
+        })
+        .collect();
+    SepPacket { fields }
+}
+
+// Fails if a field has an unknown id, or a value that is missing or not of
+// the type of the output data.
+pub fn from_proto(msg: &SepPacket) -> Result<Packet, ParseError> {
+    msg.fields
+        .iter()
+        .map(|field| {
+            let id = u16::try_from(field.id)
+                .ok()
+                .and_then(|id| SEOutputDataId::try_from(id).ok())
+                .ok_or(ParseError::Failed)?;
+            let value = from_value(field.value.as_ref().ok_or(ParseError::Failed)?)?;
+            SEOutputData::from_variant(id, value).ok_or(ParseError::Failed)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn test_proto_round_trip() {
+        let packet: Packet = vec![
+            SEOutputData::SEFrameNumber(41589),
+            SEOutputData::SECameraPositions(vec![SEVariant::Struct(vec![
+                SEStructItem(
+                    String::from("position"),
+                    SEVariant::Point3D(Point3D(0.1, 0.2, 0.3)),
+                ),
+                SEStructItem(
+                    String::from("clock"),
+                    SEVariant::Struct(vec![SEStructItem(
+                        String::from("ticks"),
+                        SEVariant::U64(123),
+                    )]),
+                ),
+                SEStructItem(
+                    String::from("rotation"),
+                    SEVariant::Matrix3X3(Matrix3X3([
+                        [1.0, 0.0, 0.0],
+                        [0.0, 0.5, -0.5],
+                        [0.0, 0.5, 0.5],
+                    ])),
+                ),
+                SEStructItem(
+                    String::from("scale"),
+                    SEVariant::Matrix2x2(Matrix2x2([[2.0, 0.0], [0.0, 3.0]])),
+                ),
+            ])]),
+        ]
+        .into_iter()
+        .collect();
+
+        let msg = to_proto(&packet);
+        let decoded = SepPacket::decode(&msg.encode_to_vec()[..]).unwrap();
+        assert_eq!(decoded, msg);
+        assert_eq!(from_proto(&decoded), Ok(packet));
+    }
+
+    #[test]
+    fn test_from_proto_type_mismatch() {
+        let mut msg = to_proto(&[SEOutputData::SEFrameNumber(1)]);
+        msg.fields[0].value = Some(to_value(&SEVariant::F64(1.0)));
+        assert_eq!(from_proto(&msg), Err(ParseError::Failed));
+    }
+}
@@ -0,0 +1,146 @@
+use super::{Point3D, SEOutputData, SEStructItem, SEVariant, Vect3D};
+use std::mem;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+    NegX,
+    NegY,
+    NegZ,
+}
+
+impl Axis {
+    fn pick(&self, (x, y, z): (f64, f64, f64)) -> f64 {
+        match self {
+            Axis::X => x,
+            Axis::Y => y,
+            Axis::Z => z,
+            Axis::NegX => -x,
+            Axis::NegY => -y,
+            Axis::NegZ => -z,
+        }
+    }
+}
+
+// World coordinate convention to convert 3D points and vectors to, given as
+// which (possibly negated) Smart Eye Pro axis each of the x, y and z axes is
+// taken from. Object points of world intersections are left as is, as they
+// are relative to the object rather than the world.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CoordinateSystem {
+    pub x: Axis,
+    pub y: Axis,
+    pub z: Axis,
+}
+
+impl Default for CoordinateSystem {
+    fn default() -> Self {
+        CoordinateSystem::IDENTITY
+    }
+}
+
+impl CoordinateSystem {
+    pub const IDENTITY: CoordinateSystem = CoordinateSystem {
+        x: Axis::X,
+        y: Axis::Y,
+        z: Axis::Z,
+    };
+
+    pub fn flip_y() -> Self {
+        CoordinateSystem {
+            y: Axis::NegY,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn swap_yz() -> Self {
+        CoordinateSystem {
+            y: Axis::Z,
+            z: Axis::Y,
+            ..Self::IDENTITY
+        }
+    }
+
+    fn remap(&self, v: (f64, f64, f64)) -> (f64, f64, f64) {
+        (self.x.pick(v), self.y.pick(v), self.z.pick(v))
+    }
+
+    pub fn remap_point(&self, Point3D(x, y, z): Point3D) -> Point3D {
+        let (x, y, z) = self.remap((x, y, z));
+        Point3D(x, y, z)
+    }
+
+    pub fn remap_vect(&self, Vect3D(x, y, z): Vect3D) -> Vect3D {
+        let (x, y, z) = self.remap((x, y, z));
+        Vect3D(x, y, z)
+    }
+
+    pub fn remap_variant(&self, v: &mut SEVariant) {
+        match v {
+            SEVariant::Point3D(p) => *p = self.remap_point(*p),
+            SEVariant::Vect3D(p) => *p = self.remap_vect(*p),
+            SEVariant::Vector(items) => items.iter_mut().for_each(|v| self.remap_variant(v)),
+            SEVariant::Struct(items) => {
+                for SEStructItem(_, v) in items {
+                    self.remap_variant(v);
+                }
+            }
+            SEVariant::WorldIntersection(Some(v)) => {
+                v.world_point = self.remap_point(v.world_point)
+            }
+            SEVariant::WorldIntersections(items) => {
+                for v in items {
+                    v.world_point = self.remap_point(v.world_point);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    // Remaps all 3D points and vectors of the packet, including those nested
+    // in vectors and structs.
+    pub fn apply(&self, packet: &mut [SEOutputData]) {
+        if *self == Self::IDENTITY {
+            return;
+        }
+        for data in packet {
+            let id = data.id();
+            let placeholder = SEOutputData::SEFrameNumber(0);
+            let mut v = SEVariant::from(mem::replace(data, placeholder));
+            self.remap_variant(&mut v);
+            *data = SEOutputData::from_variant(id, v).expect("remapping preserves variant types");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_system_remap() {
+        assert_eq!(
+            CoordinateSystem::flip_y().remap_point(Point3D(1.0, 2.0, 3.0)),
+            Point3D(1.0, -2.0, 3.0)
+        );
+        assert_eq!(
+            CoordinateSystem::swap_yz().remap_vect(Vect3D(1.0, 2.0, 3.0)),
+            Vect3D(1.0, 3.0, 2.0)
+        );
+
+        let mut packet = vec![
+            SEOutputData::SEFrameNumber(1),
+            SEOutputData::SECameraPositions(vec![SEVariant::Point3D(Point3D(1.0, 2.0, 3.0))]),
+        ];
+        CoordinateSystem::flip_y().apply(&mut packet);
+        assert_eq!(
+            packet,
+            vec![
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SECameraPositions(vec![SEVariant::Point3D(Point3D(1.0, -2.0, 3.0))]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use super::SEOutputDataId;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Eye {
+    Left,
+    Right,
+    // Measured from both eyes combined.
+    Combined,
+}
+
+// SEOutputDataId::eye is generated along with the output data.
+
+// The output data of the other eye, e.g. SERightGazeDirection for
+// SELeftGazeDirection. None for output data not measured from a single eye.
+pub fn mirror_eye(id: SEOutputDataId) -> Option<SEOutputDataId> {
+    let (from, to) = match id.eye()? {
+        Eye::Left => ("Left", "Right"),
+        Eye::Right => ("Right", "Left"),
+        Eye::Combined => return None,
+    };
+    SEOutputDataId::from_name(&id.name().replacen(from, to, 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eye() {
+        type Id = SEOutputDataId;
+        assert_eq!(Id::SELeftGazeDirection.eye(), Some(Eye::Left));
+        assert_eq!(Id::SEFilteredRightPupilDiameter.eye(), Some(Eye::Right));
+        assert_eq!(Id::SEGazeDirection.eye(), Some(Eye::Combined));
+        assert_eq!(Id::SEEyelidOpening.eye(), Some(Eye::Combined));
+        assert_eq!(Id::SEBlink.eye(), Some(Eye::Combined));
+        assert_eq!(Id::SEFixation.eye(), Some(Eye::Combined));
+        assert_eq!(Id::SESaccade.eye(), Some(Eye::Combined));
+        assert_eq!(Id::SEHeadLeftEarDirection.eye(), None);
+        assert_eq!(Id::SEEyeglassesStatus.eye(), None);
+        assert_eq!(Id::SEExpressionEyeWiden.eye(), None);
+        assert_eq!(Id::SEFrameNumber.eye(), None);
+    }
+
+    #[test]
+    fn test_mirror_eye() {
+        type Id = SEOutputDataId;
+        assert_eq!(
+            mirror_eye(Id::SELeftGazeDirection),
+            Some(Id::SERightGazeDirection)
+        );
+        assert_eq!(
+            mirror_eye(Id::SEFilteredEstimatedLeftClosestWorldIntersection),
+            Some(Id::SEFilteredEstimatedRightClosestWorldIntersection)
+        );
+        assert_eq!(
+            mirror_eye(Id::SERightPupilDiameter),
+            Some(Id::SELeftPupilDiameter)
+        );
+        assert_eq!(
+            mirror_eye(Id::SERightEyelidState),
+            Some(Id::SELeftEyelidState)
+        );
+        assert_eq!(mirror_eye(Id::SEGazeDirection), None);
+        assert_eq!(mirror_eye(Id::SEHeadLeftEarDirection), None);
+        assert_eq!(mirror_eye(Id::SEFrameNumber), None);
+    }
+}
@@ -1,5 +1,11 @@
+mod coordinates;
+mod eye;
 mod output_data;
 mod types;
+mod units;
 
+pub use coordinates::*;
+pub use eye::*;
 pub use output_data::*;
 pub use types::*;
+pub use units::*;
@@ -1,8 +1,9 @@
 // NOTE: this file is generated by generate_output_data.py
 
-use super::types::*;
+use super::{eye::Eye, types::*};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SEOutputData {
     SEFrameNumber(SETypeU32),
     SEEstimatedDelay(SETypeU32),
@@ -197,7 +198,7 @@ pub enum SEOutputData {
     SEExpressionQ(SETypeF64),
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 #[repr(u16)]
 pub enum SEOutputDataId {
     SEFrameNumber = 0x01,
@@ -904,7 +905,2268 @@ impl std::convert::TryFrom<u16> for SEOutputDataId {
             }
             x if x == SEOutputDataId::SEExpressionQ as u16 => Ok(SEOutputDataId::SEExpressionQ),
 
-            _ => unimplemented!("{:#x}", value),
+            _ => Err(()),
+        }
+    }
+}
+
+impl SEOutputDataId {
+    // All output data ids, in the order of their definition.
+    pub const ALL: &'static [SEOutputDataId] = &[
+        SEOutputDataId::SEFrameNumber,
+        SEOutputDataId::SEEstimatedDelay,
+        SEOutputDataId::SETimeStamp,
+        SEOutputDataId::SEUserTimeStamp,
+        SEOutputDataId::SEFrameRate,
+        SEOutputDataId::SECameraPositions,
+        SEOutputDataId::SECameraRotations,
+        SEOutputDataId::SEUserDefinedData,
+        SEOutputDataId::SERealTimeClock,
+        SEOutputDataId::SEHeadPosition,
+        SEOutputDataId::SEHeadPositionQ,
+        SEOutputDataId::SEHeadRotationRodrigues,
+        SEOutputDataId::SEHeadRotationQuaternion,
+        SEOutputDataId::SEHeadLeftEarDirection,
+        SEOutputDataId::SEHeadUpDirection,
+        SEOutputDataId::SEHeadNoseDirection,
+        SEOutputDataId::SEHeadHeading,
+        SEOutputDataId::SEHeadPitch,
+        SEOutputDataId::SEHeadRoll,
+        SEOutputDataId::SEHeadRotationQ,
+        SEOutputDataId::SEGazeOrigin,
+        SEOutputDataId::SELeftGazeOrigin,
+        SEOutputDataId::SERightGazeOrigin,
+        SEOutputDataId::SEEyePosition,
+        SEOutputDataId::SEGazeDirection,
+        SEOutputDataId::SEGazeDirectionQ,
+        SEOutputDataId::SELeftEyePosition,
+        SEOutputDataId::SELeftGazeDirection,
+        SEOutputDataId::SELeftGazeDirectionQ,
+        SEOutputDataId::SERightEyePosition,
+        SEOutputDataId::SERightGazeDirection,
+        SEOutputDataId::SERightGazeDirectionQ,
+        SEOutputDataId::SEGazeHeading,
+        SEOutputDataId::SEGazePitch,
+        SEOutputDataId::SELeftGazeHeading,
+        SEOutputDataId::SELeftGazePitch,
+        SEOutputDataId::SERightGazeHeading,
+        SEOutputDataId::SERightGazePitch,
+        SEOutputDataId::SEFilteredGazeDirection,
+        SEOutputDataId::SEFilteredGazeDirectionQ,
+        SEOutputDataId::SEFilteredLeftGazeDirection,
+        SEOutputDataId::SEFilteredLeftGazeDirectionQ,
+        SEOutputDataId::SEFilteredRightGazeDirection,
+        SEOutputDataId::SEFilteredRightGazeDirectionQ,
+        SEOutputDataId::SEFilteredGazeHeading,
+        SEOutputDataId::SEFilteredGazePitch,
+        SEOutputDataId::SEFilteredLeftGazeHeading,
+        SEOutputDataId::SEFilteredLeftGazePitch,
+        SEOutputDataId::SEFilteredRightGazeHeading,
+        SEOutputDataId::SEFilteredRightGazePitch,
+        SEOutputDataId::SESaccade,
+        SEOutputDataId::SEFixation,
+        SEOutputDataId::SEBlink,
+        SEOutputDataId::SEClosestWorldIntersection,
+        SEOutputDataId::SEFilteredClosestWorldIntersection,
+        SEOutputDataId::SEAllWorldIntersections,
+        SEOutputDataId::SEFilteredAllWorldIntersections,
+        SEOutputDataId::SEZoneId,
+        SEOutputDataId::SEEstimatedClosestWorldIntersection,
+        SEOutputDataId::SEEstimatedAllWorldIntersections,
+        SEOutputDataId::SEHeadClosestWorldIntersection,
+        SEOutputDataId::SEHeadAllWorldIntersections,
+        SEOutputDataId::SEEyelidOpening,
+        SEOutputDataId::SEEyelidOpeningQ,
+        SEOutputDataId::SELeftEyelidOpening,
+        SEOutputDataId::SELeftEyelidOpeningQ,
+        SEOutputDataId::SERightEyelidOpening,
+        SEOutputDataId::SERightEyelidOpeningQ,
+        SEOutputDataId::SEKeyboardState,
+        SEOutputDataId::SELeftLowerEyelidExtremePoint,
+        SEOutputDataId::SELeftUpperEyelidExtremePoint,
+        SEOutputDataId::SERightLowerEyelidExtremePoint,
+        SEOutputDataId::SERightUpperEyelidExtremePoint,
+        SEOutputDataId::SEPupilDiameter,
+        SEOutputDataId::SEPupilDiameterQ,
+        SEOutputDataId::SELeftPupilDiameter,
+        SEOutputDataId::SELeftPupilDiameterQ,
+        SEOutputDataId::SERightPupilDiameter,
+        SEOutputDataId::SERightPupilDiameterQ,
+        SEOutputDataId::SEFilteredPupilDiameter,
+        SEOutputDataId::SEFilteredPupilDiameterQ,
+        SEOutputDataId::SEFilteredLeftPupilDiameter,
+        SEOutputDataId::SEFilteredLeftPupilDiameterQ,
+        SEOutputDataId::SEFilteredRightPupilDiameter,
+        SEOutputDataId::SEFilteredRightPupilDiameterQ,
+        SEOutputDataId::SEGPSPosition,
+        SEOutputDataId::SEGPSGroundSpeed,
+        SEOutputDataId::SEGPSCourse,
+        SEOutputDataId::SEGPSTime,
+        SEOutputDataId::SEEstimatedGazeOrigin,
+        SEOutputDataId::SEEstimatedLeftGazeOrigin,
+        SEOutputDataId::SEEstimatedRightGazeOrigin,
+        SEOutputDataId::SEEstimatedEyePosition,
+        SEOutputDataId::SEEstimatedGazeDirection,
+        SEOutputDataId::SEEstimatedGazeDirectionQ,
+        SEOutputDataId::SEEstimatedGazeHeading,
+        SEOutputDataId::SEEstimatedGazePitch,
+        SEOutputDataId::SEEstimatedLeftEyePosition,
+        SEOutputDataId::SEEstimatedLeftGazeDirection,
+        SEOutputDataId::SEEstimatedLeftGazeDirectionQ,
+        SEOutputDataId::SEEstimatedLeftGazeHeading,
+        SEOutputDataId::SEEstimatedLeftGazePitch,
+        SEOutputDataId::SEEstimatedRightEyePosition,
+        SEOutputDataId::SEEstimatedRightGazeDirection,
+        SEOutputDataId::SEEstimatedRightGazeDirectionQ,
+        SEOutputDataId::SEEstimatedRightGazeHeading,
+        SEOutputDataId::SEEstimatedRightGazePitch,
+        SEOutputDataId::SEFilteredEstimatedGazeDirection,
+        SEOutputDataId::SEFilteredEstimatedGazeDirectionQ,
+        SEOutputDataId::SEFilteredEstimatedGazeHeading,
+        SEOutputDataId::SEFilteredEstimatedGazePitch,
+        SEOutputDataId::SEFilteredEstimatedLeftGazeDirection,
+        SEOutputDataId::SEFilteredEstimatedLeftGazeDirectionQ,
+        SEOutputDataId::SEFilteredEstimatedLeftGazeHeading,
+        SEOutputDataId::SEFilteredEstimatedLeftGazePitch,
+        SEOutputDataId::SEFilteredEstimatedRightGazeDirection,
+        SEOutputDataId::SEFilteredEstimatedRightGazeDirectionQ,
+        SEOutputDataId::SEFilteredEstimatedRightGazeHeading,
+        SEOutputDataId::SEFilteredEstimatedRightGazePitch,
+        SEOutputDataId::SEASCIIKeyboardState,
+        SEOutputDataId::SECalibrationGazeIntersection,
+        SEOutputDataId::SETaggedGazeIntersection,
+        SEOutputDataId::SELeftClosestWorldIntersection,
+        SEOutputDataId::SELeftAllWorldIntersections,
+        SEOutputDataId::SERightClosestWorldIntersection,
+        SEOutputDataId::SERightAllWorldIntersections,
+        SEOutputDataId::SEFilteredLeftClosestWorldIntersection,
+        SEOutputDataId::SEFilteredLeftAllWorldIntersections,
+        SEOutputDataId::SEFilteredRightClosestWorldIntersection,
+        SEOutputDataId::SEFilteredRightAllWorldIntersections,
+        SEOutputDataId::SEEstimatedLeftClosestWorldIntersection,
+        SEOutputDataId::SEEstimatedLeftAllWorldIntersections,
+        SEOutputDataId::SEEstimatedRightClosestWorldIntersection,
+        SEOutputDataId::SEEstimatedRightAllWorldIntersections,
+        SEOutputDataId::SEFilteredEstimatedClosestWorldIntersection,
+        SEOutputDataId::SEFilteredEstimatedAllWorldIntersections,
+        SEOutputDataId::SEFilteredEstimatedLeftClosestWorldIntersection,
+        SEOutputDataId::SEFilteredEstimatedLeftAllWorldIntersections,
+        SEOutputDataId::SEFilteredEstimatedRightClosestWorldIntersection,
+        SEOutputDataId::SEFilteredEstimatedRightAllWorldIntersections,
+        SEOutputDataId::SETrackingState,
+        SEOutputDataId::SEEyeglassesStatus,
+        SEOutputDataId::SEReflexReductionStateDEPRECATED,
+        SEOutputDataId::SELeftBlinkClosingMidTime,
+        SEOutputDataId::SELeftBlinkOpeningMidTime,
+        SEOutputDataId::SELeftBlinkClosingAmplitude,
+        SEOutputDataId::SELeftBlinkOpeningAmplitude,
+        SEOutputDataId::SELeftBlinkClosingSpeed,
+        SEOutputDataId::SELeftBlinkOpeningSpeed,
+        SEOutputDataId::SERightBlinkClosingMidTime,
+        SEOutputDataId::SERightBlinkOpeningMidTime,
+        SEOutputDataId::SERightBlinkClosingAmplitude,
+        SEOutputDataId::SERightBlinkOpeningAmplitude,
+        SEOutputDataId::SERightBlinkClosingSpeed,
+        SEOutputDataId::SERightBlinkOpeningSpeed,
+        SEOutputDataId::SELeftEyelidState,
+        SEOutputDataId::SERightEyelidState,
+        SEOutputDataId::SEUserMarker,
+        SEOutputDataId::SECameraClocks,
+        SEOutputDataId::SEEmotionJoy,
+        SEOutputDataId::SEEmotionFear,
+        SEOutputDataId::SEEmotionDisgust,
+        SEOutputDataId::SEEmotionSadness,
+        SEOutputDataId::SEEmotionSurprise,
+        SEOutputDataId::SEEmotionValence,
+        SEOutputDataId::SEEmotionEngagement,
+        SEOutputDataId::SEEmotionSentimentality,
+        SEOutputDataId::SEEmotionConfusion,
+        SEOutputDataId::SEEmotionNeutral,
+        SEOutputDataId::SEEmotionQ,
+        SEOutputDataId::SEExpressionSmile,
+        SEOutputDataId::SEExpressionInnerBrowRaise,
+        SEOutputDataId::SEExpressionBrowRaise,
+        SEOutputDataId::SEExpressionBrowFurrow,
+        SEOutputDataId::SEExpressionNoseWrinkle,
+        SEOutputDataId::SEExpressionUpperLipRaise,
+        SEOutputDataId::SEExpressionLipCornerDepressor,
+        SEOutputDataId::SEExpressionChinRaise,
+        SEOutputDataId::SEExpressionLipPucker,
+        SEOutputDataId::SEExpressionLipPress,
+        SEOutputDataId::SEExpressionLipSuck,
+        SEOutputDataId::SEExpressionMouthOpen,
+        SEOutputDataId::SEExpressionSmirk,
+        SEOutputDataId::SEExpressionAttention,
+        SEOutputDataId::SEExpressionEyeWiden,
+        SEOutputDataId::SEExpressionCheekRaise,
+        SEOutputDataId::SEExpressionLidTighten,
+        SEOutputDataId::SEExpressionDimpler,
+        SEOutputDataId::SEExpressionLipStretch,
+        SEOutputDataId::SEExpressionJawDrop,
+        SEOutputDataId::SEExpressionQ,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SEOutputDataId::SEFrameNumber => "SEFrameNumber",
+            SEOutputDataId::SEEstimatedDelay => "SEEstimatedDelay",
+            SEOutputDataId::SETimeStamp => "SETimeStamp",
+            SEOutputDataId::SEUserTimeStamp => "SEUserTimeStamp",
+            SEOutputDataId::SEFrameRate => "SEFrameRate",
+            SEOutputDataId::SECameraPositions => "SECameraPositions",
+            SEOutputDataId::SECameraRotations => "SECameraRotations",
+            SEOutputDataId::SEUserDefinedData => "SEUserDefinedData",
+            SEOutputDataId::SERealTimeClock => "SERealTimeClock",
+            SEOutputDataId::SEHeadPosition => "SEHeadPosition",
+            SEOutputDataId::SEHeadPositionQ => "SEHeadPositionQ",
+            SEOutputDataId::SEHeadRotationRodrigues => "SEHeadRotationRodrigues",
+            SEOutputDataId::SEHeadRotationQuaternion => "SEHeadRotationQuaternion",
+            SEOutputDataId::SEHeadLeftEarDirection => "SEHeadLeftEarDirection",
+            SEOutputDataId::SEHeadUpDirection => "SEHeadUpDirection",
+            SEOutputDataId::SEHeadNoseDirection => "SEHeadNoseDirection",
+            SEOutputDataId::SEHeadHeading => "SEHeadHeading",
+            SEOutputDataId::SEHeadPitch => "SEHeadPitch",
+            SEOutputDataId::SEHeadRoll => "SEHeadRoll",
+            SEOutputDataId::SEHeadRotationQ => "SEHeadRotationQ",
+            SEOutputDataId::SEGazeOrigin => "SEGazeOrigin",
+            SEOutputDataId::SELeftGazeOrigin => "SELeftGazeOrigin",
+            SEOutputDataId::SERightGazeOrigin => "SERightGazeOrigin",
+            SEOutputDataId::SEEyePosition => "SEEyePosition",
+            SEOutputDataId::SEGazeDirection => "SEGazeDirection",
+            SEOutputDataId::SEGazeDirectionQ => "SEGazeDirectionQ",
+            SEOutputDataId::SELeftEyePosition => "SELeftEyePosition",
+            SEOutputDataId::SELeftGazeDirection => "SELeftGazeDirection",
+            SEOutputDataId::SELeftGazeDirectionQ => "SELeftGazeDirectionQ",
+            SEOutputDataId::SERightEyePosition => "SERightEyePosition",
+            SEOutputDataId::SERightGazeDirection => "SERightGazeDirection",
+            SEOutputDataId::SERightGazeDirectionQ => "SERightGazeDirectionQ",
+            SEOutputDataId::SEGazeHeading => "SEGazeHeading",
+            SEOutputDataId::SEGazePitch => "SEGazePitch",
+            SEOutputDataId::SELeftGazeHeading => "SELeftGazeHeading",
+            SEOutputDataId::SELeftGazePitch => "SELeftGazePitch",
+            SEOutputDataId::SERightGazeHeading => "SERightGazeHeading",
+            SEOutputDataId::SERightGazePitch => "SERightGazePitch",
+            SEOutputDataId::SEFilteredGazeDirection => "SEFilteredGazeDirection",
+            SEOutputDataId::SEFilteredGazeDirectionQ => "SEFilteredGazeDirectionQ",
+            SEOutputDataId::SEFilteredLeftGazeDirection => "SEFilteredLeftGazeDirection",
+            SEOutputDataId::SEFilteredLeftGazeDirectionQ => "SEFilteredLeftGazeDirectionQ",
+            SEOutputDataId::SEFilteredRightGazeDirection => "SEFilteredRightGazeDirection",
+            SEOutputDataId::SEFilteredRightGazeDirectionQ => "SEFilteredRightGazeDirectionQ",
+            SEOutputDataId::SEFilteredGazeHeading => "SEFilteredGazeHeading",
+            SEOutputDataId::SEFilteredGazePitch => "SEFilteredGazePitch",
+            SEOutputDataId::SEFilteredLeftGazeHeading => "SEFilteredLeftGazeHeading",
+            SEOutputDataId::SEFilteredLeftGazePitch => "SEFilteredLeftGazePitch",
+            SEOutputDataId::SEFilteredRightGazeHeading => "SEFilteredRightGazeHeading",
+            SEOutputDataId::SEFilteredRightGazePitch => "SEFilteredRightGazePitch",
+            SEOutputDataId::SESaccade => "SESaccade",
+            SEOutputDataId::SEFixation => "SEFixation",
+            SEOutputDataId::SEBlink => "SEBlink",
+            SEOutputDataId::SEClosestWorldIntersection => "SEClosestWorldIntersection",
+            SEOutputDataId::SEFilteredClosestWorldIntersection => {
+                "SEFilteredClosestWorldIntersection"
+            }
+            SEOutputDataId::SEAllWorldIntersections => "SEAllWorldIntersections",
+            SEOutputDataId::SEFilteredAllWorldIntersections => "SEFilteredAllWorldIntersections",
+            SEOutputDataId::SEZoneId => "SEZoneId",
+            SEOutputDataId::SEEstimatedClosestWorldIntersection => {
+                "SEEstimatedClosestWorldIntersection"
+            }
+            SEOutputDataId::SEEstimatedAllWorldIntersections => "SEEstimatedAllWorldIntersections",
+            SEOutputDataId::SEHeadClosestWorldIntersection => "SEHeadClosestWorldIntersection",
+            SEOutputDataId::SEHeadAllWorldIntersections => "SEHeadAllWorldIntersections",
+            SEOutputDataId::SEEyelidOpening => "SEEyelidOpening",
+            SEOutputDataId::SEEyelidOpeningQ => "SEEyelidOpeningQ",
+            SEOutputDataId::SELeftEyelidOpening => "SELeftEyelidOpening",
+            SEOutputDataId::SELeftEyelidOpeningQ => "SELeftEyelidOpeningQ",
+            SEOutputDataId::SERightEyelidOpening => "SERightEyelidOpening",
+            SEOutputDataId::SERightEyelidOpeningQ => "SERightEyelidOpeningQ",
+            SEOutputDataId::SEKeyboardState => "SEKeyboardState",
+            SEOutputDataId::SELeftLowerEyelidExtremePoint => "SELeftLowerEyelidExtremePoint",
+            SEOutputDataId::SELeftUpperEyelidExtremePoint => "SELeftUpperEyelidExtremePoint",
+            SEOutputDataId::SERightLowerEyelidExtremePoint => "SERightLowerEyelidExtremePoint",
+            SEOutputDataId::SERightUpperEyelidExtremePoint => "SERightUpperEyelidExtremePoint",
+            SEOutputDataId::SEPupilDiameter => "SEPupilDiameter",
+            SEOutputDataId::SEPupilDiameterQ => "SEPupilDiameterQ",
+            SEOutputDataId::SELeftPupilDiameter => "SELeftPupilDiameter",
+            SEOutputDataId::SELeftPupilDiameterQ => "SELeftPupilDiameterQ",
+            SEOutputDataId::SERightPupilDiameter => "SERightPupilDiameter",
+            SEOutputDataId::SERightPupilDiameterQ => "SERightPupilDiameterQ",
+            SEOutputDataId::SEFilteredPupilDiameter => "SEFilteredPupilDiameter",
+            SEOutputDataId::SEFilteredPupilDiameterQ => "SEFilteredPupilDiameterQ",
+            SEOutputDataId::SEFilteredLeftPupilDiameter => "SEFilteredLeftPupilDiameter",
+            SEOutputDataId::SEFilteredLeftPupilDiameterQ => "SEFilteredLeftPupilDiameterQ",
+            SEOutputDataId::SEFilteredRightPupilDiameter => "SEFilteredRightPupilDiameter",
+            SEOutputDataId::SEFilteredRightPupilDiameterQ => "SEFilteredRightPupilDiameterQ",
+            SEOutputDataId::SEGPSPosition => "SEGPSPosition",
+            SEOutputDataId::SEGPSGroundSpeed => "SEGPSGroundSpeed",
+            SEOutputDataId::SEGPSCourse => "SEGPSCourse",
+            SEOutputDataId::SEGPSTime => "SEGPSTime",
+            SEOutputDataId::SEEstimatedGazeOrigin => "SEEstimatedGazeOrigin",
+            SEOutputDataId::SEEstimatedLeftGazeOrigin => "SEEstimatedLeftGazeOrigin",
+            SEOutputDataId::SEEstimatedRightGazeOrigin => "SEEstimatedRightGazeOrigin",
+            SEOutputDataId::SEEstimatedEyePosition => "SEEstimatedEyePosition",
+            SEOutputDataId::SEEstimatedGazeDirection => "SEEstimatedGazeDirection",
+            SEOutputDataId::SEEstimatedGazeDirectionQ => "SEEstimatedGazeDirectionQ",
+            SEOutputDataId::SEEstimatedGazeHeading => "SEEstimatedGazeHeading",
+            SEOutputDataId::SEEstimatedGazePitch => "SEEstimatedGazePitch",
+            SEOutputDataId::SEEstimatedLeftEyePosition => "SEEstimatedLeftEyePosition",
+            SEOutputDataId::SEEstimatedLeftGazeDirection => "SEEstimatedLeftGazeDirection",
+            SEOutputDataId::SEEstimatedLeftGazeDirectionQ => "SEEstimatedLeftGazeDirectionQ",
+            SEOutputDataId::SEEstimatedLeftGazeHeading => "SEEstimatedLeftGazeHeading",
+            SEOutputDataId::SEEstimatedLeftGazePitch => "SEEstimatedLeftGazePitch",
+            SEOutputDataId::SEEstimatedRightEyePosition => "SEEstimatedRightEyePosition",
+            SEOutputDataId::SEEstimatedRightGazeDirection => "SEEstimatedRightGazeDirection",
+            SEOutputDataId::SEEstimatedRightGazeDirectionQ => "SEEstimatedRightGazeDirectionQ",
+            SEOutputDataId::SEEstimatedRightGazeHeading => "SEEstimatedRightGazeHeading",
+            SEOutputDataId::SEEstimatedRightGazePitch => "SEEstimatedRightGazePitch",
+            SEOutputDataId::SEFilteredEstimatedGazeDirection => "SEFilteredEstimatedGazeDirection",
+            SEOutputDataId::SEFilteredEstimatedGazeDirectionQ => {
+                "SEFilteredEstimatedGazeDirectionQ"
+            }
+            SEOutputDataId::SEFilteredEstimatedGazeHeading => "SEFilteredEstimatedGazeHeading",
+            SEOutputDataId::SEFilteredEstimatedGazePitch => "SEFilteredEstimatedGazePitch",
+            SEOutputDataId::SEFilteredEstimatedLeftGazeDirection => {
+                "SEFilteredEstimatedLeftGazeDirection"
+            }
+            SEOutputDataId::SEFilteredEstimatedLeftGazeDirectionQ => {
+                "SEFilteredEstimatedLeftGazeDirectionQ"
+            }
+            SEOutputDataId::SEFilteredEstimatedLeftGazeHeading => {
+                "SEFilteredEstimatedLeftGazeHeading"
+            }
+            SEOutputDataId::SEFilteredEstimatedLeftGazePitch => "SEFilteredEstimatedLeftGazePitch",
+            SEOutputDataId::SEFilteredEstimatedRightGazeDirection => {
+                "SEFilteredEstimatedRightGazeDirection"
+            }
+            SEOutputDataId::SEFilteredEstimatedRightGazeDirectionQ => {
+                "SEFilteredEstimatedRightGazeDirectionQ"
+            }
+            SEOutputDataId::SEFilteredEstimatedRightGazeHeading => {
+                "SEFilteredEstimatedRightGazeHeading"
+            }
+            SEOutputDataId::SEFilteredEstimatedRightGazePitch => {
+                "SEFilteredEstimatedRightGazePitch"
+            }
+            SEOutputDataId::SEASCIIKeyboardState => "SEASCIIKeyboardState",
+            SEOutputDataId::SECalibrationGazeIntersection => "SECalibrationGazeIntersection",
+            SEOutputDataId::SETaggedGazeIntersection => "SETaggedGazeIntersection",
+            SEOutputDataId::SELeftClosestWorldIntersection => "SELeftClosestWorldIntersection",
+            SEOutputDataId::SELeftAllWorldIntersections => "SELeftAllWorldIntersections",
+            SEOutputDataId::SERightClosestWorldIntersection => "SERightClosestWorldIntersection",
+            SEOutputDataId::SERightAllWorldIntersections => "SERightAllWorldIntersections",
+            SEOutputDataId::SEFilteredLeftClosestWorldIntersection => {
+                "SEFilteredLeftClosestWorldIntersection"
+            }
+            SEOutputDataId::SEFilteredLeftAllWorldIntersections => {
+                "SEFilteredLeftAllWorldIntersections"
+            }
+            SEOutputDataId::SEFilteredRightClosestWorldIntersection => {
+                "SEFilteredRightClosestWorldIntersection"
+            }
+            SEOutputDataId::SEFilteredRightAllWorldIntersections => {
+                "SEFilteredRightAllWorldIntersections"
+            }
+            SEOutputDataId::SEEstimatedLeftClosestWorldIntersection => {
+                "SEEstimatedLeftClosestWorldIntersection"
+            }
+            SEOutputDataId::SEEstimatedLeftAllWorldIntersections => {
+                "SEEstimatedLeftAllWorldIntersections"
+            }
+            SEOutputDataId::SEEstimatedRightClosestWorldIntersection => {
+                "SEEstimatedRightClosestWorldIntersection"
+            }
+            SEOutputDataId::SEEstimatedRightAllWorldIntersections => {
+                "SEEstimatedRightAllWorldIntersections"
+            }
+            SEOutputDataId::SEFilteredEstimatedClosestWorldIntersection => {
+                "SEFilteredEstimatedClosestWorldIntersection"
+            }
+            SEOutputDataId::SEFilteredEstimatedAllWorldIntersections => {
+                "SEFilteredEstimatedAllWorldIntersections"
+            }
+            SEOutputDataId::SEFilteredEstimatedLeftClosestWorldIntersection => {
+                "SEFilteredEstimatedLeftClosestWorldIntersection"
+            }
+            SEOutputDataId::SEFilteredEstimatedLeftAllWorldIntersections => {
+                "SEFilteredEstimatedLeftAllWorldIntersections"
+            }
+            SEOutputDataId::SEFilteredEstimatedRightClosestWorldIntersection => {
+                "SEFilteredEstimatedRightClosestWorldIntersection"
+            }
+            SEOutputDataId::SEFilteredEstimatedRightAllWorldIntersections => {
+                "SEFilteredEstimatedRightAllWorldIntersections"
+            }
+            SEOutputDataId::SETrackingState => "SETrackingState",
+            SEOutputDataId::SEEyeglassesStatus => "SEEyeglassesStatus",
+            SEOutputDataId::SEReflexReductionStateDEPRECATED => "SEReflexReductionStateDEPRECATED",
+            SEOutputDataId::SELeftBlinkClosingMidTime => "SELeftBlinkClosingMidTime",
+            SEOutputDataId::SELeftBlinkOpeningMidTime => "SELeftBlinkOpeningMidTime",
+            SEOutputDataId::SELeftBlinkClosingAmplitude => "SELeftBlinkClosingAmplitude",
+            SEOutputDataId::SELeftBlinkOpeningAmplitude => "SELeftBlinkOpeningAmplitude",
+            SEOutputDataId::SELeftBlinkClosingSpeed => "SELeftBlinkClosingSpeed",
+            SEOutputDataId::SELeftBlinkOpeningSpeed => "SELeftBlinkOpeningSpeed",
+            SEOutputDataId::SERightBlinkClosingMidTime => "SERightBlinkClosingMidTime",
+            SEOutputDataId::SERightBlinkOpeningMidTime => "SERightBlinkOpeningMidTime",
+            SEOutputDataId::SERightBlinkClosingAmplitude => "SERightBlinkClosingAmplitude",
+            SEOutputDataId::SERightBlinkOpeningAmplitude => "SERightBlinkOpeningAmplitude",
+            SEOutputDataId::SERightBlinkClosingSpeed => "SERightBlinkClosingSpeed",
+            SEOutputDataId::SERightBlinkOpeningSpeed => "SERightBlinkOpeningSpeed",
+            SEOutputDataId::SELeftEyelidState => "SELeftEyelidState",
+            SEOutputDataId::SERightEyelidState => "SERightEyelidState",
+            SEOutputDataId::SEUserMarker => "SEUserMarker",
+            SEOutputDataId::SECameraClocks => "SECameraClocks",
+            SEOutputDataId::SEEmotionJoy => "SEEmotionJoy",
+            SEOutputDataId::SEEmotionFear => "SEEmotionFear",
+            SEOutputDataId::SEEmotionDisgust => "SEEmotionDisgust",
+            SEOutputDataId::SEEmotionSadness => "SEEmotionSadness",
+            SEOutputDataId::SEEmotionSurprise => "SEEmotionSurprise",
+            SEOutputDataId::SEEmotionValence => "SEEmotionValence",
+            SEOutputDataId::SEEmotionEngagement => "SEEmotionEngagement",
+            SEOutputDataId::SEEmotionSentimentality => "SEEmotionSentimentality",
+            SEOutputDataId::SEEmotionConfusion => "SEEmotionConfusion",
+            SEOutputDataId::SEEmotionNeutral => "SEEmotionNeutral",
+            SEOutputDataId::SEEmotionQ => "SEEmotionQ",
+            SEOutputDataId::SEExpressionSmile => "SEExpressionSmile",
+            SEOutputDataId::SEExpressionInnerBrowRaise => "SEExpressionInnerBrowRaise",
+            SEOutputDataId::SEExpressionBrowRaise => "SEExpressionBrowRaise",
+            SEOutputDataId::SEExpressionBrowFurrow => "SEExpressionBrowFurrow",
+            SEOutputDataId::SEExpressionNoseWrinkle => "SEExpressionNoseWrinkle",
+            SEOutputDataId::SEExpressionUpperLipRaise => "SEExpressionUpperLipRaise",
+            SEOutputDataId::SEExpressionLipCornerDepressor => "SEExpressionLipCornerDepressor",
+            SEOutputDataId::SEExpressionChinRaise => "SEExpressionChinRaise",
+            SEOutputDataId::SEExpressionLipPucker => "SEExpressionLipPucker",
+            SEOutputDataId::SEExpressionLipPress => "SEExpressionLipPress",
+            SEOutputDataId::SEExpressionLipSuck => "SEExpressionLipSuck",
+            SEOutputDataId::SEExpressionMouthOpen => "SEExpressionMouthOpen",
+            SEOutputDataId::SEExpressionSmirk => "SEExpressionSmirk",
+            SEOutputDataId::SEExpressionAttention => "SEExpressionAttention",
+            SEOutputDataId::SEExpressionEyeWiden => "SEExpressionEyeWiden",
+            SEOutputDataId::SEExpressionCheekRaise => "SEExpressionCheekRaise",
+            SEOutputDataId::SEExpressionLidTighten => "SEExpressionLidTighten",
+            SEOutputDataId::SEExpressionDimpler => "SEExpressionDimpler",
+            SEOutputDataId::SEExpressionLipStretch => "SEExpressionLipStretch",
+            SEOutputDataId::SEExpressionJawDrop => "SEExpressionJawDrop",
+            SEOutputDataId::SEExpressionQ => "SEExpressionQ",
+        }
+    }
+
+    // Inverse of name, None if there is no output data with the name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "SEFrameNumber" => Some(SEOutputDataId::SEFrameNumber),
+            "SEEstimatedDelay" => Some(SEOutputDataId::SEEstimatedDelay),
+            "SETimeStamp" => Some(SEOutputDataId::SETimeStamp),
+            "SEUserTimeStamp" => Some(SEOutputDataId::SEUserTimeStamp),
+            "SEFrameRate" => Some(SEOutputDataId::SEFrameRate),
+            "SECameraPositions" => Some(SEOutputDataId::SECameraPositions),
+            "SECameraRotations" => Some(SEOutputDataId::SECameraRotations),
+            "SEUserDefinedData" => Some(SEOutputDataId::SEUserDefinedData),
+            "SERealTimeClock" => Some(SEOutputDataId::SERealTimeClock),
+            "SEHeadPosition" => Some(SEOutputDataId::SEHeadPosition),
+            "SEHeadPositionQ" => Some(SEOutputDataId::SEHeadPositionQ),
+            "SEHeadRotationRodrigues" => Some(SEOutputDataId::SEHeadRotationRodrigues),
+            "SEHeadRotationQuaternion" => Some(SEOutputDataId::SEHeadRotationQuaternion),
+            "SEHeadLeftEarDirection" => Some(SEOutputDataId::SEHeadLeftEarDirection),
+            "SEHeadUpDirection" => Some(SEOutputDataId::SEHeadUpDirection),
+            "SEHeadNoseDirection" => Some(SEOutputDataId::SEHeadNoseDirection),
+            "SEHeadHeading" => Some(SEOutputDataId::SEHeadHeading),
+            "SEHeadPitch" => Some(SEOutputDataId::SEHeadPitch),
+            "SEHeadRoll" => Some(SEOutputDataId::SEHeadRoll),
+            "SEHeadRotationQ" => Some(SEOutputDataId::SEHeadRotationQ),
+            "SEGazeOrigin" => Some(SEOutputDataId::SEGazeOrigin),
+            "SELeftGazeOrigin" => Some(SEOutputDataId::SELeftGazeOrigin),
+            "SERightGazeOrigin" => Some(SEOutputDataId::SERightGazeOrigin),
+            "SEEyePosition" => Some(SEOutputDataId::SEEyePosition),
+            "SEGazeDirection" => Some(SEOutputDataId::SEGazeDirection),
+            "SEGazeDirectionQ" => Some(SEOutputDataId::SEGazeDirectionQ),
+            "SELeftEyePosition" => Some(SEOutputDataId::SELeftEyePosition),
+            "SELeftGazeDirection" => Some(SEOutputDataId::SELeftGazeDirection),
+            "SELeftGazeDirectionQ" => Some(SEOutputDataId::SELeftGazeDirectionQ),
+            "SERightEyePosition" => Some(SEOutputDataId::SERightEyePosition),
+            "SERightGazeDirection" => Some(SEOutputDataId::SERightGazeDirection),
+            "SERightGazeDirectionQ" => Some(SEOutputDataId::SERightGazeDirectionQ),
+            "SEGazeHeading" => Some(SEOutputDataId::SEGazeHeading),
+            "SEGazePitch" => Some(SEOutputDataId::SEGazePitch),
+            "SELeftGazeHeading" => Some(SEOutputDataId::SELeftGazeHeading),
+            "SELeftGazePitch" => Some(SEOutputDataId::SELeftGazePitch),
+            "SERightGazeHeading" => Some(SEOutputDataId::SERightGazeHeading),
+            "SERightGazePitch" => Some(SEOutputDataId::SERightGazePitch),
+            "SEFilteredGazeDirection" => Some(SEOutputDataId::SEFilteredGazeDirection),
+            "SEFilteredGazeDirectionQ" => Some(SEOutputDataId::SEFilteredGazeDirectionQ),
+            "SEFilteredLeftGazeDirection" => Some(SEOutputDataId::SEFilteredLeftGazeDirection),
+            "SEFilteredLeftGazeDirectionQ" => Some(SEOutputDataId::SEFilteredLeftGazeDirectionQ),
+            "SEFilteredRightGazeDirection" => Some(SEOutputDataId::SEFilteredRightGazeDirection),
+            "SEFilteredRightGazeDirectionQ" => Some(SEOutputDataId::SEFilteredRightGazeDirectionQ),
+            "SEFilteredGazeHeading" => Some(SEOutputDataId::SEFilteredGazeHeading),
+            "SEFilteredGazePitch" => Some(SEOutputDataId::SEFilteredGazePitch),
+            "SEFilteredLeftGazeHeading" => Some(SEOutputDataId::SEFilteredLeftGazeHeading),
+            "SEFilteredLeftGazePitch" => Some(SEOutputDataId::SEFilteredLeftGazePitch),
+            "SEFilteredRightGazeHeading" => Some(SEOutputDataId::SEFilteredRightGazeHeading),
+            "SEFilteredRightGazePitch" => Some(SEOutputDataId::SEFilteredRightGazePitch),
+            "SESaccade" => Some(SEOutputDataId::SESaccade),
+            "SEFixation" => Some(SEOutputDataId::SEFixation),
+            "SEBlink" => Some(SEOutputDataId::SEBlink),
+            "SEClosestWorldIntersection" => Some(SEOutputDataId::SEClosestWorldIntersection),
+            "SEFilteredClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEFilteredClosestWorldIntersection)
+            }
+            "SEAllWorldIntersections" => Some(SEOutputDataId::SEAllWorldIntersections),
+            "SEFilteredAllWorldIntersections" => {
+                Some(SEOutputDataId::SEFilteredAllWorldIntersections)
+            }
+            "SEZoneId" => Some(SEOutputDataId::SEZoneId),
+            "SEEstimatedClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEEstimatedClosestWorldIntersection)
+            }
+            "SEEstimatedAllWorldIntersections" => {
+                Some(SEOutputDataId::SEEstimatedAllWorldIntersections)
+            }
+            "SEHeadClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEHeadClosestWorldIntersection)
+            }
+            "SEHeadAllWorldIntersections" => Some(SEOutputDataId::SEHeadAllWorldIntersections),
+            "SEEyelidOpening" => Some(SEOutputDataId::SEEyelidOpening),
+            "SEEyelidOpeningQ" => Some(SEOutputDataId::SEEyelidOpeningQ),
+            "SELeftEyelidOpening" => Some(SEOutputDataId::SELeftEyelidOpening),
+            "SELeftEyelidOpeningQ" => Some(SEOutputDataId::SELeftEyelidOpeningQ),
+            "SERightEyelidOpening" => Some(SEOutputDataId::SERightEyelidOpening),
+            "SERightEyelidOpeningQ" => Some(SEOutputDataId::SERightEyelidOpeningQ),
+            "SEKeyboardState" => Some(SEOutputDataId::SEKeyboardState),
+            "SELeftLowerEyelidExtremePoint" => Some(SEOutputDataId::SELeftLowerEyelidExtremePoint),
+            "SELeftUpperEyelidExtremePoint" => Some(SEOutputDataId::SELeftUpperEyelidExtremePoint),
+            "SERightLowerEyelidExtremePoint" => {
+                Some(SEOutputDataId::SERightLowerEyelidExtremePoint)
+            }
+            "SERightUpperEyelidExtremePoint" => {
+                Some(SEOutputDataId::SERightUpperEyelidExtremePoint)
+            }
+            "SEPupilDiameter" => Some(SEOutputDataId::SEPupilDiameter),
+            "SEPupilDiameterQ" => Some(SEOutputDataId::SEPupilDiameterQ),
+            "SELeftPupilDiameter" => Some(SEOutputDataId::SELeftPupilDiameter),
+            "SELeftPupilDiameterQ" => Some(SEOutputDataId::SELeftPupilDiameterQ),
+            "SERightPupilDiameter" => Some(SEOutputDataId::SERightPupilDiameter),
+            "SERightPupilDiameterQ" => Some(SEOutputDataId::SERightPupilDiameterQ),
+            "SEFilteredPupilDiameter" => Some(SEOutputDataId::SEFilteredPupilDiameter),
+            "SEFilteredPupilDiameterQ" => Some(SEOutputDataId::SEFilteredPupilDiameterQ),
+            "SEFilteredLeftPupilDiameter" => Some(SEOutputDataId::SEFilteredLeftPupilDiameter),
+            "SEFilteredLeftPupilDiameterQ" => Some(SEOutputDataId::SEFilteredLeftPupilDiameterQ),
+            "SEFilteredRightPupilDiameter" => Some(SEOutputDataId::SEFilteredRightPupilDiameter),
+            "SEFilteredRightPupilDiameterQ" => Some(SEOutputDataId::SEFilteredRightPupilDiameterQ),
+            "SEGPSPosition" => Some(SEOutputDataId::SEGPSPosition),
+            "SEGPSGroundSpeed" => Some(SEOutputDataId::SEGPSGroundSpeed),
+            "SEGPSCourse" => Some(SEOutputDataId::SEGPSCourse),
+            "SEGPSTime" => Some(SEOutputDataId::SEGPSTime),
+            "SEEstimatedGazeOrigin" => Some(SEOutputDataId::SEEstimatedGazeOrigin),
+            "SEEstimatedLeftGazeOrigin" => Some(SEOutputDataId::SEEstimatedLeftGazeOrigin),
+            "SEEstimatedRightGazeOrigin" => Some(SEOutputDataId::SEEstimatedRightGazeOrigin),
+            "SEEstimatedEyePosition" => Some(SEOutputDataId::SEEstimatedEyePosition),
+            "SEEstimatedGazeDirection" => Some(SEOutputDataId::SEEstimatedGazeDirection),
+            "SEEstimatedGazeDirectionQ" => Some(SEOutputDataId::SEEstimatedGazeDirectionQ),
+            "SEEstimatedGazeHeading" => Some(SEOutputDataId::SEEstimatedGazeHeading),
+            "SEEstimatedGazePitch" => Some(SEOutputDataId::SEEstimatedGazePitch),
+            "SEEstimatedLeftEyePosition" => Some(SEOutputDataId::SEEstimatedLeftEyePosition),
+            "SEEstimatedLeftGazeDirection" => Some(SEOutputDataId::SEEstimatedLeftGazeDirection),
+            "SEEstimatedLeftGazeDirectionQ" => Some(SEOutputDataId::SEEstimatedLeftGazeDirectionQ),
+            "SEEstimatedLeftGazeHeading" => Some(SEOutputDataId::SEEstimatedLeftGazeHeading),
+            "SEEstimatedLeftGazePitch" => Some(SEOutputDataId::SEEstimatedLeftGazePitch),
+            "SEEstimatedRightEyePosition" => Some(SEOutputDataId::SEEstimatedRightEyePosition),
+            "SEEstimatedRightGazeDirection" => Some(SEOutputDataId::SEEstimatedRightGazeDirection),
+            "SEEstimatedRightGazeDirectionQ" => {
+                Some(SEOutputDataId::SEEstimatedRightGazeDirectionQ)
+            }
+            "SEEstimatedRightGazeHeading" => Some(SEOutputDataId::SEEstimatedRightGazeHeading),
+            "SEEstimatedRightGazePitch" => Some(SEOutputDataId::SEEstimatedRightGazePitch),
+            "SEFilteredEstimatedGazeDirection" => {
+                Some(SEOutputDataId::SEFilteredEstimatedGazeDirection)
+            }
+            "SEFilteredEstimatedGazeDirectionQ" => {
+                Some(SEOutputDataId::SEFilteredEstimatedGazeDirectionQ)
+            }
+            "SEFilteredEstimatedGazeHeading" => {
+                Some(SEOutputDataId::SEFilteredEstimatedGazeHeading)
+            }
+            "SEFilteredEstimatedGazePitch" => Some(SEOutputDataId::SEFilteredEstimatedGazePitch),
+            "SEFilteredEstimatedLeftGazeDirection" => {
+                Some(SEOutputDataId::SEFilteredEstimatedLeftGazeDirection)
+            }
+            "SEFilteredEstimatedLeftGazeDirectionQ" => {
+                Some(SEOutputDataId::SEFilteredEstimatedLeftGazeDirectionQ)
+            }
+            "SEFilteredEstimatedLeftGazeHeading" => {
+                Some(SEOutputDataId::SEFilteredEstimatedLeftGazeHeading)
+            }
+            "SEFilteredEstimatedLeftGazePitch" => {
+                Some(SEOutputDataId::SEFilteredEstimatedLeftGazePitch)
+            }
+            "SEFilteredEstimatedRightGazeDirection" => {
+                Some(SEOutputDataId::SEFilteredEstimatedRightGazeDirection)
+            }
+            "SEFilteredEstimatedRightGazeDirectionQ" => {
+                Some(SEOutputDataId::SEFilteredEstimatedRightGazeDirectionQ)
+            }
+            "SEFilteredEstimatedRightGazeHeading" => {
+                Some(SEOutputDataId::SEFilteredEstimatedRightGazeHeading)
+            }
+            "SEFilteredEstimatedRightGazePitch" => {
+                Some(SEOutputDataId::SEFilteredEstimatedRightGazePitch)
+            }
+            "SEASCIIKeyboardState" => Some(SEOutputDataId::SEASCIIKeyboardState),
+            "SECalibrationGazeIntersection" => Some(SEOutputDataId::SECalibrationGazeIntersection),
+            "SETaggedGazeIntersection" => Some(SEOutputDataId::SETaggedGazeIntersection),
+            "SELeftClosestWorldIntersection" => {
+                Some(SEOutputDataId::SELeftClosestWorldIntersection)
+            }
+            "SELeftAllWorldIntersections" => Some(SEOutputDataId::SELeftAllWorldIntersections),
+            "SERightClosestWorldIntersection" => {
+                Some(SEOutputDataId::SERightClosestWorldIntersection)
+            }
+            "SERightAllWorldIntersections" => Some(SEOutputDataId::SERightAllWorldIntersections),
+            "SEFilteredLeftClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEFilteredLeftClosestWorldIntersection)
+            }
+            "SEFilteredLeftAllWorldIntersections" => {
+                Some(SEOutputDataId::SEFilteredLeftAllWorldIntersections)
+            }
+            "SEFilteredRightClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEFilteredRightClosestWorldIntersection)
+            }
+            "SEFilteredRightAllWorldIntersections" => {
+                Some(SEOutputDataId::SEFilteredRightAllWorldIntersections)
+            }
+            "SEEstimatedLeftClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEEstimatedLeftClosestWorldIntersection)
+            }
+            "SEEstimatedLeftAllWorldIntersections" => {
+                Some(SEOutputDataId::SEEstimatedLeftAllWorldIntersections)
+            }
+            "SEEstimatedRightClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEEstimatedRightClosestWorldIntersection)
+            }
+            "SEEstimatedRightAllWorldIntersections" => {
+                Some(SEOutputDataId::SEEstimatedRightAllWorldIntersections)
+            }
+            "SEFilteredEstimatedClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEFilteredEstimatedClosestWorldIntersection)
+            }
+            "SEFilteredEstimatedAllWorldIntersections" => {
+                Some(SEOutputDataId::SEFilteredEstimatedAllWorldIntersections)
+            }
+            "SEFilteredEstimatedLeftClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEFilteredEstimatedLeftClosestWorldIntersection)
+            }
+            "SEFilteredEstimatedLeftAllWorldIntersections" => {
+                Some(SEOutputDataId::SEFilteredEstimatedLeftAllWorldIntersections)
+            }
+            "SEFilteredEstimatedRightClosestWorldIntersection" => {
+                Some(SEOutputDataId::SEFilteredEstimatedRightClosestWorldIntersection)
+            }
+            "SEFilteredEstimatedRightAllWorldIntersections" => {
+                Some(SEOutputDataId::SEFilteredEstimatedRightAllWorldIntersections)
+            }
+            "SETrackingState" => Some(SEOutputDataId::SETrackingState),
+            "SEEyeglassesStatus" => Some(SEOutputDataId::SEEyeglassesStatus),
+            "SEReflexReductionStateDEPRECATED" => {
+                Some(SEOutputDataId::SEReflexReductionStateDEPRECATED)
+            }
+            "SELeftBlinkClosingMidTime" => Some(SEOutputDataId::SELeftBlinkClosingMidTime),
+            "SELeftBlinkOpeningMidTime" => Some(SEOutputDataId::SELeftBlinkOpeningMidTime),
+            "SELeftBlinkClosingAmplitude" => Some(SEOutputDataId::SELeftBlinkClosingAmplitude),
+            "SELeftBlinkOpeningAmplitude" => Some(SEOutputDataId::SELeftBlinkOpeningAmplitude),
+            "SELeftBlinkClosingSpeed" => Some(SEOutputDataId::SELeftBlinkClosingSpeed),
+            "SELeftBlinkOpeningSpeed" => Some(SEOutputDataId::SELeftBlinkOpeningSpeed),
+            "SERightBlinkClosingMidTime" => Some(SEOutputDataId::SERightBlinkClosingMidTime),
+            "SERightBlinkOpeningMidTime" => Some(SEOutputDataId::SERightBlinkOpeningMidTime),
+            "SERightBlinkClosingAmplitude" => Some(SEOutputDataId::SERightBlinkClosingAmplitude),
+            "SERightBlinkOpeningAmplitude" => Some(SEOutputDataId::SERightBlinkOpeningAmplitude),
+            "SERightBlinkClosingSpeed" => Some(SEOutputDataId::SERightBlinkClosingSpeed),
+            "SERightBlinkOpeningSpeed" => Some(SEOutputDataId::SERightBlinkOpeningSpeed),
+            "SELeftEyelidState" => Some(SEOutputDataId::SELeftEyelidState),
+            "SERightEyelidState" => Some(SEOutputDataId::SERightEyelidState),
+            "SEUserMarker" => Some(SEOutputDataId::SEUserMarker),
+            "SECameraClocks" => Some(SEOutputDataId::SECameraClocks),
+            "SEEmotionJoy" => Some(SEOutputDataId::SEEmotionJoy),
+            "SEEmotionFear" => Some(SEOutputDataId::SEEmotionFear),
+            "SEEmotionDisgust" => Some(SEOutputDataId::SEEmotionDisgust),
+            "SEEmotionSadness" => Some(SEOutputDataId::SEEmotionSadness),
+            "SEEmotionSurprise" => Some(SEOutputDataId::SEEmotionSurprise),
+            "SEEmotionValence" => Some(SEOutputDataId::SEEmotionValence),
+            "SEEmotionEngagement" => Some(SEOutputDataId::SEEmotionEngagement),
+            "SEEmotionSentimentality" => Some(SEOutputDataId::SEEmotionSentimentality),
+            "SEEmotionConfusion" => Some(SEOutputDataId::SEEmotionConfusion),
+            "SEEmotionNeutral" => Some(SEOutputDataId::SEEmotionNeutral),
+            "SEEmotionQ" => Some(SEOutputDataId::SEEmotionQ),
+            "SEExpressionSmile" => Some(SEOutputDataId::SEExpressionSmile),
+            "SEExpressionInnerBrowRaise" => Some(SEOutputDataId::SEExpressionInnerBrowRaise),
+            "SEExpressionBrowRaise" => Some(SEOutputDataId::SEExpressionBrowRaise),
+            "SEExpressionBrowFurrow" => Some(SEOutputDataId::SEExpressionBrowFurrow),
+            "SEExpressionNoseWrinkle" => Some(SEOutputDataId::SEExpressionNoseWrinkle),
+            "SEExpressionUpperLipRaise" => Some(SEOutputDataId::SEExpressionUpperLipRaise),
+            "SEExpressionLipCornerDepressor" => {
+                Some(SEOutputDataId::SEExpressionLipCornerDepressor)
+            }
+            "SEExpressionChinRaise" => Some(SEOutputDataId::SEExpressionChinRaise),
+            "SEExpressionLipPucker" => Some(SEOutputDataId::SEExpressionLipPucker),
+            "SEExpressionLipPress" => Some(SEOutputDataId::SEExpressionLipPress),
+            "SEExpressionLipSuck" => Some(SEOutputDataId::SEExpressionLipSuck),
+            "SEExpressionMouthOpen" => Some(SEOutputDataId::SEExpressionMouthOpen),
+            "SEExpressionSmirk" => Some(SEOutputDataId::SEExpressionSmirk),
+            "SEExpressionAttention" => Some(SEOutputDataId::SEExpressionAttention),
+            "SEExpressionEyeWiden" => Some(SEOutputDataId::SEExpressionEyeWiden),
+            "SEExpressionCheekRaise" => Some(SEOutputDataId::SEExpressionCheekRaise),
+            "SEExpressionLidTighten" => Some(SEOutputDataId::SEExpressionLidTighten),
+            "SEExpressionDimpler" => Some(SEOutputDataId::SEExpressionDimpler),
+            "SEExpressionLipStretch" => Some(SEOutputDataId::SEExpressionLipStretch),
+            "SEExpressionJawDrop" => Some(SEOutputDataId::SEExpressionJawDrop),
+            "SEExpressionQ" => Some(SEOutputDataId::SEExpressionQ),
+
+            _ => None,
+        }
+    }
+
+    pub fn type_id(&self) -> SETypeId {
+        match self {
+            SEOutputDataId::SEFrameNumber => SETypeId::U32,
+            SEOutputDataId::SEEstimatedDelay => SETypeId::U32,
+            SEOutputDataId::SETimeStamp => SETypeId::U64,
+            SEOutputDataId::SEUserTimeStamp => SETypeId::U64,
+            SEOutputDataId::SEFrameRate => SETypeId::F64,
+            SEOutputDataId::SECameraPositions => SETypeId::Vector,
+            SEOutputDataId::SECameraRotations => SETypeId::Vector,
+            SEOutputDataId::SEUserDefinedData => SETypeId::U64,
+            SEOutputDataId::SERealTimeClock => SETypeId::U64,
+            SEOutputDataId::SEHeadPosition => SETypeId::Point3D,
+            SEOutputDataId::SEHeadPositionQ => SETypeId::F64,
+            SEOutputDataId::SEHeadRotationRodrigues => SETypeId::Vect3D,
+            SEOutputDataId::SEHeadRotationQuaternion => SETypeId::Quaternion,
+            SEOutputDataId::SEHeadLeftEarDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEHeadUpDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEHeadNoseDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEHeadHeading => SETypeId::F64,
+            SEOutputDataId::SEHeadPitch => SETypeId::F64,
+            SEOutputDataId::SEHeadRoll => SETypeId::F64,
+            SEOutputDataId::SEHeadRotationQ => SETypeId::F64,
+            SEOutputDataId::SEGazeOrigin => SETypeId::Point3D,
+            SEOutputDataId::SELeftGazeOrigin => SETypeId::Point3D,
+            SEOutputDataId::SERightGazeOrigin => SETypeId::Point3D,
+            SEOutputDataId::SEEyePosition => SETypeId::Point3D,
+            SEOutputDataId::SEGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SELeftEyePosition => SETypeId::Point3D,
+            SEOutputDataId::SELeftGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SELeftGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SERightEyePosition => SETypeId::Point3D,
+            SEOutputDataId::SERightGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SERightGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEGazePitch => SETypeId::F64,
+            SEOutputDataId::SELeftGazeHeading => SETypeId::F64,
+            SEOutputDataId::SELeftGazePitch => SETypeId::F64,
+            SEOutputDataId::SERightGazeHeading => SETypeId::F64,
+            SEOutputDataId::SERightGazePitch => SETypeId::F64,
+            SEOutputDataId::SEFilteredGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEFilteredGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredLeftGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEFilteredLeftGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredRightGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEFilteredRightGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEFilteredGazePitch => SETypeId::F64,
+            SEOutputDataId::SEFilteredLeftGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEFilteredLeftGazePitch => SETypeId::F64,
+            SEOutputDataId::SEFilteredRightGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEFilteredRightGazePitch => SETypeId::F64,
+            SEOutputDataId::SESaccade => SETypeId::U32,
+            SEOutputDataId::SEFixation => SETypeId::U32,
+            SEOutputDataId::SEBlink => SETypeId::U32,
+            SEOutputDataId::SEClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEFilteredClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEFilteredAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEZoneId => SETypeId::U16,
+            SEOutputDataId::SEEstimatedClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEEstimatedAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEHeadClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEHeadAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEEyelidOpening => SETypeId::F64,
+            SEOutputDataId::SEEyelidOpeningQ => SETypeId::F64,
+            SEOutputDataId::SELeftEyelidOpening => SETypeId::F64,
+            SEOutputDataId::SELeftEyelidOpeningQ => SETypeId::F64,
+            SEOutputDataId::SERightEyelidOpening => SETypeId::F64,
+            SEOutputDataId::SERightEyelidOpeningQ => SETypeId::F64,
+            SEOutputDataId::SEKeyboardState => SETypeId::String,
+            SEOutputDataId::SELeftLowerEyelidExtremePoint => SETypeId::Point3D,
+            SEOutputDataId::SELeftUpperEyelidExtremePoint => SETypeId::Point3D,
+            SEOutputDataId::SERightLowerEyelidExtremePoint => SETypeId::Point3D,
+            SEOutputDataId::SERightUpperEyelidExtremePoint => SETypeId::Point3D,
+            SEOutputDataId::SEPupilDiameter => SETypeId::F64,
+            SEOutputDataId::SEPupilDiameterQ => SETypeId::F64,
+            SEOutputDataId::SELeftPupilDiameter => SETypeId::F64,
+            SEOutputDataId::SELeftPupilDiameterQ => SETypeId::F64,
+            SEOutputDataId::SERightPupilDiameter => SETypeId::F64,
+            SEOutputDataId::SERightPupilDiameterQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredPupilDiameter => SETypeId::F64,
+            SEOutputDataId::SEFilteredPupilDiameterQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredLeftPupilDiameter => SETypeId::F64,
+            SEOutputDataId::SEFilteredLeftPupilDiameterQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredRightPupilDiameter => SETypeId::F64,
+            SEOutputDataId::SEFilteredRightPupilDiameterQ => SETypeId::F64,
+            SEOutputDataId::SEGPSPosition => SETypeId::Point2D,
+            SEOutputDataId::SEGPSGroundSpeed => SETypeId::F64,
+            SEOutputDataId::SEGPSCourse => SETypeId::F64,
+            SEOutputDataId::SEGPSTime => SETypeId::U64,
+            SEOutputDataId::SEEstimatedGazeOrigin => SETypeId::Point3D,
+            SEOutputDataId::SEEstimatedLeftGazeOrigin => SETypeId::Point3D,
+            SEOutputDataId::SEEstimatedRightGazeOrigin => SETypeId::Point3D,
+            SEOutputDataId::SEEstimatedEyePosition => SETypeId::Point3D,
+            SEOutputDataId::SEEstimatedGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEEstimatedGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEEstimatedGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEEstimatedGazePitch => SETypeId::F64,
+            SEOutputDataId::SEEstimatedLeftEyePosition => SETypeId::Point3D,
+            SEOutputDataId::SEEstimatedLeftGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEEstimatedLeftGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEEstimatedLeftGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEEstimatedLeftGazePitch => SETypeId::F64,
+            SEOutputDataId::SEEstimatedRightEyePosition => SETypeId::Point3D,
+            SEOutputDataId::SEEstimatedRightGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEEstimatedRightGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEEstimatedRightGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEEstimatedRightGazePitch => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEFilteredEstimatedGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedGazePitch => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedLeftGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEFilteredEstimatedLeftGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedLeftGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedLeftGazePitch => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedRightGazeDirection => SETypeId::Vect3D,
+            SEOutputDataId::SEFilteredEstimatedRightGazeDirectionQ => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedRightGazeHeading => SETypeId::F64,
+            SEOutputDataId::SEFilteredEstimatedRightGazePitch => SETypeId::F64,
+            SEOutputDataId::SEASCIIKeyboardState => SETypeId::U16,
+            SEOutputDataId::SECalibrationGazeIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SETaggedGazeIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SELeftClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SELeftAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SERightClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SERightAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEFilteredLeftClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEFilteredLeftAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEFilteredRightClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEFilteredRightAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEEstimatedLeftClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEEstimatedLeftAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEEstimatedRightClosestWorldIntersection => SETypeId::WorldIntersection,
+            SEOutputDataId::SEEstimatedRightAllWorldIntersections => SETypeId::WorldIntersections,
+            SEOutputDataId::SEFilteredEstimatedClosestWorldIntersection => {
+                SETypeId::WorldIntersection
+            }
+            SEOutputDataId::SEFilteredEstimatedAllWorldIntersections => {
+                SETypeId::WorldIntersections
+            }
+            SEOutputDataId::SEFilteredEstimatedLeftClosestWorldIntersection => {
+                SETypeId::WorldIntersection
+            }
+            SEOutputDataId::SEFilteredEstimatedLeftAllWorldIntersections => {
+                SETypeId::WorldIntersections
+            }
+            SEOutputDataId::SEFilteredEstimatedRightClosestWorldIntersection => {
+                SETypeId::WorldIntersection
+            }
+            SEOutputDataId::SEFilteredEstimatedRightAllWorldIntersections => {
+                SETypeId::WorldIntersections
+            }
+            SEOutputDataId::SETrackingState => SETypeId::U8,
+            SEOutputDataId::SEEyeglassesStatus => SETypeId::U8,
+            SEOutputDataId::SEReflexReductionStateDEPRECATED => SETypeId::U8,
+            SEOutputDataId::SELeftBlinkClosingMidTime => SETypeId::U64,
+            SEOutputDataId::SELeftBlinkOpeningMidTime => SETypeId::U64,
+            SEOutputDataId::SELeftBlinkClosingAmplitude => SETypeId::F64,
+            SEOutputDataId::SELeftBlinkOpeningAmplitude => SETypeId::F64,
+            SEOutputDataId::SELeftBlinkClosingSpeed => SETypeId::F64,
+            SEOutputDataId::SELeftBlinkOpeningSpeed => SETypeId::F64,
+            SEOutputDataId::SERightBlinkClosingMidTime => SETypeId::U64,
+            SEOutputDataId::SERightBlinkOpeningMidTime => SETypeId::U64,
+            SEOutputDataId::SERightBlinkClosingAmplitude => SETypeId::F64,
+            SEOutputDataId::SERightBlinkOpeningAmplitude => SETypeId::F64,
+            SEOutputDataId::SERightBlinkClosingSpeed => SETypeId::F64,
+            SEOutputDataId::SERightBlinkOpeningSpeed => SETypeId::F64,
+            SEOutputDataId::SELeftEyelidState => SETypeId::U8,
+            SEOutputDataId::SERightEyelidState => SETypeId::U8,
+            SEOutputDataId::SEUserMarker => SETypeId::UserMarker,
+            SEOutputDataId::SECameraClocks => SETypeId::Vector,
+            SEOutputDataId::SEEmotionJoy => SETypeId::F64,
+            SEOutputDataId::SEEmotionFear => SETypeId::F64,
+            SEOutputDataId::SEEmotionDisgust => SETypeId::F64,
+            SEOutputDataId::SEEmotionSadness => SETypeId::F64,
+            SEOutputDataId::SEEmotionSurprise => SETypeId::F64,
+            SEOutputDataId::SEEmotionValence => SETypeId::F64,
+            SEOutputDataId::SEEmotionEngagement => SETypeId::F64,
+            SEOutputDataId::SEEmotionSentimentality => SETypeId::F64,
+            SEOutputDataId::SEEmotionConfusion => SETypeId::F64,
+            SEOutputDataId::SEEmotionNeutral => SETypeId::F64,
+            SEOutputDataId::SEEmotionQ => SETypeId::F64,
+            SEOutputDataId::SEExpressionSmile => SETypeId::F64,
+            SEOutputDataId::SEExpressionInnerBrowRaise => SETypeId::F64,
+            SEOutputDataId::SEExpressionBrowRaise => SETypeId::F64,
+            SEOutputDataId::SEExpressionBrowFurrow => SETypeId::F64,
+            SEOutputDataId::SEExpressionNoseWrinkle => SETypeId::F64,
+            SEOutputDataId::SEExpressionUpperLipRaise => SETypeId::F64,
+            SEOutputDataId::SEExpressionLipCornerDepressor => SETypeId::F64,
+            SEOutputDataId::SEExpressionChinRaise => SETypeId::F64,
+            SEOutputDataId::SEExpressionLipPucker => SETypeId::F64,
+            SEOutputDataId::SEExpressionLipPress => SETypeId::F64,
+            SEOutputDataId::SEExpressionLipSuck => SETypeId::F64,
+            SEOutputDataId::SEExpressionMouthOpen => SETypeId::F64,
+            SEOutputDataId::SEExpressionSmirk => SETypeId::F64,
+            SEOutputDataId::SEExpressionAttention => SETypeId::F64,
+            SEOutputDataId::SEExpressionEyeWiden => SETypeId::F64,
+            SEOutputDataId::SEExpressionCheekRaise => SETypeId::F64,
+            SEOutputDataId::SEExpressionLidTighten => SETypeId::F64,
+            SEOutputDataId::SEExpressionDimpler => SETypeId::F64,
+            SEOutputDataId::SEExpressionLipStretch => SETypeId::F64,
+            SEOutputDataId::SEExpressionJawDrop => SETypeId::F64,
+            SEOutputDataId::SEExpressionQ => SETypeId::F64,
+        }
+    }
+
+    // Eye the output data is measured from, or None if not eye related (e.g.
+    // head pose or time stamps).
+    pub fn eye(&self) -> Option<Eye> {
+        match self {
+            SEOutputDataId::SEFrameNumber => None,
+            SEOutputDataId::SEEstimatedDelay => None,
+            SEOutputDataId::SETimeStamp => None,
+            SEOutputDataId::SEUserTimeStamp => None,
+            SEOutputDataId::SEFrameRate => None,
+            SEOutputDataId::SECameraPositions => None,
+            SEOutputDataId::SECameraRotations => None,
+            SEOutputDataId::SEUserDefinedData => None,
+            SEOutputDataId::SERealTimeClock => None,
+            SEOutputDataId::SEHeadPosition => None,
+            SEOutputDataId::SEHeadPositionQ => None,
+            SEOutputDataId::SEHeadRotationRodrigues => None,
+            SEOutputDataId::SEHeadRotationQuaternion => None,
+            SEOutputDataId::SEHeadLeftEarDirection => None,
+            SEOutputDataId::SEHeadUpDirection => None,
+            SEOutputDataId::SEHeadNoseDirection => None,
+            SEOutputDataId::SEHeadHeading => None,
+            SEOutputDataId::SEHeadPitch => None,
+            SEOutputDataId::SEHeadRoll => None,
+            SEOutputDataId::SEHeadRotationQ => None,
+            SEOutputDataId::SEGazeOrigin => Some(Eye::Combined),
+            SEOutputDataId::SELeftGazeOrigin => Some(Eye::Left),
+            SEOutputDataId::SERightGazeOrigin => Some(Eye::Right),
+            SEOutputDataId::SEEyePosition => Some(Eye::Combined),
+            SEOutputDataId::SEGazeDirection => Some(Eye::Combined),
+            SEOutputDataId::SEGazeDirectionQ => Some(Eye::Combined),
+            SEOutputDataId::SELeftEyePosition => Some(Eye::Left),
+            SEOutputDataId::SELeftGazeDirection => Some(Eye::Left),
+            SEOutputDataId::SELeftGazeDirectionQ => Some(Eye::Left),
+            SEOutputDataId::SERightEyePosition => Some(Eye::Right),
+            SEOutputDataId::SERightGazeDirection => Some(Eye::Right),
+            SEOutputDataId::SERightGazeDirectionQ => Some(Eye::Right),
+            SEOutputDataId::SEGazeHeading => Some(Eye::Combined),
+            SEOutputDataId::SEGazePitch => Some(Eye::Combined),
+            SEOutputDataId::SELeftGazeHeading => Some(Eye::Left),
+            SEOutputDataId::SELeftGazePitch => Some(Eye::Left),
+            SEOutputDataId::SERightGazeHeading => Some(Eye::Right),
+            SEOutputDataId::SERightGazePitch => Some(Eye::Right),
+            SEOutputDataId::SEFilteredGazeDirection => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredGazeDirectionQ => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredLeftGazeDirection => Some(Eye::Left),
+            SEOutputDataId::SEFilteredLeftGazeDirectionQ => Some(Eye::Left),
+            SEOutputDataId::SEFilteredRightGazeDirection => Some(Eye::Right),
+            SEOutputDataId::SEFilteredRightGazeDirectionQ => Some(Eye::Right),
+            SEOutputDataId::SEFilteredGazeHeading => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredGazePitch => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredLeftGazeHeading => Some(Eye::Left),
+            SEOutputDataId::SEFilteredLeftGazePitch => Some(Eye::Left),
+            SEOutputDataId::SEFilteredRightGazeHeading => Some(Eye::Right),
+            SEOutputDataId::SEFilteredRightGazePitch => Some(Eye::Right),
+            SEOutputDataId::SESaccade => Some(Eye::Combined),
+            SEOutputDataId::SEFixation => Some(Eye::Combined),
+            SEOutputDataId::SEBlink => Some(Eye::Combined),
+            SEOutputDataId::SEClosestWorldIntersection => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredClosestWorldIntersection => Some(Eye::Combined),
+            SEOutputDataId::SEAllWorldIntersections => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredAllWorldIntersections => Some(Eye::Combined),
+            SEOutputDataId::SEZoneId => None,
+            SEOutputDataId::SEEstimatedClosestWorldIntersection => Some(Eye::Combined),
+            SEOutputDataId::SEEstimatedAllWorldIntersections => Some(Eye::Combined),
+            SEOutputDataId::SEHeadClosestWorldIntersection => None,
+            SEOutputDataId::SEHeadAllWorldIntersections => None,
+            SEOutputDataId::SEEyelidOpening => Some(Eye::Combined),
+            SEOutputDataId::SEEyelidOpeningQ => Some(Eye::Combined),
+            SEOutputDataId::SELeftEyelidOpening => Some(Eye::Left),
+            SEOutputDataId::SELeftEyelidOpeningQ => Some(Eye::Left),
+            SEOutputDataId::SERightEyelidOpening => Some(Eye::Right),
+            SEOutputDataId::SERightEyelidOpeningQ => Some(Eye::Right),
+            SEOutputDataId::SEKeyboardState => None,
+            SEOutputDataId::SELeftLowerEyelidExtremePoint => Some(Eye::Left),
+            SEOutputDataId::SELeftUpperEyelidExtremePoint => Some(Eye::Left),
+            SEOutputDataId::SERightLowerEyelidExtremePoint => Some(Eye::Right),
+            SEOutputDataId::SERightUpperEyelidExtremePoint => Some(Eye::Right),
+            SEOutputDataId::SEPupilDiameter => Some(Eye::Combined),
+            SEOutputDataId::SEPupilDiameterQ => Some(Eye::Combined),
+            SEOutputDataId::SELeftPupilDiameter => Some(Eye::Left),
+            SEOutputDataId::SELeftPupilDiameterQ => Some(Eye::Left),
+            SEOutputDataId::SERightPupilDiameter => Some(Eye::Right),
+            SEOutputDataId::SERightPupilDiameterQ => Some(Eye::Right),
+            SEOutputDataId::SEFilteredPupilDiameter => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredPupilDiameterQ => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredLeftPupilDiameter => Some(Eye::Left),
+            SEOutputDataId::SEFilteredLeftPupilDiameterQ => Some(Eye::Left),
+            SEOutputDataId::SEFilteredRightPupilDiameter => Some(Eye::Right),
+            SEOutputDataId::SEFilteredRightPupilDiameterQ => Some(Eye::Right),
+            SEOutputDataId::SEGPSPosition => None,
+            SEOutputDataId::SEGPSGroundSpeed => None,
+            SEOutputDataId::SEGPSCourse => None,
+            SEOutputDataId::SEGPSTime => None,
+            SEOutputDataId::SEEstimatedGazeOrigin => Some(Eye::Combined),
+            SEOutputDataId::SEEstimatedLeftGazeOrigin => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedRightGazeOrigin => Some(Eye::Right),
+            SEOutputDataId::SEEstimatedEyePosition => Some(Eye::Combined),
+            SEOutputDataId::SEEstimatedGazeDirection => Some(Eye::Combined),
+            SEOutputDataId::SEEstimatedGazeDirectionQ => Some(Eye::Combined),
+            SEOutputDataId::SEEstimatedGazeHeading => Some(Eye::Combined),
+            SEOutputDataId::SEEstimatedGazePitch => Some(Eye::Combined),
+            SEOutputDataId::SEEstimatedLeftEyePosition => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedLeftGazeDirection => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedLeftGazeDirectionQ => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedLeftGazeHeading => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedLeftGazePitch => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedRightEyePosition => Some(Eye::Right),
+            SEOutputDataId::SEEstimatedRightGazeDirection => Some(Eye::Right),
+            SEOutputDataId::SEEstimatedRightGazeDirectionQ => Some(Eye::Right),
+            SEOutputDataId::SEEstimatedRightGazeHeading => Some(Eye::Right),
+            SEOutputDataId::SEEstimatedRightGazePitch => Some(Eye::Right),
+            SEOutputDataId::SEFilteredEstimatedGazeDirection => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredEstimatedGazeDirectionQ => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredEstimatedGazeHeading => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredEstimatedGazePitch => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredEstimatedLeftGazeDirection => Some(Eye::Left),
+            SEOutputDataId::SEFilteredEstimatedLeftGazeDirectionQ => Some(Eye::Left),
+            SEOutputDataId::SEFilteredEstimatedLeftGazeHeading => Some(Eye::Left),
+            SEOutputDataId::SEFilteredEstimatedLeftGazePitch => Some(Eye::Left),
+            SEOutputDataId::SEFilteredEstimatedRightGazeDirection => Some(Eye::Right),
+            SEOutputDataId::SEFilteredEstimatedRightGazeDirectionQ => Some(Eye::Right),
+            SEOutputDataId::SEFilteredEstimatedRightGazeHeading => Some(Eye::Right),
+            SEOutputDataId::SEFilteredEstimatedRightGazePitch => Some(Eye::Right),
+            SEOutputDataId::SEASCIIKeyboardState => None,
+            SEOutputDataId::SECalibrationGazeIntersection => Some(Eye::Combined),
+            SEOutputDataId::SETaggedGazeIntersection => Some(Eye::Combined),
+            SEOutputDataId::SELeftClosestWorldIntersection => Some(Eye::Left),
+            SEOutputDataId::SELeftAllWorldIntersections => Some(Eye::Left),
+            SEOutputDataId::SERightClosestWorldIntersection => Some(Eye::Right),
+            SEOutputDataId::SERightAllWorldIntersections => Some(Eye::Right),
+            SEOutputDataId::SEFilteredLeftClosestWorldIntersection => Some(Eye::Left),
+            SEOutputDataId::SEFilteredLeftAllWorldIntersections => Some(Eye::Left),
+            SEOutputDataId::SEFilteredRightClosestWorldIntersection => Some(Eye::Right),
+            SEOutputDataId::SEFilteredRightAllWorldIntersections => Some(Eye::Right),
+            SEOutputDataId::SEEstimatedLeftClosestWorldIntersection => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedLeftAllWorldIntersections => Some(Eye::Left),
+            SEOutputDataId::SEEstimatedRightClosestWorldIntersection => Some(Eye::Right),
+            SEOutputDataId::SEEstimatedRightAllWorldIntersections => Some(Eye::Right),
+            SEOutputDataId::SEFilteredEstimatedClosestWorldIntersection => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredEstimatedAllWorldIntersections => Some(Eye::Combined),
+            SEOutputDataId::SEFilteredEstimatedLeftClosestWorldIntersection => Some(Eye::Left),
+            SEOutputDataId::SEFilteredEstimatedLeftAllWorldIntersections => Some(Eye::Left),
+            SEOutputDataId::SEFilteredEstimatedRightClosestWorldIntersection => Some(Eye::Right),
+            SEOutputDataId::SEFilteredEstimatedRightAllWorldIntersections => Some(Eye::Right),
+            SEOutputDataId::SETrackingState => None,
+            SEOutputDataId::SEEyeglassesStatus => None,
+            SEOutputDataId::SEReflexReductionStateDEPRECATED => None,
+            SEOutputDataId::SELeftBlinkClosingMidTime => Some(Eye::Left),
+            SEOutputDataId::SELeftBlinkOpeningMidTime => Some(Eye::Left),
+            SEOutputDataId::SELeftBlinkClosingAmplitude => Some(Eye::Left),
+            SEOutputDataId::SELeftBlinkOpeningAmplitude => Some(Eye::Left),
+            SEOutputDataId::SELeftBlinkClosingSpeed => Some(Eye::Left),
+            SEOutputDataId::SELeftBlinkOpeningSpeed => Some(Eye::Left),
+            SEOutputDataId::SERightBlinkClosingMidTime => Some(Eye::Right),
+            SEOutputDataId::SERightBlinkOpeningMidTime => Some(Eye::Right),
+            SEOutputDataId::SERightBlinkClosingAmplitude => Some(Eye::Right),
+            SEOutputDataId::SERightBlinkOpeningAmplitude => Some(Eye::Right),
+            SEOutputDataId::SERightBlinkClosingSpeed => Some(Eye::Right),
+            SEOutputDataId::SERightBlinkOpeningSpeed => Some(Eye::Right),
+            SEOutputDataId::SELeftEyelidState => Some(Eye::Left),
+            SEOutputDataId::SERightEyelidState => Some(Eye::Right),
+            SEOutputDataId::SEUserMarker => None,
+            SEOutputDataId::SECameraClocks => None,
+            SEOutputDataId::SEEmotionJoy => None,
+            SEOutputDataId::SEEmotionFear => None,
+            SEOutputDataId::SEEmotionDisgust => None,
+            SEOutputDataId::SEEmotionSadness => None,
+            SEOutputDataId::SEEmotionSurprise => None,
+            SEOutputDataId::SEEmotionValence => None,
+            SEOutputDataId::SEEmotionEngagement => None,
+            SEOutputDataId::SEEmotionSentimentality => None,
+            SEOutputDataId::SEEmotionConfusion => None,
+            SEOutputDataId::SEEmotionNeutral => None,
+            SEOutputDataId::SEEmotionQ => None,
+            SEOutputDataId::SEExpressionSmile => None,
+            SEOutputDataId::SEExpressionInnerBrowRaise => None,
+            SEOutputDataId::SEExpressionBrowRaise => None,
+            SEOutputDataId::SEExpressionBrowFurrow => None,
+            SEOutputDataId::SEExpressionNoseWrinkle => None,
+            SEOutputDataId::SEExpressionUpperLipRaise => None,
+            SEOutputDataId::SEExpressionLipCornerDepressor => None,
+            SEOutputDataId::SEExpressionChinRaise => None,
+            SEOutputDataId::SEExpressionLipPucker => None,
+            SEOutputDataId::SEExpressionLipPress => None,
+            SEOutputDataId::SEExpressionLipSuck => None,
+            SEOutputDataId::SEExpressionMouthOpen => None,
+            SEOutputDataId::SEExpressionSmirk => None,
+            SEOutputDataId::SEExpressionAttention => None,
+            SEOutputDataId::SEExpressionEyeWiden => None,
+            SEOutputDataId::SEExpressionCheekRaise => None,
+            SEOutputDataId::SEExpressionLidTighten => None,
+            SEOutputDataId::SEExpressionDimpler => None,
+            SEOutputDataId::SEExpressionLipStretch => None,
+            SEOutputDataId::SEExpressionJawDrop => None,
+            SEOutputDataId::SEExpressionQ => None,
+        }
+    }
+}
+
+impl SEOutputData {
+    pub fn id(&self) -> SEOutputDataId {
+        match self {
+            SEOutputData::SEFrameNumber(_) => SEOutputDataId::SEFrameNumber,
+            SEOutputData::SEEstimatedDelay(_) => SEOutputDataId::SEEstimatedDelay,
+            SEOutputData::SETimeStamp(_) => SEOutputDataId::SETimeStamp,
+            SEOutputData::SEUserTimeStamp(_) => SEOutputDataId::SEUserTimeStamp,
+            SEOutputData::SEFrameRate(_) => SEOutputDataId::SEFrameRate,
+            SEOutputData::SECameraPositions(_) => SEOutputDataId::SECameraPositions,
+            SEOutputData::SECameraRotations(_) => SEOutputDataId::SECameraRotations,
+            SEOutputData::SEUserDefinedData(_) => SEOutputDataId::SEUserDefinedData,
+            SEOutputData::SERealTimeClock(_) => SEOutputDataId::SERealTimeClock,
+            SEOutputData::SEHeadPosition(_) => SEOutputDataId::SEHeadPosition,
+            SEOutputData::SEHeadPositionQ(_) => SEOutputDataId::SEHeadPositionQ,
+            SEOutputData::SEHeadRotationRodrigues(_) => SEOutputDataId::SEHeadRotationRodrigues,
+            SEOutputData::SEHeadRotationQuaternion(_) => SEOutputDataId::SEHeadRotationQuaternion,
+            SEOutputData::SEHeadLeftEarDirection(_) => SEOutputDataId::SEHeadLeftEarDirection,
+            SEOutputData::SEHeadUpDirection(_) => SEOutputDataId::SEHeadUpDirection,
+            SEOutputData::SEHeadNoseDirection(_) => SEOutputDataId::SEHeadNoseDirection,
+            SEOutputData::SEHeadHeading(_) => SEOutputDataId::SEHeadHeading,
+            SEOutputData::SEHeadPitch(_) => SEOutputDataId::SEHeadPitch,
+            SEOutputData::SEHeadRoll(_) => SEOutputDataId::SEHeadRoll,
+            SEOutputData::SEHeadRotationQ(_) => SEOutputDataId::SEHeadRotationQ,
+            SEOutputData::SEGazeOrigin(_) => SEOutputDataId::SEGazeOrigin,
+            SEOutputData::SELeftGazeOrigin(_) => SEOutputDataId::SELeftGazeOrigin,
+            SEOutputData::SERightGazeOrigin(_) => SEOutputDataId::SERightGazeOrigin,
+            SEOutputData::SEEyePosition(_) => SEOutputDataId::SEEyePosition,
+            SEOutputData::SEGazeDirection(_) => SEOutputDataId::SEGazeDirection,
+            SEOutputData::SEGazeDirectionQ(_) => SEOutputDataId::SEGazeDirectionQ,
+            SEOutputData::SELeftEyePosition(_) => SEOutputDataId::SELeftEyePosition,
+            SEOutputData::SELeftGazeDirection(_) => SEOutputDataId::SELeftGazeDirection,
+            SEOutputData::SELeftGazeDirectionQ(_) => SEOutputDataId::SELeftGazeDirectionQ,
+            SEOutputData::SERightEyePosition(_) => SEOutputDataId::SERightEyePosition,
+            SEOutputData::SERightGazeDirection(_) => SEOutputDataId::SERightGazeDirection,
+            SEOutputData::SERightGazeDirectionQ(_) => SEOutputDataId::SERightGazeDirectionQ,
+            SEOutputData::SEGazeHeading(_) => SEOutputDataId::SEGazeHeading,
+            SEOutputData::SEGazePitch(_) => SEOutputDataId::SEGazePitch,
+            SEOutputData::SELeftGazeHeading(_) => SEOutputDataId::SELeftGazeHeading,
+            SEOutputData::SELeftGazePitch(_) => SEOutputDataId::SELeftGazePitch,
+            SEOutputData::SERightGazeHeading(_) => SEOutputDataId::SERightGazeHeading,
+            SEOutputData::SERightGazePitch(_) => SEOutputDataId::SERightGazePitch,
+            SEOutputData::SEFilteredGazeDirection(_) => SEOutputDataId::SEFilteredGazeDirection,
+            SEOutputData::SEFilteredGazeDirectionQ(_) => SEOutputDataId::SEFilteredGazeDirectionQ,
+            SEOutputData::SEFilteredLeftGazeDirection(_) => {
+                SEOutputDataId::SEFilteredLeftGazeDirection
+            }
+            SEOutputData::SEFilteredLeftGazeDirectionQ(_) => {
+                SEOutputDataId::SEFilteredLeftGazeDirectionQ
+            }
+            SEOutputData::SEFilteredRightGazeDirection(_) => {
+                SEOutputDataId::SEFilteredRightGazeDirection
+            }
+            SEOutputData::SEFilteredRightGazeDirectionQ(_) => {
+                SEOutputDataId::SEFilteredRightGazeDirectionQ
+            }
+            SEOutputData::SEFilteredGazeHeading(_) => SEOutputDataId::SEFilteredGazeHeading,
+            SEOutputData::SEFilteredGazePitch(_) => SEOutputDataId::SEFilteredGazePitch,
+            SEOutputData::SEFilteredLeftGazeHeading(_) => SEOutputDataId::SEFilteredLeftGazeHeading,
+            SEOutputData::SEFilteredLeftGazePitch(_) => SEOutputDataId::SEFilteredLeftGazePitch,
+            SEOutputData::SEFilteredRightGazeHeading(_) => {
+                SEOutputDataId::SEFilteredRightGazeHeading
+            }
+            SEOutputData::SEFilteredRightGazePitch(_) => SEOutputDataId::SEFilteredRightGazePitch,
+            SEOutputData::SESaccade(_) => SEOutputDataId::SESaccade,
+            SEOutputData::SEFixation(_) => SEOutputDataId::SEFixation,
+            SEOutputData::SEBlink(_) => SEOutputDataId::SEBlink,
+            SEOutputData::SEClosestWorldIntersection(_) => {
+                SEOutputDataId::SEClosestWorldIntersection
+            }
+            SEOutputData::SEFilteredClosestWorldIntersection(_) => {
+                SEOutputDataId::SEFilteredClosestWorldIntersection
+            }
+            SEOutputData::SEAllWorldIntersections(_) => SEOutputDataId::SEAllWorldIntersections,
+            SEOutputData::SEFilteredAllWorldIntersections(_) => {
+                SEOutputDataId::SEFilteredAllWorldIntersections
+            }
+            SEOutputData::SEZoneId(_) => SEOutputDataId::SEZoneId,
+            SEOutputData::SEEstimatedClosestWorldIntersection(_) => {
+                SEOutputDataId::SEEstimatedClosestWorldIntersection
+            }
+            SEOutputData::SEEstimatedAllWorldIntersections(_) => {
+                SEOutputDataId::SEEstimatedAllWorldIntersections
+            }
+            SEOutputData::SEHeadClosestWorldIntersection(_) => {
+                SEOutputDataId::SEHeadClosestWorldIntersection
+            }
+            SEOutputData::SEHeadAllWorldIntersections(_) => {
+                SEOutputDataId::SEHeadAllWorldIntersections
+            }
+            SEOutputData::SEEyelidOpening(_) => SEOutputDataId::SEEyelidOpening,
+            SEOutputData::SEEyelidOpeningQ(_) => SEOutputDataId::SEEyelidOpeningQ,
+            SEOutputData::SELeftEyelidOpening(_) => SEOutputDataId::SELeftEyelidOpening,
+            SEOutputData::SELeftEyelidOpeningQ(_) => SEOutputDataId::SELeftEyelidOpeningQ,
+            SEOutputData::SERightEyelidOpening(_) => SEOutputDataId::SERightEyelidOpening,
+            SEOutputData::SERightEyelidOpeningQ(_) => SEOutputDataId::SERightEyelidOpeningQ,
+            SEOutputData::SEKeyboardState(_) => SEOutputDataId::SEKeyboardState,
+            SEOutputData::SELeftLowerEyelidExtremePoint(_) => {
+                SEOutputDataId::SELeftLowerEyelidExtremePoint
+            }
+            SEOutputData::SELeftUpperEyelidExtremePoint(_) => {
+                SEOutputDataId::SELeftUpperEyelidExtremePoint
+            }
+            SEOutputData::SERightLowerEyelidExtremePoint(_) => {
+                SEOutputDataId::SERightLowerEyelidExtremePoint
+            }
+            SEOutputData::SERightUpperEyelidExtremePoint(_) => {
+                SEOutputDataId::SERightUpperEyelidExtremePoint
+            }
+            SEOutputData::SEPupilDiameter(_) => SEOutputDataId::SEPupilDiameter,
+            SEOutputData::SEPupilDiameterQ(_) => SEOutputDataId::SEPupilDiameterQ,
+            SEOutputData::SELeftPupilDiameter(_) => SEOutputDataId::SELeftPupilDiameter,
+            SEOutputData::SELeftPupilDiameterQ(_) => SEOutputDataId::SELeftPupilDiameterQ,
+            SEOutputData::SERightPupilDiameter(_) => SEOutputDataId::SERightPupilDiameter,
+            SEOutputData::SERightPupilDiameterQ(_) => SEOutputDataId::SERightPupilDiameterQ,
+            SEOutputData::SEFilteredPupilDiameter(_) => SEOutputDataId::SEFilteredPupilDiameter,
+            SEOutputData::SEFilteredPupilDiameterQ(_) => SEOutputDataId::SEFilteredPupilDiameterQ,
+            SEOutputData::SEFilteredLeftPupilDiameter(_) => {
+                SEOutputDataId::SEFilteredLeftPupilDiameter
+            }
+            SEOutputData::SEFilteredLeftPupilDiameterQ(_) => {
+                SEOutputDataId::SEFilteredLeftPupilDiameterQ
+            }
+            SEOutputData::SEFilteredRightPupilDiameter(_) => {
+                SEOutputDataId::SEFilteredRightPupilDiameter
+            }
+            SEOutputData::SEFilteredRightPupilDiameterQ(_) => {
+                SEOutputDataId::SEFilteredRightPupilDiameterQ
+            }
+            SEOutputData::SEGPSPosition(_) => SEOutputDataId::SEGPSPosition,
+            SEOutputData::SEGPSGroundSpeed(_) => SEOutputDataId::SEGPSGroundSpeed,
+            SEOutputData::SEGPSCourse(_) => SEOutputDataId::SEGPSCourse,
+            SEOutputData::SEGPSTime(_) => SEOutputDataId::SEGPSTime,
+            SEOutputData::SEEstimatedGazeOrigin(_) => SEOutputDataId::SEEstimatedGazeOrigin,
+            SEOutputData::SEEstimatedLeftGazeOrigin(_) => SEOutputDataId::SEEstimatedLeftGazeOrigin,
+            SEOutputData::SEEstimatedRightGazeOrigin(_) => {
+                SEOutputDataId::SEEstimatedRightGazeOrigin
+            }
+            SEOutputData::SEEstimatedEyePosition(_) => SEOutputDataId::SEEstimatedEyePosition,
+            SEOutputData::SEEstimatedGazeDirection(_) => SEOutputDataId::SEEstimatedGazeDirection,
+            SEOutputData::SEEstimatedGazeDirectionQ(_) => SEOutputDataId::SEEstimatedGazeDirectionQ,
+            SEOutputData::SEEstimatedGazeHeading(_) => SEOutputDataId::SEEstimatedGazeHeading,
+            SEOutputData::SEEstimatedGazePitch(_) => SEOutputDataId::SEEstimatedGazePitch,
+            SEOutputData::SEEstimatedLeftEyePosition(_) => {
+                SEOutputDataId::SEEstimatedLeftEyePosition
+            }
+            SEOutputData::SEEstimatedLeftGazeDirection(_) => {
+                SEOutputDataId::SEEstimatedLeftGazeDirection
+            }
+            SEOutputData::SEEstimatedLeftGazeDirectionQ(_) => {
+                SEOutputDataId::SEEstimatedLeftGazeDirectionQ
+            }
+            SEOutputData::SEEstimatedLeftGazeHeading(_) => {
+                SEOutputDataId::SEEstimatedLeftGazeHeading
+            }
+            SEOutputData::SEEstimatedLeftGazePitch(_) => SEOutputDataId::SEEstimatedLeftGazePitch,
+            SEOutputData::SEEstimatedRightEyePosition(_) => {
+                SEOutputDataId::SEEstimatedRightEyePosition
+            }
+            SEOutputData::SEEstimatedRightGazeDirection(_) => {
+                SEOutputDataId::SEEstimatedRightGazeDirection
+            }
+            SEOutputData::SEEstimatedRightGazeDirectionQ(_) => {
+                SEOutputDataId::SEEstimatedRightGazeDirectionQ
+            }
+            SEOutputData::SEEstimatedRightGazeHeading(_) => {
+                SEOutputDataId::SEEstimatedRightGazeHeading
+            }
+            SEOutputData::SEEstimatedRightGazePitch(_) => SEOutputDataId::SEEstimatedRightGazePitch,
+            SEOutputData::SEFilteredEstimatedGazeDirection(_) => {
+                SEOutputDataId::SEFilteredEstimatedGazeDirection
+            }
+            SEOutputData::SEFilteredEstimatedGazeDirectionQ(_) => {
+                SEOutputDataId::SEFilteredEstimatedGazeDirectionQ
+            }
+            SEOutputData::SEFilteredEstimatedGazeHeading(_) => {
+                SEOutputDataId::SEFilteredEstimatedGazeHeading
+            }
+            SEOutputData::SEFilteredEstimatedGazePitch(_) => {
+                SEOutputDataId::SEFilteredEstimatedGazePitch
+            }
+            SEOutputData::SEFilteredEstimatedLeftGazeDirection(_) => {
+                SEOutputDataId::SEFilteredEstimatedLeftGazeDirection
+            }
+            SEOutputData::SEFilteredEstimatedLeftGazeDirectionQ(_) => {
+                SEOutputDataId::SEFilteredEstimatedLeftGazeDirectionQ
+            }
+            SEOutputData::SEFilteredEstimatedLeftGazeHeading(_) => {
+                SEOutputDataId::SEFilteredEstimatedLeftGazeHeading
+            }
+            SEOutputData::SEFilteredEstimatedLeftGazePitch(_) => {
+                SEOutputDataId::SEFilteredEstimatedLeftGazePitch
+            }
+            SEOutputData::SEFilteredEstimatedRightGazeDirection(_) => {
+                SEOutputDataId::SEFilteredEstimatedRightGazeDirection
+            }
+            SEOutputData::SEFilteredEstimatedRightGazeDirectionQ(_) => {
+                SEOutputDataId::SEFilteredEstimatedRightGazeDirectionQ
+            }
+            SEOutputData::SEFilteredEstimatedRightGazeHeading(_) => {
+                SEOutputDataId::SEFilteredEstimatedRightGazeHeading
+            }
+            SEOutputData::SEFilteredEstimatedRightGazePitch(_) => {
+                SEOutputDataId::SEFilteredEstimatedRightGazePitch
+            }
+            SEOutputData::SEASCIIKeyboardState(_) => SEOutputDataId::SEASCIIKeyboardState,
+            SEOutputData::SECalibrationGazeIntersection(_) => {
+                SEOutputDataId::SECalibrationGazeIntersection
+            }
+            SEOutputData::SETaggedGazeIntersection(_) => SEOutputDataId::SETaggedGazeIntersection,
+            SEOutputData::SELeftClosestWorldIntersection(_) => {
+                SEOutputDataId::SELeftClosestWorldIntersection
+            }
+            SEOutputData::SELeftAllWorldIntersections(_) => {
+                SEOutputDataId::SELeftAllWorldIntersections
+            }
+            SEOutputData::SERightClosestWorldIntersection(_) => {
+                SEOutputDataId::SERightClosestWorldIntersection
+            }
+            SEOutputData::SERightAllWorldIntersections(_) => {
+                SEOutputDataId::SERightAllWorldIntersections
+            }
+            SEOutputData::SEFilteredLeftClosestWorldIntersection(_) => {
+                SEOutputDataId::SEFilteredLeftClosestWorldIntersection
+            }
+            SEOutputData::SEFilteredLeftAllWorldIntersections(_) => {
+                SEOutputDataId::SEFilteredLeftAllWorldIntersections
+            }
+            SEOutputData::SEFilteredRightClosestWorldIntersection(_) => {
+                SEOutputDataId::SEFilteredRightClosestWorldIntersection
+            }
+            SEOutputData::SEFilteredRightAllWorldIntersections(_) => {
+                SEOutputDataId::SEFilteredRightAllWorldIntersections
+            }
+            SEOutputData::SEEstimatedLeftClosestWorldIntersection(_) => {
+                SEOutputDataId::SEEstimatedLeftClosestWorldIntersection
+            }
+            SEOutputData::SEEstimatedLeftAllWorldIntersections(_) => {
+                SEOutputDataId::SEEstimatedLeftAllWorldIntersections
+            }
+            SEOutputData::SEEstimatedRightClosestWorldIntersection(_) => {
+                SEOutputDataId::SEEstimatedRightClosestWorldIntersection
+            }
+            SEOutputData::SEEstimatedRightAllWorldIntersections(_) => {
+                SEOutputDataId::SEEstimatedRightAllWorldIntersections
+            }
+            SEOutputData::SEFilteredEstimatedClosestWorldIntersection(_) => {
+                SEOutputDataId::SEFilteredEstimatedClosestWorldIntersection
+            }
+            SEOutputData::SEFilteredEstimatedAllWorldIntersections(_) => {
+                SEOutputDataId::SEFilteredEstimatedAllWorldIntersections
+            }
+            SEOutputData::SEFilteredEstimatedLeftClosestWorldIntersection(_) => {
+                SEOutputDataId::SEFilteredEstimatedLeftClosestWorldIntersection
+            }
+            SEOutputData::SEFilteredEstimatedLeftAllWorldIntersections(_) => {
+                SEOutputDataId::SEFilteredEstimatedLeftAllWorldIntersections
+            }
+            SEOutputData::SEFilteredEstimatedRightClosestWorldIntersection(_) => {
+                SEOutputDataId::SEFilteredEstimatedRightClosestWorldIntersection
+            }
+            SEOutputData::SEFilteredEstimatedRightAllWorldIntersections(_) => {
+                SEOutputDataId::SEFilteredEstimatedRightAllWorldIntersections
+            }
+            SEOutputData::SETrackingState(_) => SEOutputDataId::SETrackingState,
+            SEOutputData::SEEyeglassesStatus(_) => SEOutputDataId::SEEyeglassesStatus,
+            SEOutputData::SEReflexReductionStateDEPRECATED(_) => {
+                SEOutputDataId::SEReflexReductionStateDEPRECATED
+            }
+            SEOutputData::SELeftBlinkClosingMidTime(_) => SEOutputDataId::SELeftBlinkClosingMidTime,
+            SEOutputData::SELeftBlinkOpeningMidTime(_) => SEOutputDataId::SELeftBlinkOpeningMidTime,
+            SEOutputData::SELeftBlinkClosingAmplitude(_) => {
+                SEOutputDataId::SELeftBlinkClosingAmplitude
+            }
+            SEOutputData::SELeftBlinkOpeningAmplitude(_) => {
+                SEOutputDataId::SELeftBlinkOpeningAmplitude
+            }
+            SEOutputData::SELeftBlinkClosingSpeed(_) => SEOutputDataId::SELeftBlinkClosingSpeed,
+            SEOutputData::SELeftBlinkOpeningSpeed(_) => SEOutputDataId::SELeftBlinkOpeningSpeed,
+            SEOutputData::SERightBlinkClosingMidTime(_) => {
+                SEOutputDataId::SERightBlinkClosingMidTime
+            }
+            SEOutputData::SERightBlinkOpeningMidTime(_) => {
+                SEOutputDataId::SERightBlinkOpeningMidTime
+            }
+            SEOutputData::SERightBlinkClosingAmplitude(_) => {
+                SEOutputDataId::SERightBlinkClosingAmplitude
+            }
+            SEOutputData::SERightBlinkOpeningAmplitude(_) => {
+                SEOutputDataId::SERightBlinkOpeningAmplitude
+            }
+            SEOutputData::SERightBlinkClosingSpeed(_) => SEOutputDataId::SERightBlinkClosingSpeed,
+            SEOutputData::SERightBlinkOpeningSpeed(_) => SEOutputDataId::SERightBlinkOpeningSpeed,
+            SEOutputData::SELeftEyelidState(_) => SEOutputDataId::SELeftEyelidState,
+            SEOutputData::SERightEyelidState(_) => SEOutputDataId::SERightEyelidState,
+            SEOutputData::SEUserMarker(_) => SEOutputDataId::SEUserMarker,
+            SEOutputData::SECameraClocks(_) => SEOutputDataId::SECameraClocks,
+            SEOutputData::SEEmotionJoy(_) => SEOutputDataId::SEEmotionJoy,
+            SEOutputData::SEEmotionFear(_) => SEOutputDataId::SEEmotionFear,
+            SEOutputData::SEEmotionDisgust(_) => SEOutputDataId::SEEmotionDisgust,
+            SEOutputData::SEEmotionSadness(_) => SEOutputDataId::SEEmotionSadness,
+            SEOutputData::SEEmotionSurprise(_) => SEOutputDataId::SEEmotionSurprise,
+            SEOutputData::SEEmotionValence(_) => SEOutputDataId::SEEmotionValence,
+            SEOutputData::SEEmotionEngagement(_) => SEOutputDataId::SEEmotionEngagement,
+            SEOutputData::SEEmotionSentimentality(_) => SEOutputDataId::SEEmotionSentimentality,
+            SEOutputData::SEEmotionConfusion(_) => SEOutputDataId::SEEmotionConfusion,
+            SEOutputData::SEEmotionNeutral(_) => SEOutputDataId::SEEmotionNeutral,
+            SEOutputData::SEEmotionQ(_) => SEOutputDataId::SEEmotionQ,
+            SEOutputData::SEExpressionSmile(_) => SEOutputDataId::SEExpressionSmile,
+            SEOutputData::SEExpressionInnerBrowRaise(_) => {
+                SEOutputDataId::SEExpressionInnerBrowRaise
+            }
+            SEOutputData::SEExpressionBrowRaise(_) => SEOutputDataId::SEExpressionBrowRaise,
+            SEOutputData::SEExpressionBrowFurrow(_) => SEOutputDataId::SEExpressionBrowFurrow,
+            SEOutputData::SEExpressionNoseWrinkle(_) => SEOutputDataId::SEExpressionNoseWrinkle,
+            SEOutputData::SEExpressionUpperLipRaise(_) => SEOutputDataId::SEExpressionUpperLipRaise,
+            SEOutputData::SEExpressionLipCornerDepressor(_) => {
+                SEOutputDataId::SEExpressionLipCornerDepressor
+            }
+            SEOutputData::SEExpressionChinRaise(_) => SEOutputDataId::SEExpressionChinRaise,
+            SEOutputData::SEExpressionLipPucker(_) => SEOutputDataId::SEExpressionLipPucker,
+            SEOutputData::SEExpressionLipPress(_) => SEOutputDataId::SEExpressionLipPress,
+            SEOutputData::SEExpressionLipSuck(_) => SEOutputDataId::SEExpressionLipSuck,
+            SEOutputData::SEExpressionMouthOpen(_) => SEOutputDataId::SEExpressionMouthOpen,
+            SEOutputData::SEExpressionSmirk(_) => SEOutputDataId::SEExpressionSmirk,
+            SEOutputData::SEExpressionAttention(_) => SEOutputDataId::SEExpressionAttention,
+            SEOutputData::SEExpressionEyeWiden(_) => SEOutputDataId::SEExpressionEyeWiden,
+            SEOutputData::SEExpressionCheekRaise(_) => SEOutputDataId::SEExpressionCheekRaise,
+            SEOutputData::SEExpressionLidTighten(_) => SEOutputDataId::SEExpressionLidTighten,
+            SEOutputData::SEExpressionDimpler(_) => SEOutputDataId::SEExpressionDimpler,
+            SEOutputData::SEExpressionLipStretch(_) => SEOutputDataId::SEExpressionLipStretch,
+            SEOutputData::SEExpressionJawDrop(_) => SEOutputDataId::SEExpressionJawDrop,
+            SEOutputData::SEExpressionQ(_) => SEOutputDataId::SEExpressionQ,
+        }
+    }
+
+    // Output data with the given id and value, None if the type of the value
+    // does not match the id.
+    pub(crate) fn from_variant(id: SEOutputDataId, value: SEVariant) -> Option<Self> {
+        match (id, value) {
+            (SEOutputDataId::SEFrameNumber, SEVariant::U32(v)) => {
+                Some(SEOutputData::SEFrameNumber(v))
+            }
+            (SEOutputDataId::SEEstimatedDelay, SEVariant::U32(v)) => {
+                Some(SEOutputData::SEEstimatedDelay(v))
+            }
+            (SEOutputDataId::SETimeStamp, SEVariant::U64(v)) => Some(SEOutputData::SETimeStamp(v)),
+            (SEOutputDataId::SEUserTimeStamp, SEVariant::U64(v)) => {
+                Some(SEOutputData::SEUserTimeStamp(v))
+            }
+            (SEOutputDataId::SEFrameRate, SEVariant::F64(v)) => Some(SEOutputData::SEFrameRate(v)),
+            (SEOutputDataId::SECameraPositions, SEVariant::Vector(v)) => {
+                Some(SEOutputData::SECameraPositions(v))
+            }
+            (SEOutputDataId::SECameraRotations, SEVariant::Vector(v)) => {
+                Some(SEOutputData::SECameraRotations(v))
+            }
+            (SEOutputDataId::SEUserDefinedData, SEVariant::U64(v)) => {
+                Some(SEOutputData::SEUserDefinedData(v))
+            }
+            (SEOutputDataId::SERealTimeClock, SEVariant::U64(v)) => {
+                Some(SEOutputData::SERealTimeClock(v))
+            }
+            (SEOutputDataId::SEHeadPosition, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEHeadPosition(v))
+            }
+            (SEOutputDataId::SEHeadPositionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEHeadPositionQ(v))
+            }
+            (SEOutputDataId::SEHeadRotationRodrigues, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEHeadRotationRodrigues(v))
+            }
+            (SEOutputDataId::SEHeadRotationQuaternion, SEVariant::Quaternion(v)) => {
+                Some(SEOutputData::SEHeadRotationQuaternion(v))
+            }
+            (SEOutputDataId::SEHeadLeftEarDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEHeadLeftEarDirection(v))
+            }
+            (SEOutputDataId::SEHeadUpDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEHeadUpDirection(v))
+            }
+            (SEOutputDataId::SEHeadNoseDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEHeadNoseDirection(v))
+            }
+            (SEOutputDataId::SEHeadHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEHeadHeading(v))
+            }
+            (SEOutputDataId::SEHeadPitch, SEVariant::F64(v)) => Some(SEOutputData::SEHeadPitch(v)),
+            (SEOutputDataId::SEHeadRoll, SEVariant::F64(v)) => Some(SEOutputData::SEHeadRoll(v)),
+            (SEOutputDataId::SEHeadRotationQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEHeadRotationQ(v))
+            }
+            (SEOutputDataId::SEGazeOrigin, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEGazeOrigin(v))
+            }
+            (SEOutputDataId::SELeftGazeOrigin, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SELeftGazeOrigin(v))
+            }
+            (SEOutputDataId::SERightGazeOrigin, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SERightGazeOrigin(v))
+            }
+            (SEOutputDataId::SEEyePosition, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEEyePosition(v))
+            }
+            (SEOutputDataId::SEGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEGazeDirection(v))
+            }
+            (SEOutputDataId::SEGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SELeftEyePosition, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SELeftEyePosition(v))
+            }
+            (SEOutputDataId::SELeftGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SELeftGazeDirection(v))
+            }
+            (SEOutputDataId::SELeftGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SERightEyePosition, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SERightEyePosition(v))
+            }
+            (SEOutputDataId::SERightGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SERightGazeDirection(v))
+            }
+            (SEOutputDataId::SERightGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEGazeHeading(v))
+            }
+            (SEOutputDataId::SEGazePitch, SEVariant::F64(v)) => Some(SEOutputData::SEGazePitch(v)),
+            (SEOutputDataId::SELeftGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftGazeHeading(v))
+            }
+            (SEOutputDataId::SELeftGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftGazePitch(v))
+            }
+            (SEOutputDataId::SERightGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightGazeHeading(v))
+            }
+            (SEOutputDataId::SERightGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightGazePitch(v))
+            }
+            (SEOutputDataId::SEFilteredGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEFilteredGazeDirection(v))
+            }
+            (SEOutputDataId::SEFilteredGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEFilteredLeftGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEFilteredLeftGazeDirection(v))
+            }
+            (SEOutputDataId::SEFilteredLeftGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredLeftGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEFilteredRightGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEFilteredRightGazeDirection(v))
+            }
+            (SEOutputDataId::SEFilteredRightGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredRightGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEFilteredGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredGazeHeading(v))
+            }
+            (SEOutputDataId::SEFilteredGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredGazePitch(v))
+            }
+            (SEOutputDataId::SEFilteredLeftGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredLeftGazeHeading(v))
+            }
+            (SEOutputDataId::SEFilteredLeftGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredLeftGazePitch(v))
+            }
+            (SEOutputDataId::SEFilteredRightGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredRightGazeHeading(v))
+            }
+            (SEOutputDataId::SEFilteredRightGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredRightGazePitch(v))
+            }
+            (SEOutputDataId::SESaccade, SEVariant::U32(v)) => Some(SEOutputData::SESaccade(v)),
+            (SEOutputDataId::SEFixation, SEVariant::U32(v)) => Some(SEOutputData::SEFixation(v)),
+            (SEOutputDataId::SEBlink, SEVariant::U32(v)) => Some(SEOutputData::SEBlink(v)),
+            (SEOutputDataId::SEClosestWorldIntersection, SEVariant::WorldIntersection(v)) => {
+                Some(SEOutputData::SEClosestWorldIntersection(v))
+            }
+            (
+                SEOutputDataId::SEFilteredClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEFilteredClosestWorldIntersection(v)),
+            (SEOutputDataId::SEAllWorldIntersections, SEVariant::WorldIntersections(v)) => {
+                Some(SEOutputData::SEAllWorldIntersections(v))
+            }
+            (SEOutputDataId::SEFilteredAllWorldIntersections, SEVariant::WorldIntersections(v)) => {
+                Some(SEOutputData::SEFilteredAllWorldIntersections(v))
+            }
+            (SEOutputDataId::SEZoneId, SEVariant::U16(v)) => Some(SEOutputData::SEZoneId(v)),
+            (
+                SEOutputDataId::SEEstimatedClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEEstimatedClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEEstimatedAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEEstimatedAllWorldIntersections(v)),
+            (SEOutputDataId::SEHeadClosestWorldIntersection, SEVariant::WorldIntersection(v)) => {
+                Some(SEOutputData::SEHeadClosestWorldIntersection(v))
+            }
+            (SEOutputDataId::SEHeadAllWorldIntersections, SEVariant::WorldIntersections(v)) => {
+                Some(SEOutputData::SEHeadAllWorldIntersections(v))
+            }
+            (SEOutputDataId::SEEyelidOpening, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEyelidOpening(v))
+            }
+            (SEOutputDataId::SEEyelidOpeningQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEyelidOpeningQ(v))
+            }
+            (SEOutputDataId::SELeftEyelidOpening, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftEyelidOpening(v))
+            }
+            (SEOutputDataId::SELeftEyelidOpeningQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftEyelidOpeningQ(v))
+            }
+            (SEOutputDataId::SERightEyelidOpening, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightEyelidOpening(v))
+            }
+            (SEOutputDataId::SERightEyelidOpeningQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightEyelidOpeningQ(v))
+            }
+            (SEOutputDataId::SEKeyboardState, SEVariant::String(v)) => {
+                Some(SEOutputData::SEKeyboardState(v))
+            }
+            (SEOutputDataId::SELeftLowerEyelidExtremePoint, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SELeftLowerEyelidExtremePoint(v))
+            }
+            (SEOutputDataId::SELeftUpperEyelidExtremePoint, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SELeftUpperEyelidExtremePoint(v))
+            }
+            (SEOutputDataId::SERightLowerEyelidExtremePoint, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SERightLowerEyelidExtremePoint(v))
+            }
+            (SEOutputDataId::SERightUpperEyelidExtremePoint, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SERightUpperEyelidExtremePoint(v))
+            }
+            (SEOutputDataId::SEPupilDiameter, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEPupilDiameter(v))
+            }
+            (SEOutputDataId::SEPupilDiameterQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEPupilDiameterQ(v))
+            }
+            (SEOutputDataId::SELeftPupilDiameter, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftPupilDiameter(v))
+            }
+            (SEOutputDataId::SELeftPupilDiameterQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftPupilDiameterQ(v))
+            }
+            (SEOutputDataId::SERightPupilDiameter, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightPupilDiameter(v))
+            }
+            (SEOutputDataId::SERightPupilDiameterQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightPupilDiameterQ(v))
+            }
+            (SEOutputDataId::SEFilteredPupilDiameter, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredPupilDiameter(v))
+            }
+            (SEOutputDataId::SEFilteredPupilDiameterQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredPupilDiameterQ(v))
+            }
+            (SEOutputDataId::SEFilteredLeftPupilDiameter, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredLeftPupilDiameter(v))
+            }
+            (SEOutputDataId::SEFilteredLeftPupilDiameterQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredLeftPupilDiameterQ(v))
+            }
+            (SEOutputDataId::SEFilteredRightPupilDiameter, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredRightPupilDiameter(v))
+            }
+            (SEOutputDataId::SEFilteredRightPupilDiameterQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredRightPupilDiameterQ(v))
+            }
+            (SEOutputDataId::SEGPSPosition, SEVariant::Point2D(v)) => {
+                Some(SEOutputData::SEGPSPosition(v))
+            }
+            (SEOutputDataId::SEGPSGroundSpeed, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEGPSGroundSpeed(v))
+            }
+            (SEOutputDataId::SEGPSCourse, SEVariant::F64(v)) => Some(SEOutputData::SEGPSCourse(v)),
+            (SEOutputDataId::SEGPSTime, SEVariant::U64(v)) => Some(SEOutputData::SEGPSTime(v)),
+            (SEOutputDataId::SEEstimatedGazeOrigin, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEEstimatedGazeOrigin(v))
+            }
+            (SEOutputDataId::SEEstimatedLeftGazeOrigin, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEEstimatedLeftGazeOrigin(v))
+            }
+            (SEOutputDataId::SEEstimatedRightGazeOrigin, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEEstimatedRightGazeOrigin(v))
+            }
+            (SEOutputDataId::SEEstimatedEyePosition, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEEstimatedEyePosition(v))
+            }
+            (SEOutputDataId::SEEstimatedGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEEstimatedGazeDirection(v))
+            }
+            (SEOutputDataId::SEEstimatedGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEEstimatedGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedGazeHeading(v))
+            }
+            (SEOutputDataId::SEEstimatedGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedGazePitch(v))
+            }
+            (SEOutputDataId::SEEstimatedLeftEyePosition, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEEstimatedLeftEyePosition(v))
+            }
+            (SEOutputDataId::SEEstimatedLeftGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEEstimatedLeftGazeDirection(v))
+            }
+            (SEOutputDataId::SEEstimatedLeftGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedLeftGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEEstimatedLeftGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedLeftGazeHeading(v))
+            }
+            (SEOutputDataId::SEEstimatedLeftGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedLeftGazePitch(v))
+            }
+            (SEOutputDataId::SEEstimatedRightEyePosition, SEVariant::Point3D(v)) => {
+                Some(SEOutputData::SEEstimatedRightEyePosition(v))
+            }
+            (SEOutputDataId::SEEstimatedRightGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEEstimatedRightGazeDirection(v))
+            }
+            (SEOutputDataId::SEEstimatedRightGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedRightGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEEstimatedRightGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedRightGazeHeading(v))
+            }
+            (SEOutputDataId::SEEstimatedRightGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEstimatedRightGazePitch(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedGazeDirection(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedGazeHeading(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedGazePitch(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedLeftGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedLeftGazeDirection(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedLeftGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedLeftGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedLeftGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedLeftGazeHeading(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedLeftGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedLeftGazePitch(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedRightGazeDirection, SEVariant::Vect3D(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedRightGazeDirection(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedRightGazeDirectionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedRightGazeDirectionQ(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedRightGazeHeading, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedRightGazeHeading(v))
+            }
+            (SEOutputDataId::SEFilteredEstimatedRightGazePitch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEFilteredEstimatedRightGazePitch(v))
+            }
+            (SEOutputDataId::SEASCIIKeyboardState, SEVariant::U16(v)) => {
+                Some(SEOutputData::SEASCIIKeyboardState(v))
+            }
+            (SEOutputDataId::SECalibrationGazeIntersection, SEVariant::WorldIntersection(v)) => {
+                Some(SEOutputData::SECalibrationGazeIntersection(v))
+            }
+            (SEOutputDataId::SETaggedGazeIntersection, SEVariant::WorldIntersection(v)) => {
+                Some(SEOutputData::SETaggedGazeIntersection(v))
+            }
+            (SEOutputDataId::SELeftClosestWorldIntersection, SEVariant::WorldIntersection(v)) => {
+                Some(SEOutputData::SELeftClosestWorldIntersection(v))
+            }
+            (SEOutputDataId::SELeftAllWorldIntersections, SEVariant::WorldIntersections(v)) => {
+                Some(SEOutputData::SELeftAllWorldIntersections(v))
+            }
+            (SEOutputDataId::SERightClosestWorldIntersection, SEVariant::WorldIntersection(v)) => {
+                Some(SEOutputData::SERightClosestWorldIntersection(v))
+            }
+            (SEOutputDataId::SERightAllWorldIntersections, SEVariant::WorldIntersections(v)) => {
+                Some(SEOutputData::SERightAllWorldIntersections(v))
+            }
+            (
+                SEOutputDataId::SEFilteredLeftClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEFilteredLeftClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEFilteredLeftAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEFilteredLeftAllWorldIntersections(v)),
+            (
+                SEOutputDataId::SEFilteredRightClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEFilteredRightClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEFilteredRightAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEFilteredRightAllWorldIntersections(v)),
+            (
+                SEOutputDataId::SEEstimatedLeftClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEEstimatedLeftClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEEstimatedLeftAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEEstimatedLeftAllWorldIntersections(v)),
+            (
+                SEOutputDataId::SEEstimatedRightClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEEstimatedRightClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEEstimatedRightAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEEstimatedRightAllWorldIntersections(v)),
+            (
+                SEOutputDataId::SEFilteredEstimatedClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEFilteredEstimatedClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEFilteredEstimatedAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEFilteredEstimatedAllWorldIntersections(v)),
+            (
+                SEOutputDataId::SEFilteredEstimatedLeftClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEFilteredEstimatedLeftClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEFilteredEstimatedLeftAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEFilteredEstimatedLeftAllWorldIntersections(
+                v,
+            )),
+            (
+                SEOutputDataId::SEFilteredEstimatedRightClosestWorldIntersection,
+                SEVariant::WorldIntersection(v),
+            ) => Some(SEOutputData::SEFilteredEstimatedRightClosestWorldIntersection(v)),
+            (
+                SEOutputDataId::SEFilteredEstimatedRightAllWorldIntersections,
+                SEVariant::WorldIntersections(v),
+            ) => Some(SEOutputData::SEFilteredEstimatedRightAllWorldIntersections(
+                v,
+            )),
+            (SEOutputDataId::SETrackingState, SEVariant::U8(v)) => {
+                Some(SEOutputData::SETrackingState(v))
+            }
+            (SEOutputDataId::SEEyeglassesStatus, SEVariant::U8(v)) => {
+                Some(SEOutputData::SEEyeglassesStatus(v))
+            }
+            (SEOutputDataId::SEReflexReductionStateDEPRECATED, SEVariant::U8(v)) => {
+                Some(SEOutputData::SEReflexReductionStateDEPRECATED(v))
+            }
+            (SEOutputDataId::SELeftBlinkClosingMidTime, SEVariant::U64(v)) => {
+                Some(SEOutputData::SELeftBlinkClosingMidTime(v))
+            }
+            (SEOutputDataId::SELeftBlinkOpeningMidTime, SEVariant::U64(v)) => {
+                Some(SEOutputData::SELeftBlinkOpeningMidTime(v))
+            }
+            (SEOutputDataId::SELeftBlinkClosingAmplitude, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftBlinkClosingAmplitude(v))
+            }
+            (SEOutputDataId::SELeftBlinkOpeningAmplitude, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftBlinkOpeningAmplitude(v))
+            }
+            (SEOutputDataId::SELeftBlinkClosingSpeed, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftBlinkClosingSpeed(v))
+            }
+            (SEOutputDataId::SELeftBlinkOpeningSpeed, SEVariant::F64(v)) => {
+                Some(SEOutputData::SELeftBlinkOpeningSpeed(v))
+            }
+            (SEOutputDataId::SERightBlinkClosingMidTime, SEVariant::U64(v)) => {
+                Some(SEOutputData::SERightBlinkClosingMidTime(v))
+            }
+            (SEOutputDataId::SERightBlinkOpeningMidTime, SEVariant::U64(v)) => {
+                Some(SEOutputData::SERightBlinkOpeningMidTime(v))
+            }
+            (SEOutputDataId::SERightBlinkClosingAmplitude, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightBlinkClosingAmplitude(v))
+            }
+            (SEOutputDataId::SERightBlinkOpeningAmplitude, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightBlinkOpeningAmplitude(v))
+            }
+            (SEOutputDataId::SERightBlinkClosingSpeed, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightBlinkClosingSpeed(v))
+            }
+            (SEOutputDataId::SERightBlinkOpeningSpeed, SEVariant::F64(v)) => {
+                Some(SEOutputData::SERightBlinkOpeningSpeed(v))
+            }
+            (SEOutputDataId::SELeftEyelidState, SEVariant::U8(v)) => {
+                Some(SEOutputData::SELeftEyelidState(v))
+            }
+            (SEOutputDataId::SERightEyelidState, SEVariant::U8(v)) => {
+                Some(SEOutputData::SERightEyelidState(v))
+            }
+            (SEOutputDataId::SEUserMarker, SEVariant::UserMarker(v)) => {
+                Some(SEOutputData::SEUserMarker(v))
+            }
+            (SEOutputDataId::SECameraClocks, SEVariant::Vector(v)) => {
+                Some(SEOutputData::SECameraClocks(v))
+            }
+            (SEOutputDataId::SEEmotionJoy, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionJoy(v))
+            }
+            (SEOutputDataId::SEEmotionFear, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionFear(v))
+            }
+            (SEOutputDataId::SEEmotionDisgust, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionDisgust(v))
+            }
+            (SEOutputDataId::SEEmotionSadness, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionSadness(v))
+            }
+            (SEOutputDataId::SEEmotionSurprise, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionSurprise(v))
+            }
+            (SEOutputDataId::SEEmotionValence, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionValence(v))
+            }
+            (SEOutputDataId::SEEmotionEngagement, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionEngagement(v))
+            }
+            (SEOutputDataId::SEEmotionSentimentality, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionSentimentality(v))
+            }
+            (SEOutputDataId::SEEmotionConfusion, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionConfusion(v))
+            }
+            (SEOutputDataId::SEEmotionNeutral, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEEmotionNeutral(v))
+            }
+            (SEOutputDataId::SEEmotionQ, SEVariant::F64(v)) => Some(SEOutputData::SEEmotionQ(v)),
+            (SEOutputDataId::SEExpressionSmile, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionSmile(v))
+            }
+            (SEOutputDataId::SEExpressionInnerBrowRaise, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionInnerBrowRaise(v))
+            }
+            (SEOutputDataId::SEExpressionBrowRaise, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionBrowRaise(v))
+            }
+            (SEOutputDataId::SEExpressionBrowFurrow, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionBrowFurrow(v))
+            }
+            (SEOutputDataId::SEExpressionNoseWrinkle, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionNoseWrinkle(v))
+            }
+            (SEOutputDataId::SEExpressionUpperLipRaise, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionUpperLipRaise(v))
+            }
+            (SEOutputDataId::SEExpressionLipCornerDepressor, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionLipCornerDepressor(v))
+            }
+            (SEOutputDataId::SEExpressionChinRaise, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionChinRaise(v))
+            }
+            (SEOutputDataId::SEExpressionLipPucker, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionLipPucker(v))
+            }
+            (SEOutputDataId::SEExpressionLipPress, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionLipPress(v))
+            }
+            (SEOutputDataId::SEExpressionLipSuck, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionLipSuck(v))
+            }
+            (SEOutputDataId::SEExpressionMouthOpen, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionMouthOpen(v))
+            }
+            (SEOutputDataId::SEExpressionSmirk, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionSmirk(v))
+            }
+            (SEOutputDataId::SEExpressionAttention, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionAttention(v))
+            }
+            (SEOutputDataId::SEExpressionEyeWiden, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionEyeWiden(v))
+            }
+            (SEOutputDataId::SEExpressionCheekRaise, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionCheekRaise(v))
+            }
+            (SEOutputDataId::SEExpressionLidTighten, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionLidTighten(v))
+            }
+            (SEOutputDataId::SEExpressionDimpler, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionDimpler(v))
+            }
+            (SEOutputDataId::SEExpressionLipStretch, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionLipStretch(v))
+            }
+            (SEOutputDataId::SEExpressionJawDrop, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionJawDrop(v))
+            }
+            (SEOutputDataId::SEExpressionQ, SEVariant::F64(v)) => {
+                Some(SEOutputData::SEExpressionQ(v))
+            }
+
+            _ => None,
+        }
+    }
+}
+
+impl From<SEOutputData> for SEVariant {
+    fn from(data: SEOutputData) -> Self {
+        match data {
+            SEOutputData::SEFrameNumber(v) => SEVariant::U32(v),
+            SEOutputData::SEEstimatedDelay(v) => SEVariant::U32(v),
+            SEOutputData::SETimeStamp(v) => SEVariant::U64(v),
+            SEOutputData::SEUserTimeStamp(v) => SEVariant::U64(v),
+            SEOutputData::SEFrameRate(v) => SEVariant::F64(v),
+            SEOutputData::SECameraPositions(v) => SEVariant::Vector(v),
+            SEOutputData::SECameraRotations(v) => SEVariant::Vector(v),
+            SEOutputData::SEUserDefinedData(v) => SEVariant::U64(v),
+            SEOutputData::SERealTimeClock(v) => SEVariant::U64(v),
+            SEOutputData::SEHeadPosition(v) => SEVariant::Point3D(v),
+            SEOutputData::SEHeadPositionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEHeadRotationRodrigues(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEHeadRotationQuaternion(v) => SEVariant::Quaternion(v),
+            SEOutputData::SEHeadLeftEarDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEHeadUpDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEHeadNoseDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEHeadHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEHeadPitch(v) => SEVariant::F64(v),
+            SEOutputData::SEHeadRoll(v) => SEVariant::F64(v),
+            SEOutputData::SEHeadRotationQ(v) => SEVariant::F64(v),
+            SEOutputData::SEGazeOrigin(v) => SEVariant::Point3D(v),
+            SEOutputData::SELeftGazeOrigin(v) => SEVariant::Point3D(v),
+            SEOutputData::SERightGazeOrigin(v) => SEVariant::Point3D(v),
+            SEOutputData::SEEyePosition(v) => SEVariant::Point3D(v),
+            SEOutputData::SEGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SELeftEyePosition(v) => SEVariant::Point3D(v),
+            SEOutputData::SELeftGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SELeftGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SERightEyePosition(v) => SEVariant::Point3D(v),
+            SEOutputData::SERightGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SERightGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SELeftGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SELeftGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SERightGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SERightGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEFilteredGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredLeftGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEFilteredLeftGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredRightGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEFilteredRightGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredLeftGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredLeftGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredRightGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredRightGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SESaccade(v) => SEVariant::U32(v),
+            SEOutputData::SEFixation(v) => SEVariant::U32(v),
+            SEOutputData::SEBlink(v) => SEVariant::U32(v),
+            SEOutputData::SEClosestWorldIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SEFilteredClosestWorldIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SEAllWorldIntersections(v) => SEVariant::WorldIntersections(v),
+            SEOutputData::SEFilteredAllWorldIntersections(v) => SEVariant::WorldIntersections(v),
+            SEOutputData::SEZoneId(v) => SEVariant::U16(v),
+            SEOutputData::SEEstimatedClosestWorldIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SEEstimatedAllWorldIntersections(v) => SEVariant::WorldIntersections(v),
+            SEOutputData::SEHeadClosestWorldIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SEHeadAllWorldIntersections(v) => SEVariant::WorldIntersections(v),
+            SEOutputData::SEEyelidOpening(v) => SEVariant::F64(v),
+            SEOutputData::SEEyelidOpeningQ(v) => SEVariant::F64(v),
+            SEOutputData::SELeftEyelidOpening(v) => SEVariant::F64(v),
+            SEOutputData::SELeftEyelidOpeningQ(v) => SEVariant::F64(v),
+            SEOutputData::SERightEyelidOpening(v) => SEVariant::F64(v),
+            SEOutputData::SERightEyelidOpeningQ(v) => SEVariant::F64(v),
+            SEOutputData::SEKeyboardState(v) => SEVariant::String(v),
+            SEOutputData::SELeftLowerEyelidExtremePoint(v) => SEVariant::Point3D(v),
+            SEOutputData::SELeftUpperEyelidExtremePoint(v) => SEVariant::Point3D(v),
+            SEOutputData::SERightLowerEyelidExtremePoint(v) => SEVariant::Point3D(v),
+            SEOutputData::SERightUpperEyelidExtremePoint(v) => SEVariant::Point3D(v),
+            SEOutputData::SEPupilDiameter(v) => SEVariant::F64(v),
+            SEOutputData::SEPupilDiameterQ(v) => SEVariant::F64(v),
+            SEOutputData::SELeftPupilDiameter(v) => SEVariant::F64(v),
+            SEOutputData::SELeftPupilDiameterQ(v) => SEVariant::F64(v),
+            SEOutputData::SERightPupilDiameter(v) => SEVariant::F64(v),
+            SEOutputData::SERightPupilDiameterQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredPupilDiameter(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredPupilDiameterQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredLeftPupilDiameter(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredLeftPupilDiameterQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredRightPupilDiameter(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredRightPupilDiameterQ(v) => SEVariant::F64(v),
+            SEOutputData::SEGPSPosition(v) => SEVariant::Point2D(v),
+            SEOutputData::SEGPSGroundSpeed(v) => SEVariant::F64(v),
+            SEOutputData::SEGPSCourse(v) => SEVariant::F64(v),
+            SEOutputData::SEGPSTime(v) => SEVariant::U64(v),
+            SEOutputData::SEEstimatedGazeOrigin(v) => SEVariant::Point3D(v),
+            SEOutputData::SEEstimatedLeftGazeOrigin(v) => SEVariant::Point3D(v),
+            SEOutputData::SEEstimatedRightGazeOrigin(v) => SEVariant::Point3D(v),
+            SEOutputData::SEEstimatedEyePosition(v) => SEVariant::Point3D(v),
+            SEOutputData::SEEstimatedGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEEstimatedGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedLeftEyePosition(v) => SEVariant::Point3D(v),
+            SEOutputData::SEEstimatedLeftGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEEstimatedLeftGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedLeftGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedLeftGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedRightEyePosition(v) => SEVariant::Point3D(v),
+            SEOutputData::SEEstimatedRightGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEEstimatedRightGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedRightGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEEstimatedRightGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEFilteredEstimatedGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedLeftGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEFilteredEstimatedLeftGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedLeftGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedLeftGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedRightGazeDirection(v) => SEVariant::Vect3D(v),
+            SEOutputData::SEFilteredEstimatedRightGazeDirectionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedRightGazeHeading(v) => SEVariant::F64(v),
+            SEOutputData::SEFilteredEstimatedRightGazePitch(v) => SEVariant::F64(v),
+            SEOutputData::SEASCIIKeyboardState(v) => SEVariant::U16(v),
+            SEOutputData::SECalibrationGazeIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SETaggedGazeIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SELeftClosestWorldIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SELeftAllWorldIntersections(v) => SEVariant::WorldIntersections(v),
+            SEOutputData::SERightClosestWorldIntersection(v) => SEVariant::WorldIntersection(v),
+            SEOutputData::SERightAllWorldIntersections(v) => SEVariant::WorldIntersections(v),
+            SEOutputData::SEFilteredLeftClosestWorldIntersection(v) => {
+                SEVariant::WorldIntersection(v)
+            }
+            SEOutputData::SEFilteredLeftAllWorldIntersections(v) => {
+                SEVariant::WorldIntersections(v)
+            }
+            SEOutputData::SEFilteredRightClosestWorldIntersection(v) => {
+                SEVariant::WorldIntersection(v)
+            }
+            SEOutputData::SEFilteredRightAllWorldIntersections(v) => {
+                SEVariant::WorldIntersections(v)
+            }
+            SEOutputData::SEEstimatedLeftClosestWorldIntersection(v) => {
+                SEVariant::WorldIntersection(v)
+            }
+            SEOutputData::SEEstimatedLeftAllWorldIntersections(v) => {
+                SEVariant::WorldIntersections(v)
+            }
+            SEOutputData::SEEstimatedRightClosestWorldIntersection(v) => {
+                SEVariant::WorldIntersection(v)
+            }
+            SEOutputData::SEEstimatedRightAllWorldIntersections(v) => {
+                SEVariant::WorldIntersections(v)
+            }
+            SEOutputData::SEFilteredEstimatedClosestWorldIntersection(v) => {
+                SEVariant::WorldIntersection(v)
+            }
+            SEOutputData::SEFilteredEstimatedAllWorldIntersections(v) => {
+                SEVariant::WorldIntersections(v)
+            }
+            SEOutputData::SEFilteredEstimatedLeftClosestWorldIntersection(v) => {
+                SEVariant::WorldIntersection(v)
+            }
+            SEOutputData::SEFilteredEstimatedLeftAllWorldIntersections(v) => {
+                SEVariant::WorldIntersections(v)
+            }
+            SEOutputData::SEFilteredEstimatedRightClosestWorldIntersection(v) => {
+                SEVariant::WorldIntersection(v)
+            }
+            SEOutputData::SEFilteredEstimatedRightAllWorldIntersections(v) => {
+                SEVariant::WorldIntersections(v)
+            }
+            SEOutputData::SETrackingState(v) => SEVariant::U8(v),
+            SEOutputData::SEEyeglassesStatus(v) => SEVariant::U8(v),
+            SEOutputData::SEReflexReductionStateDEPRECATED(v) => SEVariant::U8(v),
+            SEOutputData::SELeftBlinkClosingMidTime(v) => SEVariant::U64(v),
+            SEOutputData::SELeftBlinkOpeningMidTime(v) => SEVariant::U64(v),
+            SEOutputData::SELeftBlinkClosingAmplitude(v) => SEVariant::F64(v),
+            SEOutputData::SELeftBlinkOpeningAmplitude(v) => SEVariant::F64(v),
+            SEOutputData::SELeftBlinkClosingSpeed(v) => SEVariant::F64(v),
+            SEOutputData::SELeftBlinkOpeningSpeed(v) => SEVariant::F64(v),
+            SEOutputData::SERightBlinkClosingMidTime(v) => SEVariant::U64(v),
+            SEOutputData::SERightBlinkOpeningMidTime(v) => SEVariant::U64(v),
+            SEOutputData::SERightBlinkClosingAmplitude(v) => SEVariant::F64(v),
+            SEOutputData::SERightBlinkOpeningAmplitude(v) => SEVariant::F64(v),
+            SEOutputData::SERightBlinkClosingSpeed(v) => SEVariant::F64(v),
+            SEOutputData::SERightBlinkOpeningSpeed(v) => SEVariant::F64(v),
+            SEOutputData::SELeftEyelidState(v) => SEVariant::U8(v),
+            SEOutputData::SERightEyelidState(v) => SEVariant::U8(v),
+            SEOutputData::SEUserMarker(v) => SEVariant::UserMarker(v),
+            SEOutputData::SECameraClocks(v) => SEVariant::Vector(v),
+            SEOutputData::SEEmotionJoy(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionFear(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionDisgust(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionSadness(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionSurprise(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionValence(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionEngagement(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionSentimentality(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionConfusion(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionNeutral(v) => SEVariant::F64(v),
+            SEOutputData::SEEmotionQ(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionSmile(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionInnerBrowRaise(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionBrowRaise(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionBrowFurrow(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionNoseWrinkle(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionUpperLipRaise(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionLipCornerDepressor(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionChinRaise(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionLipPucker(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionLipPress(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionLipSuck(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionMouthOpen(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionSmirk(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionAttention(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionEyeWiden(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionCheekRaise(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionLidTighten(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionDimpler(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionLipStretch(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionJawDrop(v) => SEVariant::F64(v),
+            SEOutputData::SEExpressionQ(v) => SEVariant::F64(v),
         }
     }
 }
@@ -1,3 +1,10 @@
+use super::{SEOutputData, SEOutputDataId};
+use std::{
+    convert::TryFrom,
+    ops::{Add, Sub},
+};
+use thiserror::Error;
+
 pub type SETypeU8 = u8;
 pub type SETypeU16 = u16;
 pub type SETypeU32 = u32;
@@ -13,6 +20,8 @@ pub type SETypePoint3D = Point3D;
 pub type SETypeVect3D = Vect3D;
 pub type SETypeString = String;
 pub type SETypeQuaternion = Quaternion;
+pub type SETypeMatrix3X3 = Matrix3X3;
+pub type SETypeMatrix2x2 = Matrix2x2;
 pub type SETypeUserMarker = Option<UserMarker>;
 pub type SETypeWorldIntersection = Option<WorldIntersection>;
 pub type SETypeWorldIntersections = Vec<WorldIntersection>;
@@ -45,27 +54,342 @@ pub enum SETypeId {
     UserMarker = 0x15,
 }
 
+impl SETypeId {
+    // Smallest number of bytes a value of the type can be encoded in, e.g.
+    // an empty string or vector, or a missing world intersection.
+    pub fn min_size(&self) -> usize {
+        match self {
+            SETypeId::U8 => 1,
+            SETypeId::U16 => 2,
+            SETypeId::U32 | SETypeId::S32 | SETypeId::F32 => 4,
+            SETypeId::U64 | SETypeId::F64 => 8,
+            SETypeId::Point2D | SETypeId::Vect2D => 2 * 8,
+            SETypeId::Point3D | SETypeId::Vect3D => 3 * 8,
+            SETypeId::String | SETypeId::Vector | SETypeId::Struct => 2,
+            SETypeId::WorldIntersection | SETypeId::WorldIntersections => 2,
+            SETypeId::PacketHeader => 4 + 2 + 2,
+            SETypeId::SubPacketHeader => 2 + 2,
+            SETypeId::Matrix3X3 => 9 * 8,
+            SETypeId::Matrix2x2 => 4 * 8,
+            SETypeId::Quaternion => 4 * 8,
+            SETypeId::UserMarker => 2,
+        }
+    }
+}
+
 // x, y
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "serde_repr::Xy", into = "serde_repr::Xy")
+)]
 pub struct Point2D(pub f64, pub f64);
 
 // x, y
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "serde_repr::Xy", into = "serde_repr::Xy")
+)]
 pub struct Vect2D(pub f64, pub f64);
 
 // x, y, z
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "serde_repr::Xyz", into = "serde_repr::Xyz")
+)]
 pub struct Point3D(pub f64, pub f64, pub f64);
 
 // x, y, z
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "serde_repr::Xyz", into = "serde_repr::Xyz")
+)]
 pub struct Vect3D(pub f64, pub f64, pub f64);
 
+impl From<Point2D> for Vect2D {
+    fn from(Point2D(x, y): Point2D) -> Self {
+        Vect2D(x, y)
+    }
+}
+
+impl From<Vect2D> for Point2D {
+    fn from(Vect2D(x, y): Vect2D) -> Self {
+        Point2D(x, y)
+    }
+}
+
+impl From<Point3D> for Vect3D {
+    fn from(Point3D(x, y, z): Point3D) -> Self {
+        Vect3D(x, y, z)
+    }
+}
+
+impl From<Vect3D> for Point3D {
+    fn from(Vect3D(x, y, z): Vect3D) -> Self {
+        Point3D(x, y, z)
+    }
+}
+
+impl Point2D {
+    pub fn x(&self) -> f64 {
+        self.0
+    }
+
+    pub fn y(&self) -> f64 {
+        self.1
+    }
+}
+
+impl From<[f64; 2]> for Point2D {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Point2D(x, y)
+    }
+}
+
+impl From<Point2D> for [f64; 2] {
+    fn from(Point2D(x, y): Point2D) -> Self {
+        [x, y]
+    }
+}
+
+impl Vect2D {
+    pub fn x(&self) -> f64 {
+        self.0
+    }
+
+    pub fn y(&self) -> f64 {
+        self.1
+    }
+}
+
+impl From<[f64; 2]> for Vect2D {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Vect2D(x, y)
+    }
+}
+
+impl From<Vect2D> for [f64; 2] {
+    fn from(Vect2D(x, y): Vect2D) -> Self {
+        [x, y]
+    }
+}
+
+impl Point3D {
+    pub fn x(&self) -> f64 {
+        self.0
+    }
+
+    pub fn y(&self) -> f64 {
+        self.1
+    }
+
+    pub fn z(&self) -> f64 {
+        self.2
+    }
+}
+
+impl From<[f64; 3]> for Point3D {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Point3D(x, y, z)
+    }
+}
+
+impl From<Point3D> for [f64; 3] {
+    fn from(Point3D(x, y, z): Point3D) -> Self {
+        [x, y, z]
+    }
+}
+
+impl Vect3D {
+    pub fn x(&self) -> f64 {
+        self.0
+    }
+
+    pub fn y(&self) -> f64 {
+        self.1
+    }
+
+    pub fn z(&self) -> f64 {
+        self.2
+    }
+}
+
+impl From<[f64; 3]> for Vect3D {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Vect3D(x, y, z)
+    }
+}
+
+impl From<Vect3D> for [f64; 3] {
+    fn from(Vect3D(x, y, z): Vect3D) -> Self {
+        [x, y, z]
+    }
+}
+
+// Displacement from rhs to self.
+impl Sub for Point3D {
+    type Output = Vect3D;
+
+    fn sub(self, rhs: Point3D) -> Vect3D {
+        Vect3D(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Add<Vect3D> for Point3D {
+    type Output = Point3D;
+
+    fn add(self, rhs: Vect3D) -> Point3D {
+        Point3D(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
 // w, x, y, z
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "serde_repr::Wxyz", into = "serde_repr::Wxyz")
+)]
 pub struct Quaternion(pub f64, pub f64, pub f64, pub f64);
 
+impl Quaternion {
+    pub fn w(&self) -> f64 {
+        self.0
+    }
+
+    pub fn x(&self) -> f64 {
+        self.1
+    }
+
+    pub fn y(&self) -> f64 {
+        self.2
+    }
+
+    pub fn z(&self) -> f64 {
+        self.3
+    }
+}
+
+impl From<[f64; 4]> for Quaternion {
+    fn from([w, x, y, z]: [f64; 4]) -> Self {
+        Quaternion(w, x, y, z)
+    }
+}
+
+impl From<Quaternion> for [f64; 4] {
+    fn from(Quaternion(w, x, y, z): Quaternion) -> Self {
+        [w, x, y, z]
+    }
+}
+
+// Points, vectors and quaternions serialize with named components, e.g.
+// {"x": 1.0, "y": 2.0, "z": 3.0}, rather than as tuples.
+#[cfg(feature = "serde")]
+mod serde_repr {
+    use super::{Point2D, Point3D, Quaternion, Vect2D, Vect3D};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Xy {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Xyz {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    pub struct Wxyz {
+        w: f64,
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    impl From<Xy> for Point2D {
+        fn from(Xy { x, y }: Xy) -> Self {
+            Point2D(x, y)
+        }
+    }
+
+    impl From<Point2D> for Xy {
+        fn from(Point2D(x, y): Point2D) -> Self {
+            Xy { x, y }
+        }
+    }
+
+    impl From<Xy> for Vect2D {
+        fn from(Xy { x, y }: Xy) -> Self {
+            Vect2D(x, y)
+        }
+    }
+
+    impl From<Vect2D> for Xy {
+        fn from(Vect2D(x, y): Vect2D) -> Self {
+            Xy { x, y }
+        }
+    }
+
+    impl From<Xyz> for Point3D {
+        fn from(Xyz { x, y, z }: Xyz) -> Self {
+            Point3D(x, y, z)
+        }
+    }
+
+    impl From<Point3D> for Xyz {
+        fn from(Point3D(x, y, z): Point3D) -> Self {
+            Xyz { x, y, z }
+        }
+    }
+
+    impl From<Xyz> for Vect3D {
+        fn from(Xyz { x, y, z }: Xyz) -> Self {
+            Vect3D(x, y, z)
+        }
+    }
+
+    impl From<Vect3D> for Xyz {
+        fn from(Vect3D(x, y, z): Vect3D) -> Self {
+            Xyz { x, y, z }
+        }
+    }
+
+    impl From<Wxyz> for Quaternion {
+        fn from(Wxyz { w, x, y, z }: Wxyz) -> Self {
+            Quaternion(w, x, y, z)
+        }
+    }
+
+    impl From<Quaternion> for Wxyz {
+        fn from(Quaternion(w, x, y, z): Quaternion) -> Self {
+            Wxyz { w, x, y, z }
+        }
+    }
+}
+
+// Rows of the matrix.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix3X3(pub [[f64; 3]; 3]);
+
+// Rows of the matrix.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix2x2(pub [[f64; 2]; 2]);
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorldIntersection {
     pub world_point: Point3D,
     pub object_point: Point3D,
@@ -73,6 +397,7 @@ pub struct WorldIntersection {
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserMarker {
     pub error: i32,
     pub time_stamp: u64,
@@ -81,7 +406,35 @@ pub struct UserMarker {
     pub data: u64,
 }
 
+// Application specific bits of SEUserDefinedData. Bit 0 and byte 0 are the
+// least significant.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UserDefinedData(pub u64);
+
+impl UserDefinedData {
+    pub fn bit(&self, n: u8) -> bool {
+        assert!(n < 64, "bit index out of range");
+        (self.0 >> n) & 1 == 1
+    }
+
+    pub fn byte(&self, n: u8) -> u8 {
+        assert!(n < 8, "byte index out of range");
+        (self.0 >> (n * 8)) as u8
+    }
+}
+
+impl From<u64> for UserDefinedData {
+    fn from(v: u64) -> Self {
+        UserDefinedData(v)
+    }
+}
+
+// Id of a gaze zone, as defined in the Smart Eye Pro world model.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct ZoneId(pub u16);
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SEVariant {
     U8(SETypeU8),
     U16(SETypeU16),
@@ -101,8 +454,8 @@ pub enum SEVariant {
     // PacketHeader(SETypePacketHeader),
     // SubPacketHeader(SETypeSubPacketHeader),
     F32(SETypeF32),
-    // Matrix3X3(SETypeMatrix3X3),
-    // Matrix2x2(SETypeMatrix2x2),
+    Matrix3X3(SETypeMatrix3X3),
+    Matrix2x2(SETypeMatrix2x2),
     Quaternion(SETypeQuaternion),
     UserMarker(SETypeUserMarker),
 }
@@ -111,6 +464,7 @@ pub type SEVectorItem = SEVariant;
 
 // key, value
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SEStructItem(pub String, pub SEVariant);
 
 impl std::convert::TryFrom<u16> for SETypeId {
@@ -144,3 +498,301 @@ impl std::convert::TryFrom<u16> for SETypeId {
         }
     }
 }
+
+// A numeric scalar, keeping the exact type it was sent as.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Number {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    I32(i32),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+}
+
+impl Number {
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Number::U8(v) => v.into(),
+            Number::U16(v) => v.into(),
+            Number::U32(v) => v.into(),
+            Number::I32(v) => v.into(),
+            Number::U64(v) => v as f64,
+            Number::F32(v) => v.into(),
+            Number::F64(v) => v,
+        }
+    }
+
+    // The value of integer types, None for floats.
+    pub fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Number::U8(v) => Some(v.into()),
+            Number::U16(v) => Some(v.into()),
+            Number::U32(v) => Some(v.into()),
+            Number::I32(v) => Some(v.into()),
+            Number::U64(v) => Some(v.into()),
+            Number::F32(_) | Number::F64(_) => None,
+        }
+    }
+}
+
+impl SEVariant {
+    // Numeric scalars, None for all other types.
+    pub fn as_number(&self) -> Option<Number> {
+        match *self {
+            SEVariant::U8(v) => Some(Number::U8(v)),
+            SEVariant::U16(v) => Some(Number::U16(v)),
+            SEVariant::U32(v) => Some(Number::U32(v)),
+            SEVariant::S32(v) => Some(Number::I32(v)),
+            SEVariant::U64(v) => Some(Number::U64(v)),
+            SEVariant::F64(v) => Some(Number::F64(v)),
+            SEVariant::F32(v) => Some(Number::F32(v)),
+            _ => None,
+        }
+    }
+
+    // Numeric scalars widened to f64, None for all other types.
+    pub fn as_f64(&self) -> Option<f64> {
+        self.as_number().map(|n| n.as_f64())
+    }
+}
+
+// The variants of SEVariant holding Copy values, i.e. all but strings,
+// vectors, structs, world intersections and matrices.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SEScalar {
+    U8(SETypeU8),
+    U16(SETypeU16),
+    U32(SETypeU32),
+    S32(SETypeS32),
+    U64(SETypeU64),
+    F64(SETypeF64),
+    Point2D(SETypePoint2D),
+    Vect2D(SETypeVect2D),
+    Point3D(SETypePoint3D),
+    Vect3D(SETypeVect3D),
+    F32(SETypeF32),
+    Quaternion(SETypeQuaternion),
+    Matrix3X3(SETypeMatrix3X3),
+    Matrix2x2(SETypeMatrix2x2),
+    UserMarker(SETypeUserMarker),
+}
+
+impl TryFrom<SEVariant> for SEScalar {
+    type Error = SEVariant;
+
+    fn try_from(v: SEVariant) -> Result<Self, Self::Error> {
+        match v {
+            SEVariant::U8(v) => Ok(SEScalar::U8(v)),
+            SEVariant::U16(v) => Ok(SEScalar::U16(v)),
+            SEVariant::U32(v) => Ok(SEScalar::U32(v)),
+            SEVariant::S32(v) => Ok(SEScalar::S32(v)),
+            SEVariant::U64(v) => Ok(SEScalar::U64(v)),
+            SEVariant::F64(v) => Ok(SEScalar::F64(v)),
+            SEVariant::Point2D(v) => Ok(SEScalar::Point2D(v)),
+            SEVariant::Vect2D(v) => Ok(SEScalar::Vect2D(v)),
+            SEVariant::Point3D(v) => Ok(SEScalar::Point3D(v)),
+            SEVariant::Vect3D(v) => Ok(SEScalar::Vect3D(v)),
+            SEVariant::F32(v) => Ok(SEScalar::F32(v)),
+            SEVariant::Quaternion(v) => Ok(SEScalar::Quaternion(v)),
+            SEVariant::Matrix3X3(v) => Ok(SEScalar::Matrix3X3(v)),
+            SEVariant::Matrix2x2(v) => Ok(SEScalar::Matrix2x2(v)),
+            SEVariant::UserMarker(v) => Ok(SEScalar::UserMarker(v)),
+            v => Err(v),
+        }
+    }
+}
+
+// Fails with the data given back for non-scalar output data.
+impl TryFrom<SEOutputData> for SEScalar {
+    type Error = SEOutputData;
+
+    fn try_from(data: SEOutputData) -> Result<Self, Self::Error> {
+        let id = data.id();
+        SEScalar::try_from(SEVariant::from(data)).map_err(|v| {
+            SEOutputData::from_variant(id, v).expect("variant taken from the same output data")
+        })
+    }
+}
+
+impl From<SEScalar> for SEVariant {
+    fn from(v: SEScalar) -> Self {
+        match v {
+            SEScalar::U8(v) => SEVariant::U8(v),
+            SEScalar::U16(v) => SEVariant::U16(v),
+            SEScalar::U32(v) => SEVariant::U32(v),
+            SEScalar::S32(v) => SEVariant::S32(v),
+            SEScalar::U64(v) => SEVariant::U64(v),
+            SEScalar::F64(v) => SEVariant::F64(v),
+            SEScalar::Point2D(v) => SEVariant::Point2D(v),
+            SEScalar::Vect2D(v) => SEVariant::Vect2D(v),
+            SEScalar::Point3D(v) => SEVariant::Point3D(v),
+            SEScalar::Vect3D(v) => SEVariant::Vect3D(v),
+            SEScalar::F32(v) => SEVariant::F32(v),
+            SEScalar::Quaternion(v) => SEVariant::Quaternion(v),
+            SEScalar::Matrix3X3(v) => SEVariant::Matrix3X3(v),
+            SEScalar::Matrix2x2(v) => SEVariant::Matrix2x2(v),
+            SEScalar::UserMarker(v) => SEVariant::UserMarker(v),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Clone, Copy)]
+#[error("{id:?} requires a value of type {expected:?}")]
+pub struct TypeMismatch {
+    pub id: SEOutputDataId,
+    pub expected: SETypeId,
+}
+
+// Output data with the given id and value, the inverse of SEOutputData::id
+// and SEVariant::from. Fails if the type of the value is not the type of the
+// id, e.g. SEFrameNumber requires a U32.
+pub fn make_output(id: SEOutputDataId, value: SEVariant) -> Result<SEOutputData, TypeMismatch> {
+    SEOutputData::from_variant(id, value).ok_or(TypeMismatch {
+        id,
+        expected: id.type_id(),
+    })
+}
+
+impl SEOutputData {
+    pub fn as_user_defined_data(&self) -> Option<UserDefinedData> {
+        match *self {
+            SEOutputData::SEUserDefinedData(v) => Some(UserDefinedData(v)),
+            _ => None,
+        }
+    }
+
+    pub fn as_zone_id(&self) -> Option<ZoneId> {
+        match *self {
+            SEOutputData::SEZoneId(v) => Some(ZoneId(v)),
+            _ => None,
+        }
+    }
+
+    // Key of SEASCIIKeyboardState as a char, from the low byte of the key
+    // code. None unless it is printable ASCII.
+    pub fn ascii_char(&self) -> Option<char> {
+        match *self {
+            SEOutputData::SEASCIIKeyboardState(v) => {
+                let c = char::from(v as u8);
+                if c.is_ascii_graphic() || c == ' ' {
+                    Some(c)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_defined_data() {
+        let data = SEOutputData::SEUserDefinedData(0x0000_1200_0000_0005);
+        let bits = data.as_user_defined_data().unwrap();
+        assert_eq!(bits.0, 0x0000_1200_0000_0005);
+        assert!(bits.bit(0));
+        assert!(!bits.bit(1));
+        assert!(bits.bit(2));
+        assert!(!bits.bit(63));
+        assert_eq!(bits.byte(0), 0x05);
+        assert_eq!(bits.byte(5), 0x12);
+        assert_eq!(SEOutputData::SEFrameNumber(1).as_user_defined_data(), None);
+    }
+
+    #[test]
+    fn test_component_accessors() {
+        let v = Vect3D::from([1.0, 2.0, 3.0]);
+        assert_eq!((v.x(), v.y(), v.z()), (1.0, 2.0, 3.0));
+        assert_eq!(<[f64; 3]>::from(Point3D(4.0, 5.0, 6.0)), [4.0, 5.0, 6.0]);
+        let p: [f64; 2] = Point2D::from([7.0, 8.0]).into();
+        assert_eq!(p, [7.0, 8.0]);
+        assert_eq!(Vect2D(1.0, -1.0).y(), -1.0);
+
+        let q = Quaternion::from([1.0, 0.0, 0.5, 0.25]);
+        assert_eq!((q.w(), q.x(), q.y(), q.z()), (1.0, 0.0, 0.5, 0.25));
+        assert_eq!(<[f64; 4]>::from(q), [1.0, 0.0, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_make_output() {
+        assert_eq!(
+            make_output(SEOutputDataId::SEFrameNumber, SEVariant::U32(7)),
+            Ok(SEOutputData::SEFrameNumber(7))
+        );
+        assert_eq!(
+            make_output(SEOutputDataId::SEFrameNumber, SEVariant::U16(7)),
+            Err(TypeMismatch {
+                id: SEOutputDataId::SEFrameNumber,
+                expected: SETypeId::U32,
+            })
+        );
+    }
+
+    #[test]
+    fn test_ascii_char() {
+        assert_eq!(
+            SEOutputData::SEASCIIKeyboardState(0x41).ascii_char(),
+            Some('A')
+        );
+        assert_eq!(SEOutputData::SEASCIIKeyboardState(0x0D).ascii_char(), None);
+        assert_eq!(SEOutputData::SEZoneId(0x41).ascii_char(), None);
+    }
+
+    #[test]
+    fn test_as_number() {
+        assert_eq!(SEVariant::U8(1).as_number(), Some(Number::U8(1)));
+        assert_eq!(SEVariant::U16(2).as_number(), Some(Number::U16(2)));
+        assert_eq!(SEVariant::U32(3).as_number(), Some(Number::U32(3)));
+        assert_eq!(SEVariant::S32(-4).as_number(), Some(Number::I32(-4)));
+        assert_eq!(SEVariant::U64(5).as_number(), Some(Number::U64(5)));
+        assert_eq!(SEVariant::F32(6.5).as_number(), Some(Number::F32(6.5)));
+        assert_eq!(SEVariant::F64(7.5).as_number(), Some(Number::F64(7.5)));
+        assert_eq!(SEVariant::String(String::from("8")).as_number(), None);
+
+        assert_eq!(Number::I32(-4).as_i128(), Some(-4));
+        assert_eq!(Number::U64(u64::MAX).as_i128(), Some(u64::MAX.into()));
+        assert_eq!(Number::F64(7.5).as_i128(), None);
+        assert_eq!(Number::U16(2).as_f64(), 2.0);
+    }
+
+    #[test]
+    fn test_se_scalar_try_from() {
+        let head_position = Point3D(0.1, 0.2, 0.6);
+        assert_eq!(
+            SEScalar::try_from(SEOutputData::SEHeadPosition(head_position)),
+            Ok(SEScalar::Point3D(head_position))
+        );
+        assert_eq!(
+            SEScalar::try_from(SEOutputData::SEFrameNumber(3)),
+            Ok(SEScalar::U32(3))
+        );
+        let keyboard_state = SEOutputData::SEKeyboardState(String::from("a"));
+        assert_eq!(
+            SEScalar::try_from(keyboard_state.clone()),
+            Err(keyboard_state)
+        );
+        assert_eq!(SEVariant::from(SEScalar::U32(3)), SEVariant::U32(3));
+    }
+
+    #[test]
+    fn test_point_vect_conversions() {
+        assert_eq!(Vect3D::from(Point3D(1.0, 2.0, 3.0)), Vect3D(1.0, 2.0, 3.0));
+        assert_eq!(Point3D::from(Vect3D(1.0, 2.0, 3.0)), Point3D(1.0, 2.0, 3.0));
+        assert_eq!(Vect2D::from(Point2D(1.0, 2.0)), Vect2D(1.0, 2.0));
+        assert_eq!(Point2D::from(Vect2D(1.0, 2.0)), Point2D(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_point_vect_ops() {
+        let from = Point3D(1.0, 1.0, 0.5);
+        let to = Point3D(1.5, 0.0, 2.5);
+        assert_eq!(to - from, Vect3D(0.5, -1.0, 2.0));
+        assert_eq!(from + (to - from), to);
+    }
+}
@@ -0,0 +1,151 @@
+use super::{Point3D, SEOutputData, SEOutputDataId, SETypeId, SEVariant};
+use std::ops::Deref;
+
+// Unit-carrying wrappers for scalar output data, to avoid mixing up e.g.
+// angles and lengths. Deref to the plain f64 value.
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct Radians(pub f64);
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct Millimeters(pub f64);
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct Quality(pub f64);
+
+impl Deref for Radians {
+    type Target = f64;
+
+    fn deref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl Deref for Millimeters {
+    type Target = f64;
+
+    fn deref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl Deref for Quality {
+    type Target = f64;
+
+    fn deref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl SEOutputDataId {
+    // Heading, pitch and roll angles.
+    pub fn is_angle(&self) -> bool {
+        let name = self.name();
+        name.ends_with("Heading") || name.ends_with("Pitch") || name.ends_with("Roll")
+    }
+
+    // Pupil diameters and eyelid openings.
+    pub fn is_length(&self) -> bool {
+        let name = self.name();
+        name.ends_with("PupilDiameter") || name.ends_with("EyelidOpening")
+    }
+
+    // Positions in world coordinates, such as head position and gaze
+    // origins.
+    pub fn is_position(&self) -> bool {
+        self.type_id() == SETypeId::Point3D
+    }
+
+    // Quality measures (the *Q fields), in the range [0, 1].
+    pub fn is_quality(&self) -> bool {
+        self.name().ends_with('Q')
+    }
+}
+
+impl SEOutputData {
+    fn scalar_f64(&self) -> Option<f64> {
+        match SEVariant::from(self.clone()) {
+            SEVariant::F64(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_radians(&self) -> Option<Radians> {
+        if self.id().is_angle() {
+            self.scalar_f64().map(Radians)
+        } else {
+            None
+        }
+    }
+
+    // Lengths are reported by Smart Eye Pro in meters, and converted here.
+    pub fn as_millimeters(&self) -> Option<Millimeters> {
+        if self.id().is_length() {
+            self.scalar_f64().map(|v| Millimeters(v * 1000.0))
+        } else {
+            None
+        }
+    }
+
+    // The x, y and z coordinates of positions, converted like as_millimeters.
+    pub fn as_position_millimeters(&self) -> Option<[Millimeters; 3]> {
+        match SEVariant::from(self.clone()) {
+            SEVariant::Point3D(Point3D(x, y, z)) if self.id().is_position() => Some([
+                Millimeters(x * 1000.0),
+                Millimeters(y * 1000.0),
+                Millimeters(z * 1000.0),
+            ]),
+            _ => None,
+        }
+    }
+
+    pub fn as_quality(&self) -> Option<Quality> {
+        if self.id().is_quality() {
+            self.scalar_f64().map(Quality)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_radians() {
+        let heading = SEOutputData::SEGazeHeading(-0.25);
+        assert_eq!(heading.as_radians(), Some(Radians(-0.25)));
+        assert_eq!(*heading.as_radians().unwrap(), -0.25);
+        assert_eq!(heading.as_millimeters(), None);
+        assert_eq!(heading.as_quality(), None);
+        assert_eq!(SEOutputData::SEFrameRate(60.0).as_radians(), None);
+    }
+
+    #[test]
+    fn test_as_millimeters_and_quality() {
+        let diameter = SEOutputData::SEPupilDiameter(0.003);
+        assert!((*diameter.as_millimeters().unwrap() - 3.0).abs() < 1e-12);
+        assert_eq!(
+            SEOutputData::SEPupilDiameterQ(0.9).as_quality(),
+            Some(Quality(0.9))
+        );
+        assert_eq!(SEOutputData::SEPupilDiameterQ(0.9).as_millimeters(), None);
+    }
+
+    #[test]
+    fn test_as_position_millimeters() {
+        let position = SEOutputData::SEHeadPosition(Point3D(0.01, -0.02, 0.6));
+        let [x, y, z] = position.as_position_millimeters().unwrap();
+        assert!((*x - 10.0).abs() < 1e-9);
+        assert!((*y + 20.0).abs() < 1e-9);
+        assert!((*z - 600.0).abs() < 1e-9);
+        assert!(SEOutputData::SELeftGazeOrigin(Point3D(0.0, 0.0, 0.5))
+            .as_position_millimeters()
+            .is_some());
+        assert_eq!(
+            SEOutputData::SEPupilDiameter(0.003).as_position_millimeters(),
+            None
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{
+    parser::{parse_packet, ParseError},
+    se_types::*,
+};
+use std::{collections::HashSet, convert::TryFrom};
+
+// The serializer is the inverse of the parser, producing SEPD packets from
+// output data. Lengths and counts that do not fit the u16 fields of the
+// protocol cause a panic.
+
+fn serialize_u16_len(len: usize, out: &mut Vec<u8>) {
+    let len = u16::try_from(len).expect("length does not fit in u16");
+    out.extend_from_slice(&len.to_be_bytes());
+}
+
+fn serialize_f64s(values: &[f64], out: &mut Vec<u8>) {
+    for v in values {
+        out.extend_from_slice(&v.to_be_bytes());
+    }
+}
+
+fn serialize_string(s: &str, out: &mut Vec<u8>) {
+    serialize_u16_len(s.len(), out);
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn serialize_world_intersection_item(v: &WorldIntersection, out: &mut Vec<u8>) {
+    let Point3D(x, y, z) = v.world_point;
+    serialize_f64s(&[x, y, z], out);
+    let Point3D(x, y, z) = v.object_point;
+    serialize_f64s(&[x, y, z], out);
+    serialize_string(&v.object_name, out);
+}
+
+fn serialize_user_marker_item(v: &UserMarker, out: &mut Vec<u8>) {
+    out.extend_from_slice(&v.error.to_be_bytes());
+    out.extend_from_slice(&v.time_stamp.to_be_bytes());
+    out.extend_from_slice(&v.camera_clock.to_be_bytes());
+    out.extend_from_slice(&v.camera_idx.to_be_bytes());
+    out.extend_from_slice(&v.data.to_be_bytes());
+}
+
+fn type_id(v: &SEVariant) -> SETypeId {
+    match v {
+        SEVariant::U8(_) => SETypeId::U8,
+        SEVariant::U16(_) => SETypeId::U16,
+        SEVariant::U32(_) => SETypeId::U32,
+        SEVariant::S32(_) => SETypeId::S32,
+        SEVariant::U64(_) => SETypeId::U64,
+        SEVariant::F64(_) => SETypeId::F64,
+        SEVariant::Point2D(_) => SETypeId::Point2D,
+        SEVariant::Vect2D(_) => SETypeId::Vect2D,
+        SEVariant::Point3D(_) => SETypeId::Point3D,
+        SEVariant::Vect3D(_) => SETypeId::Vect3D,
+        SEVariant::String(_) => SETypeId::String,
+        SEVariant::Vector(_) => SETypeId::Vector,
+        SEVariant::Struct(_) => SETypeId::Struct,
+        SEVariant::WorldIntersection(_) => SETypeId::WorldIntersection,
+        SEVariant::WorldIntersections(_) => SETypeId::WorldIntersections,
+        SEVariant::F32(_) => SETypeId::F32,
+        SEVariant::Quaternion(_) => SETypeId::Quaternion,
+        SEVariant::Matrix3X3(_) => SETypeId::Matrix3X3,
+        SEVariant::UserMarker(_) => SETypeId::UserMarker,
+        SEVariant::Matrix2x2(_) => SETypeId::Matrix2x2,
+    }
+}
+
+// Serializes the value of a variant, without its type id.
+fn serialize_variant_value(v: &SEVariant, out: &mut Vec<u8>) {
+    match v {
+        SEVariant::U8(v) => out.extend_from_slice(&v.to_be_bytes()),
+        SEVariant::U16(v) => out.extend_from_slice(&v.to_be_bytes()),
+        SEVariant::U32(v) => out.extend_from_slice(&v.to_be_bytes()),
+        SEVariant::S32(v) => out.extend_from_slice(&v.to_be_bytes()),
+        SEVariant::U64(v) => out.extend_from_slice(&v.to_be_bytes()),
+        SEVariant::F64(v) => out.extend_from_slice(&v.to_be_bytes()),
+        SEVariant::Point2D(Point2D(x, y)) => serialize_f64s(&[*x, *y], out),
+        SEVariant::Vect2D(Vect2D(x, y)) => serialize_f64s(&[*x, *y], out),
+        SEVariant::Point3D(Point3D(x, y, z)) => serialize_f64s(&[*x, *y, *z], out),
+        SEVariant::Vect3D(Vect3D(x, y, z)) => serialize_f64s(&[*x, *y, *z], out),
+        SEVariant::String(v) => serialize_string(v, out),
+        SEVariant::Vector(items) => {
+            serialize_u16_len(items.len(), out);
+            for item in items {
+                serialize_variant_into(item, out);
+            }
+        }
+        SEVariant::Struct(items) => {
+            serialize_u16_len(items.len(), out);
+            for SEStructItem(key, value) in items {
+                serialize_string(key, out);
+                serialize_variant_into(value, out);
+            }
+        }
+        SEVariant::WorldIntersection(v) => match v {
+            None => out.extend_from_slice(&0u16.to_be_bytes()),
+            Some(v) => {
+                out.extend_from_slice(&1u16.to_be_bytes());
+                serialize_world_intersection_item(v, out);
+            }
+        },
+        SEVariant::WorldIntersections(items) => {
+            serialize_u16_len(items.len(), out);
+            for item in items {
+                serialize_world_intersection_item(item, out);
+            }
+        }
+        SEVariant::F32(v) => out.extend_from_slice(&v.to_be_bytes()),
+        SEVariant::Quaternion(Quaternion(w, x, y, z)) => serialize_f64s(&[*w, *x, *y, *z], out),
+        SEVariant::Matrix3X3(Matrix3X3(rows)) => serialize_f64s(rows.concat().as_slice(), out),
+        SEVariant::Matrix2x2(Matrix2x2(rows)) => serialize_f64s(rows.concat().as_slice(), out),
+        SEVariant::UserMarker(v) => match v {
+            None => out.extend_from_slice(&0u16.to_be_bytes()),
+            Some(v) => {
+                out.extend_from_slice(&1u16.to_be_bytes());
+                serialize_user_marker_item(v, out);
+            }
+        },
+    }
+}
+
+fn serialize_variant_into(v: &SEVariant, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(type_id(v) as u16).to_be_bytes());
+    serialize_variant_value(v, out);
+}
+
+// Serializes a variant as found in vectors and structs, i.e. prefixed by its
+// type id.
+pub fn serialize_variant(v: &SEVariant) -> Vec<u8> {
+    let mut out = Vec::new();
+    serialize_variant_into(v, &mut out);
+    out
+}
+
+fn serialize_sub_packet(data: &SEOutputData, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(data.id() as u16).to_be_bytes());
+    let length_pos = out.len();
+    out.extend_from_slice(&[0, 0]);
+    serialize_variant_value(&SEVariant::from(data.clone()), out);
+    let length = out.len() - length_pos - 2;
+    let length = u16::try_from(length).expect("sub-packet length does not fit in u16");
+    out[length_pos..length_pos + 2].copy_from_slice(&length.to_be_bytes());
+}
+
+pub fn serialize_packet(data: &[SEOutputData]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(b"SEPD");
+    out.extend_from_slice(&[0x00, 0x04]);
+    out.extend_from_slice(&[0, 0]);
+    for sub_packet in data {
+        serialize_sub_packet(sub_packet, &mut out);
+    }
+    let length = out.len() - 8;
+    let length = u16::try_from(length).expect("packet length does not fit in u16");
+    out[6..8].copy_from_slice(&length.to_be_bytes());
+    out
+}
+
+// Parses the packet at the start of buf and serializes it again with only
+// the wanted sub-packets, e.g. for forwarding a reduced stream.
+pub fn reencode_filtered(
+    buf: &[u8],
+    wanted: &HashSet<SEOutputDataId>,
+) -> Result<Vec<u8>, ParseError> {
+    let mut data = parse_packet(buf)?;
+    data.retain(|d| wanted.contains(&d.id()));
+    Ok(serialize_packet(&data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::packet_of;
+
+    const PACKET_TIME_STAMP_FRAME_NUMBER: &[u8] = &[
+        0x53, 0x45, 0x50, 0x44, // Sync Id
+        0x00, 0x04, // Packet type
+        0x00, 0x14, // Packet length
+        0x00, 0x03, // Id (0x0003 = SETimeStamp)
+        0x00, 0x08, // Length
+        0x00, 0x00, 0x04, 0x12, 0xDE, 0x00, 0x01, 0x00, // Subpacket data
+        0x00, 0x01, // Id (0x0001 = SEFrameNumber)
+        0x00, 0x04, // Length
+        0x00, 0x00, 0x45, 0x9B, // Subpacket data
+    ];
+
+    #[test]
+    fn test_serialize_variant() {
+        assert_eq!(serialize_variant(&SEVariant::U8(1)), vec![0x00, 0x00, 0x01]);
+        assert_eq!(
+            serialize_variant(&SEVariant::Struct(vec![SEStructItem(
+                String::from("AbC"),
+                SEVariant::U16(1337)
+            )])),
+            vec![
+                0x00, 0x0C, // typeId (SEType_Struct)
+                0x00, 0x01, // numElements
+                0x00, 0x03, // elem[0].id.length
+                0x41, 0x62, 0x43, // elem[0].id.chars ("AbC")
+                0x00, 0x01, // elem[0].typeId (=SEType_u16)
+                0x05, 0x39, // elem[0] (=1337)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serialize_packet() {
+        let data = vec![
+            SEOutputData::SETimeStamp(4479080464640),
+            SEOutputData::SEFrameNumber(17819),
+        ];
+        let packet = serialize_packet(&data);
+        assert_eq!(packet, PACKET_TIME_STAMP_FRAME_NUMBER);
+        assert_eq!(parse_packet(&packet).as_deref(), Ok(&data[..]));
+    }
+
+    #[test]
+    fn test_serialize_packet_capture_round_trip() {
+        let capture = include_bytes!("../../assets/captures/SEP_DEFAULT_LICENSE_ALL_OUTPUT.bin");
+        let data = parse_packet(capture).unwrap();
+        let packet = serialize_packet(&data);
+        assert_eq!(packet, &capture[..]);
+        assert_eq!(parse_packet(&packet), Ok(data));
+    }
+
+    #[test]
+    fn test_reencode_filtered() {
+        let wanted = [SEOutputDataId::SETimeStamp].iter().copied().collect();
+        let packet = reencode_filtered(PACKET_TIME_STAMP_FRAME_NUMBER, &wanted).unwrap();
+        assert_eq!(&packet[6..8], &[0x00, 0x0C]); // Packet length
+        assert_eq!(
+            parse_packet(&packet),
+            Ok(packet_of(vec![SEOutputData::SETimeStamp(4479080464640)]))
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptest_tests;
@@ -0,0 +1,125 @@
+use super::*;
+use crate::parser::parse_packet;
+use proptest::prelude::*;
+
+// Finite floats only, NaN would never compare equal after a round-trip.
+fn f64_strategy() -> impl Strategy<Value = f64> {
+    -1.0e9..1.0e9f64
+}
+
+fn point_3d_strategy() -> impl Strategy<Value = Point3D> {
+    (f64_strategy(), f64_strategy(), f64_strategy()).prop_map(|(x, y, z)| Point3D(x, y, z))
+}
+
+fn vect_3d_strategy() -> impl Strategy<Value = Vect3D> {
+    (f64_strategy(), f64_strategy(), f64_strategy()).prop_map(|(x, y, z)| Vect3D(x, y, z))
+}
+
+fn string_strategy() -> impl Strategy<Value = String> {
+    ".{0,16}"
+}
+
+fn world_intersection_strategy() -> impl Strategy<Value = WorldIntersection> {
+    (point_3d_strategy(), point_3d_strategy(), string_strategy()).prop_map(
+        |(world_point, object_point, object_name)| WorldIntersection {
+            world_point,
+            object_point,
+            object_name,
+        },
+    )
+}
+
+fn user_marker_strategy() -> impl Strategy<Value = UserMarker> {
+    (
+        any::<i32>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u8>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(error, time_stamp, camera_clock, camera_idx, data)| UserMarker {
+                error,
+                time_stamp,
+                camera_clock,
+                camera_idx,
+                data,
+            },
+        )
+}
+
+fn variant_strategy() -> impl Strategy<Value = SEVariant> {
+    let leaf = prop_oneof![
+        any::<u8>().prop_map(SEVariant::U8),
+        any::<u16>().prop_map(SEVariant::U16),
+        any::<u32>().prop_map(SEVariant::U32),
+        any::<i32>().prop_map(SEVariant::S32),
+        any::<u64>().prop_map(SEVariant::U64),
+        f64_strategy().prop_map(SEVariant::F64),
+        (-1.0e6..1.0e6f32).prop_map(SEVariant::F32),
+        (f64_strategy(), f64_strategy()).prop_map(|(x, y)| SEVariant::Point2D(Point2D(x, y))),
+        (f64_strategy(), f64_strategy()).prop_map(|(x, y)| SEVariant::Vect2D(Vect2D(x, y))),
+        point_3d_strategy().prop_map(SEVariant::Point3D),
+        vect_3d_strategy().prop_map(SEVariant::Vect3D),
+        string_strategy().prop_map(SEVariant::String),
+        proptest::option::of(world_intersection_strategy()).prop_map(SEVariant::WorldIntersection),
+        proptest::option::of(user_marker_strategy()).prop_map(SEVariant::UserMarker),
+        proptest::array::uniform3(proptest::array::uniform3(f64_strategy()))
+            .prop_map(|rows| SEVariant::Matrix3X3(Matrix3X3(rows))),
+        proptest::array::uniform2(proptest::array::uniform2(f64_strategy()))
+            .prop_map(|rows| SEVariant::Matrix2x2(Matrix2x2(rows))),
+    ];
+    leaf.prop_recursive(3, 16, 4, |inner| {
+        prop_oneof![
+            proptest::collection::vec(inner.clone(), 0..4).prop_map(SEVariant::Vector),
+            proptest::collection::vec(
+                (string_strategy(), inner).prop_map(|(k, v)| SEStructItem(k, v)),
+                0..4
+            )
+            .prop_map(SEVariant::Struct),
+        ]
+    })
+}
+
+// One field per value type of SEOutputData.
+fn output_data_strategy() -> impl Strategy<Value = SEOutputData> {
+    prop_oneof![
+        any::<u16>().prop_map(SEOutputData::SEASCIIKeyboardState),
+        any::<u32>().prop_map(SEOutputData::SEFrameNumber),
+        any::<u64>().prop_map(SEOutputData::SETimeStamp),
+        f64_strategy().prop_map(SEOutputData::SEHeadHeading),
+        (f64_strategy(), f64_strategy())
+            .prop_map(|(x, y)| SEOutputData::SEGPSPosition(Point2D(x, y))),
+        point_3d_strategy().prop_map(SEOutputData::SEHeadPosition),
+        vect_3d_strategy().prop_map(SEOutputData::SEGazeDirection),
+        (
+            f64_strategy(),
+            f64_strategy(),
+            f64_strategy(),
+            f64_strategy()
+        )
+            .prop_map(
+                |(w, x, y, z)| SEOutputData::SEHeadRotationQuaternion(Quaternion(w, x, y, z))
+            ),
+        string_strategy().prop_map(SEOutputData::SEKeyboardState),
+        proptest::option::of(user_marker_strategy()).prop_map(SEOutputData::SEUserMarker),
+        proptest::collection::vec(variant_strategy(), 0..4)
+            .prop_map(SEOutputData::SECameraPositions),
+        proptest::option::of(world_intersection_strategy())
+            .prop_map(SEOutputData::SEClosestWorldIntersection),
+        proptest::collection::vec(world_intersection_strategy(), 0..3)
+            .prop_map(SEOutputData::SEAllWorldIntersections),
+    ]
+}
+
+fn packet_strategy() -> impl Strategy<Value = Vec<SEOutputData>> {
+    proptest::collection::vec(output_data_strategy(), 0..8)
+}
+
+proptest! {
+    #[test]
+    fn test_round_trip_packet(packet in packet_strategy()) {
+        let parsed = parse_packet(&serialize_packet(&packet));
+        prop_assert_eq!(parsed.as_deref(), Ok(&packet[..]));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::se_types::{SEOutputData, SEOutputDataId, SEVariant};
+use std::{collections::VecDeque, time::Instant};
+
+// Running min/max/mean/variance of a single numeric output data field,
+// updated using Welford's online algorithm.
+#[derive(Debug, Clone)]
+pub struct FieldStats {
+    id: SEOutputDataId,
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl FieldStats {
+    pub fn new(id: SEOutputDataId) -> Self {
+        FieldStats {
+            id,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn id(&self) -> SEOutputDataId {
+        self.id
+    }
+
+    // Updates the statistics with the value of the tracked field in packet.
+    // Packets lacking the field (or where it is not numeric) are skipped.
+    pub fn update(&mut self, packet: &[SEOutputData]) {
+        let id = self.id;
+        let values = packet
+            .iter()
+            .filter(|data| data.id() == id)
+            .filter_map(|data| SEVariant::from(data.clone()).as_f64());
+        for value in values {
+            self.push(value);
+        }
+    }
+
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.non_empty(self.min)
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.non_empty(self.max)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        self.non_empty(self.mean)
+    }
+
+    // Sample variance, requires at least two values.
+    pub fn variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / (self.count - 1) as f64)
+        }
+    }
+
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    fn non_empty(&self, value: f64) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(value)
+        }
+    }
+}
+
+// Percentiles of SEEstimatedDelay over the last window packets having it.
+#[derive(Debug, Clone)]
+pub struct LatencyTracker {
+    window: usize,
+    delays: VecDeque<u32>,
+}
+
+impl LatencyTracker {
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window must not be empty");
+        LatencyTracker {
+            window,
+            delays: VecDeque::with_capacity(window),
+        }
+    }
+
+    // Adds the SEEstimatedDelay of packet, if any, dropping the oldest delay
+    // once the window is full.
+    pub fn update(&mut self, packet: &[SEOutputData]) {
+        let delay = packet.iter().find_map(|data| match data {
+            SEOutputData::SEEstimatedDelay(v) => Some(*v),
+            _ => None,
+        });
+        if let Some(delay) = delay {
+            self.push(delay);
+        }
+    }
+
+    pub fn push(&mut self, delay: u32) {
+        if self.delays.len() == self.window {
+            self.delays.pop_front();
+        }
+        self.delays.push_back(delay);
+    }
+
+    pub fn count(&self) -> usize {
+        self.delays.len()
+    }
+
+    // The p:th percentile (0 to 100) of the delays in the window, using the
+    // nearest-rank method. None if the window is empty.
+    pub fn percentile(&self, p: f64) -> Option<u32> {
+        assert!((0.0..=100.0).contains(&p), "percentile must be in [0, 100]");
+        if self.delays.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<u32> = self.delays.iter().copied().collect();
+        sorted.sort_unstable();
+        let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.saturating_sub(1)])
+    }
+}
+
+// Compares the SEFrameRate reported by the tracker with the rate at which
+// packets actually arrive, measured over the arrival times of the last window
+// packets.
+#[derive(Debug, Clone)]
+pub struct RateDriftMonitor {
+    window: usize,
+    arrivals: VecDeque<Instant>,
+    reported_rate: Option<f64>,
+}
+
+impl RateDriftMonitor {
+    pub fn new(window: usize) -> Self {
+        assert!(window >= 2, "window must hold at least two packets");
+        RateDriftMonitor {
+            window,
+            arrivals: VecDeque::with_capacity(window),
+            reported_rate: None,
+        }
+    }
+
+    // Records the arrival of packet at arrival. The reported frame rate is
+    // taken from the latest packet having SEFrameRate.
+    pub fn update(&mut self, packet: &[SEOutputData], arrival: Instant) {
+        let frame_rate = packet.iter().find_map(|data| match data {
+            SEOutputData::SEFrameRate(v) => Some(*v),
+            _ => None,
+        });
+        if frame_rate.is_some() {
+            self.reported_rate = frame_rate;
+        }
+        if self.arrivals.len() == self.window {
+            self.arrivals.pop_front();
+        }
+        self.arrivals.push_back(arrival);
+    }
+
+    pub fn reported_rate(&self) -> Option<f64> {
+        self.reported_rate
+    }
+
+    // Packets per second, over the arrivals in the window. None until two
+    // packets have arrived at different times.
+    pub fn observed_rate(&self) -> Option<f64> {
+        let elapsed = self
+            .arrivals
+            .back()?
+            .duration_since(*self.arrivals.front()?)
+            .as_secs_f64();
+        if elapsed > 0.0 {
+            Some((self.arrivals.len() - 1) as f64 / elapsed)
+        } else {
+            None
+        }
+    }
+
+    // Shortfall of the observed rate relative to the reported rate, in
+    // percent: 0 when they match, 50 when only half of the frames arrive, and
+    // negative when packets arrive faster than reported.
+    pub fn drift_percent(&self) -> Option<f64> {
+        let reported = self.reported_rate.filter(|&rate| rate > 0.0)?;
+        Some((reported - self.observed_rate()?) / reported * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::se_types::SEOutputData;
+    use std::time::Duration;
+
+    #[test]
+    fn test_field_stats() {
+        let mut stats = FieldStats::new(SEOutputDataId::SEPupilDiameter);
+        assert_eq!(stats.mean(), None);
+
+        for &diameter in &[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.update(&[
+                SEOutputData::SEFrameNumber(1),
+                SEOutputData::SEPupilDiameter(diameter),
+            ]);
+        }
+        // Packets lacking the field are skipped.
+        stats.update(&[SEOutputData::SEFrameNumber(2)]);
+
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.mean(), Some(5.0));
+        assert_eq!(stats.min(), Some(2.0));
+        assert_eq!(stats.max(), Some(9.0));
+        assert!((stats.variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_latency_tracker() {
+        let mut tracker = LatencyTracker::new(100);
+        assert_eq!(tracker.percentile(50.0), None);
+
+        // Older delays fall out of the window.
+        for _ in 0..50 {
+            tracker.push(1000);
+        }
+        for delay in (1..=100).rev() {
+            tracker.update(&[
+                SEOutputData::SEFrameNumber(delay),
+                SEOutputData::SEEstimatedDelay(delay * 10),
+            ]);
+        }
+        tracker.update(&[SEOutputData::SEFrameNumber(101)]);
+
+        assert_eq!(tracker.count(), 100);
+        assert_eq!(tracker.percentile(50.0), Some(500));
+        assert_eq!(tracker.percentile(95.0), Some(950));
+        assert_eq!(tracker.percentile(99.0), Some(990));
+        assert_eq!(tracker.percentile(0.0), Some(10));
+        assert_eq!(tracker.percentile(100.0), Some(1000));
+    }
+
+    #[test]
+    fn test_rate_drift_monitor() {
+        let mut monitor = RateDriftMonitor::new(10);
+        let start = Instant::now();
+        monitor.update(&[SEOutputData::SEFrameRate(60.0)], start);
+        assert_eq!(monitor.drift_percent(), None);
+
+        // 30 packets per second.
+        for n in 1..20 {
+            let arrival = start + Duration::from_secs_f64(n as f64 / 30.0);
+            monitor.update(&[SEOutputData::SEFrameNumber(n)], arrival);
+        }
+        assert_eq!(monitor.reported_rate(), Some(60.0));
+        assert!((monitor.observed_rate().unwrap() - 30.0).abs() < 1e-6);
+        assert!((monitor.drift_percent().unwrap() - 50.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{client::Packet, se_types::SEOutputData};
+
+// Parses a hex dump into bytes, e.g. for writing packet fixtures. Whitespace
+// is ignored, as is everything from "//" or "#" to the end of a line.
+// Panics on anything else that is not a pair of hex digits.
+pub fn packet_from_hex(s: &str) -> Vec<u8> {
+    let digits: Vec<char> = s
+        .lines()
+        .flat_map(|line| {
+            let line = line.split("//").next().unwrap_or_default();
+            line.split('#').next().unwrap_or_default().chars()
+        })
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let byte: String = pair.iter().collect();
+            match u8::from_str_radix(&byte, 16) {
+                Ok(b) if pair.len() == 2 => b,
+                _ => panic!("invalid hex byte {:?}", byte),
+            }
+        })
+        .collect()
+}
+
+// Builds a Packet holding fields, regardless of which type Packet is with
+// the enabled features.
+pub fn packet_of(fields: Vec<SEOutputData>) -> Packet {
+    fields.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_from_hex() {
+        let packet = packet_from_hex(
+            "
+            53 45 50 44 # Sync Id
+            0004        // Packet type
+            00 08       // Packet length
+            ",
+        );
+        assert_eq!(packet, vec![0x53, 0x45, 0x50, 0x44, 0x00, 0x04, 0x00, 0x08]);
+        assert_eq!(packet_from_hex(""), Vec::<u8>::new());
+    }
+}